@@ -14,7 +14,7 @@ use sc_network::config::MultiaddrWithPeerId;
 use codec::{Encode,Decode};
 use sp_core::{crypto::{Ss58AddressFormatRegistry, Ss58Codec}};
 use sp_runtime::{MultiSigner};
-use vane_tanssi_runtime::CurrencyId::DOT;
+use vane_tanssi_runtime::CurrencyId::{DOT, USDC, USDT};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type TanssiChainSpec = sc_service::GenericChainSpec<vane_tanssi_runtime::GenesisConfig, Extensions>;
@@ -249,8 +249,8 @@ fn genesis_config(
 	let bob = get_from_seed::<sr25519::Public>("Bob");
 
 	let v_dot = "vDOT".as_bytes().to_vec();
-	let _v_usdt = "vUSDT".as_bytes().to_vec();
-	let _v_usdc = "vUSDC".as_bytes().to_vec();
+	let v_usdt = "vUSDT".as_bytes().to_vec();
+	let v_usdc = "vUSDC".as_bytes().to_vec();
 
 	// Calculate parachain Soverign account id
 	let sovererign_acount = calculate_sovereign_account::<PairType>(id.into()).unwrap();
@@ -292,17 +292,22 @@ fn genesis_config(
 
 				vane_assets: vane_tanssi_runtime::VaneAssetsConfig {
 
-					metadata: vec![(DOT,v_dot.clone(), v_dot,10)],
+					metadata: vec![(DOT,v_dot.clone(), v_dot.clone(),10),(USDT,v_usdt.clone(), v_usdt.clone(),6),(USDC,v_usdc.clone(), v_usdc.clone(),6)],
 
-					assets: vec![(DOT,para_account.clone(),true,1)],
+					assets: vec![(DOT,para_account.clone(),true,1),(USDT,para_account.clone(),true,1),(USDC,para_account.clone(),true,1)],
 
-					accounts: vec![(DOT,para_account.clone(),0)]
+					accounts: vec![(DOT,para_account.clone(),0),(USDT,para_account.clone(),0),(USDC,para_account.clone(),0)]
 
 				},
 				// This should initialize it to whatever we have set in the pallet
 				polkadot_xcm: vane_tanssi_runtime::PolkadotXcmConfig::default(),
 				transaction_payment: Default::default(),
 
+				vane_asset_registry: vane_tanssi_runtime::VaneAssetRegistryConfig {
+					assets: vec![(DOT,v_dot.clone(), v_dot.clone(),10),(USDT,v_usdt.clone(), v_usdt.clone(),6),(USDC,v_usdc.clone(), v_usdc.clone(),6)],
+					..Default::default()
+				},
+
 				vane_xcm_transfer: vane_tanssi_runtime::VaneXcmTransferConfig {
 					para_account: Some(para_account)
 				}
@@ -327,14 +332,19 @@ fn genesis_config(
 
 				vane_assets: vane_para_runtime::VaneAssetsConfig {
 
-					metadata: vec![(DOT,v_dot.clone(), v_dot,10)],
+					metadata: vec![(DOT,v_dot.clone(), v_dot.clone(),10),(USDT,v_usdt.clone(), v_usdt.clone(),6),(USDC,v_usdc.clone(), v_usdc.clone(),6)],
 
-					assets: vec![(DOT,para_account.clone(),true,1)],
+					assets: vec![(DOT,para_account.clone(),true,1),(USDT,para_account.clone(),true,1),(USDC,para_account.clone(),true,1)],
 
-					accounts: vec![(DOT,para_account.clone(),0)]
+					accounts: vec![(DOT,para_account.clone(),0),(USDT,para_account.clone(),0),(USDC,para_account.clone(),0)]
 
 				},
 
+				vane_asset_registry: vane_para_runtime::VaneAssetRegistryConfig {
+					assets: vec![(DOT,v_dot.clone(), v_dot.clone(),10),(USDT,v_usdt.clone(), v_usdt.clone(),6),(USDC,v_usdc.clone(), v_usdc.clone(),6)],
+					..Default::default()
+				},
+
 				vane_xcm_transfer: vane_para_runtime::VaneXcmTransferConfig {
 					para_account: Some(para_account)
 				},
@@ -0,0 +1,144 @@
+// Benchmarks for the Vane asset registry
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use staging_xcm::latest::{Junction::{AccountId32, GeneralIndex, PalletInstance, Parachain}, Junctions::{X1, X3}};
+
+// AssetHub asset which is not built in
+fn foreign_asset(index: u128) -> (CurrencyId, MultiLocation, MultiLocation) {
+	(
+		CurrencyId::Foreign(index as u32),
+		MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(index))),
+		MultiLocation::new(1, X1(Parachain(1000))),
+	)
+}
+
+fn name<T: Config>() -> Vec<u8> {
+	sp_std::vec![b'v'; T::StringLimit::get() as usize]
+}
+
+fn registered<T: Config>(index: u128) -> Result<CurrencyId, BenchmarkError> {
+	let (currency_id, location, reserve) = foreign_asset(index);
+	Pallet::<T>::do_register_asset(currency_id, location, reserve, 1_000, name::<T>(), name::<T>(), 6)?;
+	Ok(currency_id)
+}
+
+// DOT is created at genesis, registered here when the benchmark chain has no registry entry
+fn registered_dot<T: Config>() -> Result<MultiLocation, BenchmarkError> {
+	let location = CurrencyId::DOT.location().ok_or(BenchmarkError::Stop("DOT has no location"))?;
+
+	if Assets::<T>::get(CurrencyId::DOT).is_none() {
+		let reserve = CurrencyId::DOT.reserve().ok_or(BenchmarkError::Stop("DOT has no reserve"))?;
+		Pallet::<T>::do_register_asset(CurrencyId::DOT, location, reserve, 1_000, name::<T>(), name::<T>(), 10)?;
+	}
+
+	Ok(location)
+}
+
+#[benchmarks(where T::AccountId: Into<[u8; 32]>)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_asset() -> Result<(), BenchmarkError> {
+		let origin = T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (currency_id, location, reserve) = foreign_asset(4242);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			currency_id,
+			Box::new(VersionedMultiLocation::V3(location)),
+			Box::new(VersionedMultiLocation::V3(reserve)),
+			1_000,
+			name::<T>(),
+			name::<T>(),
+			6
+		);
+
+		assert_eq!(LocationToCurrency::<T>::get(&location), Some(currency_id));
+		Ok(())
+	}
+
+	// Moving the location rewrites the reverse lookup
+	#[benchmark]
+	fn update_asset() -> Result<(), BenchmarkError> {
+		let origin = T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let currency_id = registered::<T>(4242)?;
+		let (_, location, reserve) = foreign_asset(4243);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			currency_id,
+			Some(Box::new(VersionedMultiLocation::V3(location))),
+			Some(Box::new(VersionedMultiLocation::V3(reserve))),
+			Some(2_000),
+			Some(name::<T>()),
+			Some(name::<T>()),
+			Some(12)
+		);
+
+		assert_eq!(LocationToCurrency::<T>::get(&location), Some(currency_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_asset_status() -> Result<(), BenchmarkError> {
+		let origin = T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let currency_id = registered::<T>(4242)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, currency_id, false);
+
+		assert_eq!(Assets::<T>::get(currency_id).map(|asset| asset.enabled), Some(false));
+		Ok(())
+	}
+
+	// Held while unknown, then claimed once registered
+	#[benchmark]
+	fn claim_unknown_asset() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = MultiLocation::new(0, X1(AccountId32 { network: None, id: caller.clone().into() }));
+		let asset: MultiAsset = (registered_dot::<T>()?, 1_000_000_000_000u128).into();
+
+		UnknownAssets::<T>::insert(&owner, &asset.id, 1_000_000_000_000u128);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			Box::new(VersionedMultiLocation::V3(owner)),
+			Box::new(VersionedMultiAsset::V3(asset.clone())),
+			None
+		);
+
+		assert!(!UnknownAssets::<T>::contains_key(&owner, &asset.id));
+		Ok(())
+	}
+
+	// Held for a location no account controls, released by governance
+	#[benchmark]
+	fn release_unknown_asset() -> Result<(), BenchmarkError> {
+		let origin = T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let owner = MultiLocation::new(1, X1(Parachain(2000)));
+		let beneficiary = MultiLocation::new(0, X1(AccountId32 { network: None, id: whitelisted_caller::<T::AccountId>().into() }));
+		let asset: MultiAsset = (registered_dot::<T>()?, 1_000_000_000_000u128).into();
+
+		UnknownAssets::<T>::insert(&owner, &asset.id, 1_000_000_000_000u128);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(VersionedMultiLocation::V3(owner)),
+			Box::new(VersionedMultiAsset::V3(asset.clone())),
+			Box::new(VersionedMultiLocation::V3(beneficiary))
+		);
+
+		assert!(!UnknownAssets::<T>::contains_key(&owner, &asset.id));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
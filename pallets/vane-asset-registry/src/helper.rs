@@ -0,0 +1,17 @@
+use super::*;
+
+// Registered currency, its XCM locations and metadata
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct AssetInfo<T: Config> {
+	// Location of the asset as seen from Vane
+	pub location: MultiLocation,
+	// Chain allowed to reserve transfer the asset to Vane
+	pub reserve: MultiLocation,
+	pub existential_deposit: u128,
+	pub name: BoundedVec<u8, T::StringLimit>,
+	pub symbol: BoundedVec<u8, T::StringLimit>,
+	pub decimals: u8,
+	// Disabled assets are rejected over XCM, balances already held stay untouched
+	pub enabled: bool,
+}
@@ -0,0 +1,450 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Governed registry of the currencies Vane accepts over XCM
+// The converters, the reserve matcher and the foreign asset creators all read from it
+// Assets sent before they are registered are kept in custody until their owner claims them
+
+pub use pallet::*;
+pub mod helper;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_std::boxed::Box;
+use sp_std::vec::Vec;
+use staging_xcm::latest::{AssetId, Fungibility, MultiAsset, MultiLocation, XcmContext};
+use staging_xcm::{VersionedMultiAsset, VersionedMultiLocation};
+use staging_xcm_executor::traits::{ConvertLocation, TransactAsset};
+use vane_primitive::{AssetRegistry, CurrencyId, UnknownAssetTrait};
+
+
+#[frame_support::pallet]
+pub mod pallet {
+
+	use super::helper::AssetInfo;
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		// Governance origin registering and updating assets
+		type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		// Bytes of an asset name or symbol
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		// Resolves the account owning an unknown asset deposit
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
+		// Deposits claimed unknown assets once they are registered, the runtime asset transactor
+		type AssetTransactor: TransactAsset;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	// Built in currencies registered by the v1 migration on chains which launched without the registry
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	// Key ----> currency_id
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset)]
+	pub type Assets<T: Config> = StorageMap<_, Blake2_128Concat, CurrencyId, AssetInfo<T>>;
+
+	// Reverse lookup used when matching inbound assets
+	// Key ----> location
+	#[pallet::storage]
+	#[pallet::getter(fn get_location_currency)]
+	pub type LocationToCurrency<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, CurrencyId>;
+
+	// Fungible assets deposited while unknown
+	// Keys ----> (owner, asset_id)
+	#[pallet::storage]
+	#[pallet::getter(fn get_unknown_asset)]
+	pub type UnknownAssets<T: Config> =
+	StorageDoubleMap<_, Blake2_128Concat, MultiLocation, Blake2_128Concat, AssetId, u128, ValueQuery>;
+
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		// (currency_id, name, symbol, decimals), only the built in currencies have locations at genesis
+		pub assets: Vec<(CurrencyId, Vec<u8>, Vec<u8>, u8)>,
+		#[serde(skip)]
+		pub _phantom: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (currency_id, name, symbol, decimals) in &self.assets {
+				// panicks if the currency is not built in
+				let location = currency_id.location().expect("genesis asset has no built in location");
+				let reserve = currency_id.reserve().expect("genesis asset has no built in reserve");
+				let existential_deposit = <() as AssetRegistry>::existential_deposit(currency_id)
+					.expect("genesis asset has no built in existential deposit");
+
+				Pallet::<T>::do_register_asset(
+					*currency_id,
+					location,
+					reserve,
+					existential_deposit,
+					name.clone(),
+					symbol.clone(),
+					*decimals
+				).expect("genesis asset registered twice");
+			}
+		}
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		AssetAlreadyRegistered,
+
+		AssetNotRegistered,
+
+		LocationAlreadyRegistered,
+
+		BadVersion,
+
+		NameTooLong,
+
+		SymbolTooLong,
+
+		UnsupportedAsset,
+
+		UnknownAssetBalanceLow,
+
+		UnknownAssetOverflow,
+
+		NotTheOwner,
+
+		AssetStillUnknown,
+
+		ClaimDepositFailed,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		AssetRegistered {
+			currency_id: CurrencyId,
+			location: MultiLocation,
+			reserve: MultiLocation,
+		},
+		AssetUpdated {
+			currency_id: CurrencyId,
+			location: MultiLocation,
+			reserve: MultiLocation,
+			existential_deposit: u128,
+		},
+		AssetStatusChanged {
+			currency_id: CurrencyId,
+			enabled: bool,
+		},
+		UnknownAssetDeposited {
+			owner: MultiLocation,
+			asset: MultiAsset,
+		},
+		UnknownAssetWithdrawn {
+			owner: MultiLocation,
+			asset: MultiAsset,
+		},
+		UnknownAssetClaimed {
+			owner: MultiLocation,
+			asset: MultiAsset,
+			beneficiary: MultiLocation,
+		},
+		UnknownAssetReleased {
+			owner: MultiLocation,
+			asset: MultiAsset,
+			beneficiary: MultiLocation,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+
+		// Registers an enabled asset, adding a stablecoin only needs a new `CurrencyId::Foreign` id
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_asset())]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			location: Box<VersionedMultiLocation>,
+			reserve: Box<VersionedMultiLocation>,
+			existential_deposit: u128,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let reserve: MultiLocation = (*reserve).try_into().map_err(|_| Error::<T>::BadVersion)?;
+
+			Self::do_register_asset(currency_id, location, reserve, existential_deposit, name, symbol, decimals)
+		}
+
+		// Fields left as None are kept
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update_asset())]
+		pub fn update_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			location: Option<Box<VersionedMultiLocation>>,
+			reserve: Option<Box<VersionedMultiLocation>>,
+			existential_deposit: Option<u128>,
+			name: Option<Vec<u8>>,
+			symbol: Option<Vec<u8>>,
+			decimals: Option<u8>
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let location: Option<MultiLocation> = location
+				.map(|location| (*location).try_into())
+				.transpose()
+				.map_err(|_| Error::<T>::BadVersion)?;
+			let reserve: Option<MultiLocation> = reserve
+				.map(|reserve| (*reserve).try_into())
+				.transpose()
+				.map_err(|_| Error::<T>::BadVersion)?;
+
+			let asset = Assets::<T>::try_mutate(currency_id, |maybe_asset| -> Result<AssetInfo<T>, DispatchError> {
+				let asset = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+
+				if let Some(location) = location {
+					if location != asset.location {
+						ensure!(!LocationToCurrency::<T>::contains_key(&location), Error::<T>::LocationAlreadyRegistered);
+						LocationToCurrency::<T>::remove(&asset.location);
+						LocationToCurrency::<T>::insert(&location, currency_id);
+						asset.location = location;
+					}
+				}
+				if let Some(reserve) = reserve {
+					asset.reserve = reserve;
+				}
+				if let Some(existential_deposit) = existential_deposit {
+					asset.existential_deposit = existential_deposit;
+				}
+				if let Some(name) = name {
+					asset.name = name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+				}
+				if let Some(symbol) = symbol {
+					asset.symbol = symbol.try_into().map_err(|_| Error::<T>::SymbolTooLong)?;
+				}
+				if let Some(decimals) = decimals {
+					asset.decimals = decimals;
+				}
+
+				Ok(asset.clone())
+			})?;
+
+			Self::deposit_event(Event::AssetUpdated {
+				currency_id,
+				location: asset.location,
+				reserve: asset.reserve,
+				existential_deposit: asset.existential_deposit,
+			});
+
+			Ok(())
+		}
+
+		// Stops or resumes accepting the asset over XCM without losing its registration
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_asset_status())]
+		pub fn set_asset_status(origin: OriginFor<T>, currency_id: CurrencyId, enabled: bool) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			Assets::<T>::try_mutate(currency_id, |maybe_asset| -> DispatchResult {
+				let asset = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotRegistered)?;
+				asset.enabled = enabled;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AssetStatusChanged { currency_id, enabled });
+
+			Ok(())
+		}
+
+		// Moves an unknown asset out of custody once governance registered it
+		// Deposited to the owner unless another local `beneficiary` is given
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::claim_unknown_asset())]
+		pub fn claim_unknown_asset(
+			origin: OriginFor<T>,
+			owner: Box<VersionedMultiLocation>,
+			asset: Box<VersionedMultiAsset>,
+			beneficiary: Option<Box<VersionedMultiLocation>>
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+
+			let owner: MultiLocation = (*owner).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let asset: MultiAsset = (*asset).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let beneficiary: MultiLocation = match beneficiary {
+				Some(beneficiary) => (*beneficiary).try_into().map_err(|_| Error::<T>::BadVersion)?,
+				None => owner,
+			};
+
+			ensure!(T::LocationToAccountId::convert_location(&owner) == Some(caller), Error::<T>::NotTheOwner);
+
+			let AssetId::Concrete(ref location) = asset.id else { return Err(Error::<T>::AssetStillUnknown.into()) };
+			ensure!(Self::currency_id(location).is_some(), Error::<T>::AssetStillUnknown);
+
+			Self::do_withdraw_unknown(&asset, &owner)?;
+
+			let context = XcmContext { origin: Some(owner), message_id: [0u8; 32], topic: None };
+			T::AssetTransactor::deposit_asset(&asset, &beneficiary, &context)
+				.map_err(|_| Error::<T>::ClaimDepositFailed)?;
+
+			Self::deposit_event(Event::UnknownAssetClaimed { owner, asset, beneficiary });
+
+			Ok(())
+		}
+
+		// Moves an unknown asset out of custody on behalf of an owner which no local account controls
+		// The asset must be registered so the transactor can deposit it to `beneficiary`
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::release_unknown_asset())]
+		pub fn release_unknown_asset(
+			origin: OriginFor<T>,
+			owner: Box<VersionedMultiLocation>,
+			asset: Box<VersionedMultiAsset>,
+			beneficiary: Box<VersionedMultiLocation>
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let owner: MultiLocation = (*owner).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let asset: MultiAsset = (*asset).try_into().map_err(|_| Error::<T>::BadVersion)?;
+			let beneficiary: MultiLocation = (*beneficiary).try_into().map_err(|_| Error::<T>::BadVersion)?;
+
+			let AssetId::Concrete(ref location) = asset.id else { return Err(Error::<T>::AssetStillUnknown.into()) };
+			ensure!(Self::currency_id(location).is_some(), Error::<T>::AssetStillUnknown);
+
+			Self::do_withdraw_unknown(&asset, &owner)?;
+
+			let context = XcmContext { origin: Some(owner), message_id: [0u8; 32], topic: None };
+			T::AssetTransactor::deposit_asset(&asset, &beneficiary, &context)
+				.map_err(|_| Error::<T>::ClaimDepositFailed)?;
+
+			Self::deposit_event(Event::UnknownAssetReleased { owner, asset, beneficiary });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn do_register_asset(
+			currency_id: CurrencyId,
+			location: MultiLocation,
+			reserve: MultiLocation,
+			existential_deposit: u128,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8
+		) -> DispatchResult {
+			ensure!(!Assets::<T>::contains_key(currency_id), Error::<T>::AssetAlreadyRegistered);
+			ensure!(!LocationToCurrency::<T>::contains_key(&location), Error::<T>::LocationAlreadyRegistered);
+
+			let asset = AssetInfo::<T> {
+				location,
+				reserve,
+				existential_deposit,
+				name: name.try_into().map_err(|_| Error::<T>::NameTooLong)?,
+				symbol: symbol.try_into().map_err(|_| Error::<T>::SymbolTooLong)?,
+				decimals,
+				enabled: true,
+			};
+
+			Assets::<T>::insert(currency_id, asset);
+			LocationToCurrency::<T>::insert(&location, currency_id);
+
+			Self::deposit_event(Event::AssetRegistered { currency_id, location, reserve });
+
+			Ok(())
+		}
+
+		fn fungible_amount(asset: &MultiAsset) -> Result<u128, DispatchError> {
+			match asset.fun {
+				Fungibility::Fungible(amount) => Ok(amount),
+				_ => Err(Error::<T>::UnsupportedAsset.into())
+			}
+		}
+
+		pub fn do_withdraw_unknown(asset: &MultiAsset, owner: &MultiLocation) -> DispatchResult {
+			let amount = Self::fungible_amount(asset)?;
+
+			UnknownAssets::<T>::try_mutate_exists(owner, &asset.id, |maybe_balance| -> DispatchResult {
+				let balance = maybe_balance.unwrap_or_default()
+					.checked_sub(amount)
+					.ok_or(Error::<T>::UnknownAssetBalanceLow)?;
+				*maybe_balance = if balance == 0 { None } else { Some(balance) };
+				Ok(())
+			})
+		}
+	}
+}
+
+impl<T: Config> AssetRegistry for Pallet<T> {
+	fn location(currency_id: &CurrencyId) -> Option<MultiLocation> {
+		Assets::<T>::get(currency_id)
+			.filter(|asset| asset.enabled)
+			.map(|asset| asset.location)
+	}
+
+	fn currency_id(location: &MultiLocation) -> Option<CurrencyId> {
+		LocationToCurrency::<T>::get(location)
+			.filter(|currency_id| Assets::<T>::get(currency_id).map_or(false, |asset| asset.enabled))
+	}
+
+	fn reserve(currency_id: &CurrencyId) -> Option<MultiLocation> {
+		Assets::<T>::get(currency_id)
+			.filter(|asset| asset.enabled)
+			.map(|asset| asset.reserve)
+	}
+
+	fn existential_deposit(currency_id: &CurrencyId) -> Option<u128> {
+		Assets::<T>::get(currency_id).map(|asset| asset.existential_deposit)
+	}
+}
+
+// Custody of fungible assets the transactor could not convert
+impl<T: Config> UnknownAssetTrait for Pallet<T> {
+	fn deposit(asset: &MultiAsset, to: &MultiLocation) -> DispatchResult {
+		let amount = Self::fungible_amount(asset)?;
+
+		UnknownAssets::<T>::try_mutate(to, &asset.id, |balance| -> DispatchResult {
+			*balance = balance.checked_add(amount).ok_or(Error::<T>::UnknownAssetOverflow)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::UnknownAssetDeposited { owner: *to, asset: asset.clone() });
+
+		Ok(())
+	}
+
+	fn withdraw(asset: &MultiAsset, from: &MultiLocation) -> DispatchResult {
+		Self::do_withdraw_unknown(asset, from)?;
+
+		Self::deposit_event(Event::UnknownAssetWithdrawn { owner: *from, asset: asset.clone() });
+
+		Ok(())
+	}
+}
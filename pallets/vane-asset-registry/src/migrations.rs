@@ -0,0 +1,72 @@
+// Storage migrations for the Vane asset registry
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	// (currency_id, symbol, decimals) Vane accepted before the registry existed
+	const BUILT_IN: [(CurrencyId, &[u8], u8); 3] = [
+		(CurrencyId::DOT, b"vDOT", 10),
+		(CurrencyId::USDT, b"vUSDT", 6),
+		(CurrencyId::USDC, b"vUSDC", 6),
+	];
+
+	// Registers the built in currencies on chains upgrading to the registry,
+	// without them every inbound asset would be rejected
+	pub struct RegisterBuiltInAssets<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for RegisterBuiltInAssets<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 0 {
+				log::info!(target: "vane-asset-registry", "RegisterBuiltInAssets skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut registered = 0u64;
+
+			for (currency_id, symbol, decimals) in BUILT_IN {
+				let (Some(location), Some(reserve), Some(existential_deposit)) = (
+					currency_id.location(),
+					currency_id.reserve(),
+					<() as AssetRegistry>::existential_deposit(&currency_id),
+				) else { continue };
+
+				match Pallet::<T>::do_register_asset(
+					currency_id,
+					location,
+					reserve,
+					existential_deposit,
+					symbol.to_vec(),
+					symbol.to_vec(),
+					decimals
+				) {
+					Ok(()) => registered += 1,
+					Err(e) => log::warn!(target: "vane-asset-registry", "{:?} not registered: {:?}", currency_id, e),
+				}
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "vane-asset-registry", "RegisterBuiltInAssets registered {} assets", registered);
+
+			T::DbWeight::get().reads_writes(registered.saturating_mul(2).saturating_add(1), registered.saturating_mul(2).saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			for (currency_id, _, _) in BUILT_IN {
+				ensure!(Assets::<T>::contains_key(currency_id), "built in asset not registered");
+			}
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,124 @@
+// Test runtime for the Vane asset registry.
+// Claimed unknown assets are captured by `TestTransactor` instead of being minted
+
+use crate as vane_asset_registry;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use staging_xcm::latest::prelude::*;
+use staging_xcm_builder::AccountId32Aliases;
+use staging_xcm_executor::traits::TransactAsset;
+use std::cell::RefCell;
+use vane_primitive::CurrencyId;
+
+pub type AccountId = AccountId32;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const OWNER: AccountId = AccountId32::new([1u8; 32]);
+pub const STRANGER: AccountId = AccountId32::new([2u8; 32]);
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		VaneAssetRegistry: vane_asset_registry,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const AnyNetwork: Option<NetworkId> = None;
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+thread_local! {
+	pub static DEPOSITED: RefCell<Vec<(MultiAsset, MultiLocation)>> = RefCell::new(Vec::new());
+}
+
+// Assets handed back by the registry, oldest first
+pub fn deposited() -> Vec<(MultiAsset, MultiLocation)> {
+	DEPOSITED.with(|deposited| deposited.borrow().clone())
+}
+
+pub struct TestTransactor;
+impl TransactAsset for TestTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: &XcmContext) -> XcmResult {
+		DEPOSITED.with(|deposited| deposited.borrow_mut().push((what.clone(), *who)));
+		Ok(())
+	}
+}
+
+impl vane_asset_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type StringLimit = ConstU32<16>;
+	type LocationToAccountId = AccountId32Aliases<AnyNetwork, AccountId>;
+	type AssetTransactor = TestTransactor;
+	type WeightInfo = ();
+}
+
+// Location of `who` as the owner of an unknown asset
+pub fn owner_location(who: &AccountId) -> MultiLocation {
+	MultiLocation::new(0, X1(Junction::AccountId32 { network: None, id: who.clone().into() }))
+}
+
+// AssetHub asset which is not built in
+pub fn foreign_asset(index: u128) -> (CurrencyId, MultiLocation, MultiLocation) {
+	(
+		CurrencyId::Foreign(index as u32),
+		MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(index))),
+		MultiLocation::new(1, X1(Parachain(1000))),
+	)
+}
+
+// DOT registered at genesis, the other built in currencies are left to governance
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	vane_asset_registry::GenesisConfig::<Test> {
+		assets: vec![(CurrencyId::DOT, b"vDOT".to_vec(), b"vDOT".to_vec(), 10)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		DEPOSITED.with(|deposited| deposited.borrow_mut().clear());
+	});
+	ext
+}
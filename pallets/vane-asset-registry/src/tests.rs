@@ -0,0 +1,293 @@
+// Asset registry tests, registrations go through the root origin
+
+use crate::{migrations::v1::RegisterBuiltInAssets, mock::*, Assets, Error, LocationToCurrency, Pallet, UnknownAssets};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{ContainsPair, Everything, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::{DispatchError, DispatchResult};
+use staging_xcm::{
+	latest::{Junction::Parachain, Junctions::X1, MultiAsset, MultiLocation},
+	VersionedMultiAsset, VersionedMultiLocation,
+};
+use vane_primitive::{AssetRegistry, CurrencyId, UnknownAssetTrait, VaneTrustedUnknownAssets};
+
+fn register(index: u128) -> (CurrencyId, MultiLocation) {
+	let (currency_id, location, reserve) = foreign_asset(index);
+	assert_ok!(VaneAssetRegistry::register_asset(
+		RuntimeOrigin::root(),
+		currency_id,
+		Box::new(VersionedMultiLocation::V3(location)),
+		Box::new(VersionedMultiLocation::V3(reserve)),
+		1_000,
+		b"vEURC".to_vec(),
+		b"vEURC".to_vec(),
+		6
+	));
+	(currency_id, location)
+}
+
+// Registration
+
+#[test]
+fn genesis_registers_the_built_in_currencies() {
+	new_test_ext().execute_with(|| {
+		let location = CurrencyId::DOT.location().unwrap();
+
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::location(&CurrencyId::DOT), Some(location));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&location), Some(CurrencyId::DOT));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::reserve(&CurrencyId::DOT), CurrencyId::DOT.reserve());
+	});
+}
+
+#[test]
+fn registered_asset_resolves_both_ways() {
+	new_test_ext().execute_with(|| {
+		let (currency_id, location) = register(4242);
+
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::location(&currency_id), Some(location));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&location), Some(currency_id));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::existential_deposit(&currency_id), Some(1_000));
+	});
+}
+
+#[test]
+fn only_the_registry_origin_registers() {
+	new_test_ext().execute_with(|| {
+		let (currency_id, location, reserve) = foreign_asset(4242);
+
+		assert_noop!(
+			VaneAssetRegistry::register_asset(
+				RuntimeOrigin::signed(OWNER),
+				currency_id,
+				Box::new(VersionedMultiLocation::V3(location)),
+				Box::new(VersionedMultiLocation::V3(reserve)),
+				1_000,
+				b"vEURC".to_vec(),
+				b"vEURC".to_vec(),
+				6
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn a_location_backs_one_currency_only() {
+	new_test_ext().execute_with(|| {
+		let (_, location) = register(4242);
+		let (_, _, reserve) = foreign_asset(4242);
+
+		assert_noop!(
+			VaneAssetRegistry::register_asset(
+				RuntimeOrigin::root(),
+				CurrencyId::Foreign(1),
+				Box::new(VersionedMultiLocation::V3(location)),
+				Box::new(VersionedMultiLocation::V3(reserve)),
+				1_000,
+				b"vEURC".to_vec(),
+				b"vEURC".to_vec(),
+				6
+			),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn moving_an_asset_rewrites_the_reverse_lookup() {
+	new_test_ext().execute_with(|| {
+		let (currency_id, old_location) = register(4242);
+		let (_, new_location, _) = foreign_asset(4243);
+
+		assert_ok!(VaneAssetRegistry::update_asset(
+			RuntimeOrigin::root(),
+			currency_id,
+			Some(Box::new(VersionedMultiLocation::V3(new_location))),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert!(!LocationToCurrency::<Test>::contains_key(&old_location));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&new_location), Some(currency_id));
+		assert_eq!(Assets::<Test>::get(currency_id).unwrap().existential_deposit, 1_000);
+	});
+}
+
+#[test]
+fn disabled_asset_is_not_accepted_but_stays_registered() {
+	new_test_ext().execute_with(|| {
+		let (currency_id, location) = register(4242);
+
+		assert_ok!(VaneAssetRegistry::set_asset_status(RuntimeOrigin::root(), currency_id, false));
+
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::location(&currency_id), None);
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&location), None);
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::existential_deposit(&currency_id), Some(1_000));
+
+		assert_ok!(VaneAssetRegistry::set_asset_status(RuntimeOrigin::root(), currency_id, true));
+		assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&location), Some(currency_id));
+	});
+}
+
+// Custody of unknown assets
+
+const HELD: u128 = 5_000;
+
+// Foreign asset deposited to OWNER before governance registered it
+fn held_unknown_asset(index: u128) -> MultiAsset {
+	let (_, location, _) = foreign_asset(index);
+	let asset: MultiAsset = (location, HELD).into();
+	assert_ok!(<VaneAssetRegistry as UnknownAssetTrait>::deposit(&asset, &owner_location(&OWNER)));
+	asset
+}
+
+fn claim(who: AccountId, asset: &MultiAsset, beneficiary: Option<MultiLocation>) -> DispatchResult {
+	VaneAssetRegistry::claim_unknown_asset(
+		RuntimeOrigin::signed(who),
+		Box::new(VersionedMultiLocation::V3(owner_location(&OWNER))),
+		Box::new(VersionedMultiAsset::V3(asset.clone())),
+		beneficiary.map(|beneficiary| Box::new(VersionedMultiLocation::V3(beneficiary)))
+	)
+}
+
+#[test]
+fn unknown_asset_is_held_for_its_owner() {
+	new_test_ext().execute_with(|| {
+		let asset = held_unknown_asset(4242);
+		assert_ok!(<VaneAssetRegistry as UnknownAssetTrait>::deposit(&asset, &owner_location(&OWNER)));
+
+		assert_eq!(UnknownAssets::<Test>::get(owner_location(&OWNER), &asset.id), 2 * HELD);
+	});
+}
+
+#[test]
+fn unknown_asset_is_claimed_once_registered() {
+	new_test_ext().execute_with(|| {
+		let asset = held_unknown_asset(4242);
+		assert_noop!(claim(OWNER, &asset, None), Error::<Test>::AssetStillUnknown);
+
+		register(4242);
+		assert_ok!(claim(OWNER, &asset, None));
+
+		assert!(!UnknownAssets::<Test>::contains_key(owner_location(&OWNER), &asset.id));
+		assert_eq!(deposited(), vec![(asset, owner_location(&OWNER))]);
+	});
+}
+
+#[test]
+fn claim_can_go_to_another_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let asset = held_unknown_asset(4242);
+		register(4242);
+
+		assert_ok!(claim(OWNER, &asset, Some(owner_location(&STRANGER))));
+
+		assert_eq!(deposited(), vec![(asset, owner_location(&STRANGER))]);
+	});
+}
+
+#[test]
+fn only_the_owner_claims() {
+	new_test_ext().execute_with(|| {
+		let asset = held_unknown_asset(4242);
+		register(4242);
+
+		assert_noop!(claim(STRANGER, &asset, Some(owner_location(&STRANGER))), Error::<Test>::NotTheOwner);
+	});
+}
+
+#[test]
+fn claim_is_bounded_by_the_held_amount() {
+	new_test_ext().execute_with(|| {
+		let (_, location, _) = foreign_asset(4242);
+		held_unknown_asset(4242);
+		register(4242);
+
+		let asset: MultiAsset = (location, HELD + 1).into();
+		assert_noop!(claim(OWNER, &asset, None), Error::<Test>::UnknownAssetBalanceLow);
+
+		// Part of the holding can be claimed, the rest stays in custody
+		let asset: MultiAsset = (location, HELD - 1).into();
+		assert_ok!(claim(OWNER, &asset, None));
+		assert_eq!(UnknownAssets::<Test>::get(owner_location(&OWNER), &asset.id), 1);
+	});
+}
+
+// Sibling parachain, no local account controls it
+fn stranded_owner() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(2000)))
+}
+
+fn release(origin: RuntimeOrigin, asset: &MultiAsset) -> DispatchResult {
+	VaneAssetRegistry::release_unknown_asset(
+		origin,
+		Box::new(VersionedMultiLocation::V3(stranded_owner())),
+		Box::new(VersionedMultiAsset::V3(asset.clone())),
+		Box::new(VersionedMultiLocation::V3(owner_location(&STRANGER)))
+	)
+}
+
+#[test]
+fn governance_releases_assets_no_account_can_claim() {
+	new_test_ext().execute_with(|| {
+		let (_, location, _) = foreign_asset(4242);
+		let asset: MultiAsset = (location, HELD).into();
+		assert_ok!(<VaneAssetRegistry as UnknownAssetTrait>::deposit(&asset, &stranded_owner()));
+		assert_noop!(release(RuntimeOrigin::root(), &asset), Error::<Test>::AssetStillUnknown);
+
+		register(4242);
+		assert_noop!(release(RuntimeOrigin::signed(OWNER), &asset), DispatchError::BadOrigin);
+		assert_ok!(release(RuntimeOrigin::root(), &asset));
+
+		assert!(!UnknownAssets::<Test>::contains_key(stranded_owner(), &asset.id));
+		assert_eq!(deposited(), vec![(asset, owner_location(&STRANGER))]);
+	});
+}
+
+#[test]
+fn unregistered_assets_of_a_trusted_reserve_are_taken_into_custody() {
+	new_test_ext().execute_with(|| {
+		type TrustedUnknown = VaneTrustedUnknownAssets<VaneAssetRegistry, Everything>;
+		let (_, location, reserve) = foreign_asset(4242);
+		let asset: MultiAsset = (location, HELD).into();
+
+		assert!(TrustedUnknown::contains(&asset, &reserve));
+		// Only the chain the asset lives on can act as its reserve
+		assert!(!TrustedUnknown::contains(&asset, &stranded_owner()));
+
+		// Registered assets are left to their registered reserve
+		register(4242);
+		assert!(!TrustedUnknown::contains(&asset, &reserve));
+	});
+}
+
+// Migrations
+
+fn upgrade<M: OnRuntimeUpgrade>() {
+	#[cfg(feature = "try-runtime")]
+	let state = M::pre_upgrade().unwrap();
+	M::on_runtime_upgrade();
+	#[cfg(feature = "try-runtime")]
+	M::post_upgrade(state).unwrap();
+}
+
+#[test]
+fn upgrade_registers_the_built_in_currencies() {
+	new_test_ext().execute_with(|| {
+		Assets::<Test>::remove(CurrencyId::DOT);
+		LocationToCurrency::<Test>::remove(CurrencyId::DOT.location().unwrap());
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		upgrade::<RegisterBuiltInAssets<Test>>();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		for currency_id in [CurrencyId::DOT, CurrencyId::USDT, CurrencyId::USDC] {
+			let location = currency_id.location().unwrap();
+			assert_eq!(<VaneAssetRegistry as AssetRegistry>::currency_id(&location), Some(currency_id));
+		}
+	});
+}
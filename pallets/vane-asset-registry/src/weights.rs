@@ -0,0 +1,97 @@
+//! Weights for `vane_asset_registry`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_asset_registry`.
+pub trait WeightInfo {
+	fn register_asset() -> Weight;
+	fn update_asset() -> Weight;
+	fn set_asset_status() -> Weight;
+	fn claim_unknown_asset() -> Weight;
+	fn release_unknown_asset() -> Weight;
+}
+
+/// Hand-written weights for `vane_asset_registry`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:1)
+	fn register_asset() -> Weight {
+		Weight::from_parts(24_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:2)
+	fn update_asset() -> Weight {
+		Weight::from_parts(27_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(16_000_000, 3_700)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:0)
+	/// Storage: VaneAssetRegistry Assets (r:1 w:0)
+	/// Storage: VaneAssetRegistry UnknownAssets (r:1 w:1)
+	/// Storage: VaneAssets Asset (r:1 w:1)
+	/// Storage: VaneAssets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	fn claim_unknown_asset() -> Weight {
+		Weight::from_parts(58_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:0)
+	/// Storage: VaneAssetRegistry Assets (r:1 w:0)
+	/// Storage: VaneAssetRegistry UnknownAssets (r:1 w:1)
+	/// Storage: VaneAssets Asset (r:1 w:1)
+	/// Storage: VaneAssets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	fn release_unknown_asset() -> Weight {
+		Weight::from_parts(56_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_asset() -> Weight {
+		Weight::from_parts(24_000_000, 3_900)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn update_asset() -> Weight {
+		Weight::from_parts(27_000_000, 3_900)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(16_000_000, 3_700)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn claim_unknown_asset() -> Weight {
+		Weight::from_parts(58_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn release_unknown_asset() -> Weight {
+		Weight::from_parts(56_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
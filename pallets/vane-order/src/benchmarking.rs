@@ -0,0 +1,56 @@
+// Benchmarks for Vane orders
+// `p` is the number of products the payee lists, `o` the number of orders the payer already placed
+
+use super::*;
+use crate::helper::Order;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
+use vane_register::helper::ProductProfile;
+
+const SEED: u32 = 0;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The ordered item is the payee's last listed product
+	#[benchmark]
+	fn place_order(
+		p: Linear<1, { <T as vane_register::Config>::MaxProducts::get() }>,
+		o: Linear<0, { T::MaxOrders::get() - 1 }>,
+	) {
+		let payer: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, SEED);
+
+		let products = (0..p)
+			.map(|id| ProductProfile::<T>::new(
+				Some(BoundedVec::truncate_from(vec![0u8; 64])),
+				100u32.into(),
+				id,
+				seller.clone(),
+				BoundedVec::truncate_from(vec![0u8; 64])
+			))
+			.collect::<Vec<_>>();
+		vane_register::PayeeProducts::<T>::insert(
+			&seller,
+			BoundedVec::<_, <T as vane_register::Config>::MaxProducts>::truncate_from(products)
+		);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let orders = (0..o)
+			.map(|no| Order::<T>::new(no + 1, 0, 100u32.into(), seller.clone(), payer.clone(), now, now))
+			.collect::<Vec<_>>();
+		let refs = (0..o).map(|no| (payer.clone(), 0, no + 1)).collect::<Vec<_>>();
+		PayerOrder::<T>::insert(&payer, BoundedVec::<_, T::MaxOrders>::truncate_from(orders));
+		PayeeOrderRef::<T>::insert(&seller, BoundedVec::<_, T::MaxOrders>::truncate_from(refs));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), p - 1, seller.clone());
+
+		assert_eq!(PayerOrder::<T>::decode_len(&payer), Some(o as usize + 1));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -1,12 +1,25 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod helper;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::helper::{Order, OrderStatus};
+	use crate::weights::WeightInfo;
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{Currency, ExistenceRequirement},
@@ -19,24 +32,32 @@ pub mod pallet {
 	pub trait Config: frame_system::Config + vane_register::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type Currency: Currency<Self::AccountId>;
+
+		// Orders kept per payer and order references kept per payee
+		#[pallet::constant]
+		type MaxOrders: Get<u32>;
+
+		type WeightInfo: WeightInfo;
 	}
 
 	//pub(super) type BalanceOf<T> = <<T as vane_register::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	// Orders and their references moved to bounded storage in v1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayerOrder<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<Order<T>>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<Order<T>, T::MaxOrders>, ValueQuery>;
 
 	// (PayersId, item_id, order_no)
 	// With payers id, item_id you can get the reference order from specific payeeId
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayeeOrderRef<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<(T::AccountId, u32, u32)>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<(T::AccountId, u32, u32), T::MaxOrders>, ValueQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -50,12 +71,16 @@ pub mod pallet {
 	pub enum Error<T> {
 		ProductDontExist,
 		UnexpectedError, // System Error
+		TooManyOrders,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order(
+			<T as vane_register::Config>::MaxProducts::get(),
+			T::MaxOrders::get()
+		))]
 		pub fn place_order(
 			origin: OriginFor<T>,
 			item_id: u32,
@@ -71,12 +96,17 @@ pub mod pallet {
 				.ok_or(Error::<T>::ProductDontExist)?;
 			let product_to_order = products.get(prod_pos).ok_or(Error::<T>::UnexpectedError)?;
 
-			// Take the existing order from the stated payee account and increment it
+			// Take the latest order with the stated payee account and increment it,
+			// the first order with this payee starts at 1
 			let order_vec_payer = PayerOrder::<T>::get(&payer);
 
-			let order = order_vec_payer.iter().find(|&ord| ord.payee_id == seller_id.clone());
+			let last_order_no = order_vec_payer
+				.iter()
+				.filter(|ord| ord.payee_id == seller_id)
+				.map(|ord| ord.order_no)
+				.max();
 
-			let order_no = order.unwrap().order_no + 1;
+			let order_no = last_order_no.unwrap_or(0).saturating_add(1);
 			let order_time = <frame_system::Pallet<T>>::block_number();
 			// For testing
 
@@ -93,11 +123,11 @@ pub mod pallet {
 			);
 
 			// Store to payer
-			PayerOrder::<T>::mutate(&payer, |vec| vec.push(order));
+			PayerOrder::<T>::try_mutate(&payer, |vec| vec.try_push(order))
+				.map_err(|_| Error::<T>::TooManyOrders)?;
 			// Store the reference to Payee storage item
-			PayeeOrderRef::<T>::mutate(&seller_id, |vec| {
-				vec.push((payer.clone(), item_id, order_no))
-			});
+			PayeeOrderRef::<T>::try_mutate(&seller_id, |vec| vec.try_push((payer.clone(), item_id, order_no)))
+				.map_err(|_| Error::<T>::TooManyOrders)?;
 			// Event
 			Self::deposit_event(Event::OrderPlaced {
 				buyer: payer,
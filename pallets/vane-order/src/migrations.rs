@@ -0,0 +1,67 @@
+// Storage migrations for Vane orders
+
+use super::*;
+use crate::helper::Order;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	// Moves the orders and their references into bounded vectors, keeping the most recent ones
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+
+			PayerOrder::<T>::translate::<Vec<Order<T>>, _>(|_, orders| {
+				translated += 1;
+				Some(keep_recent::<_, T::MaxOrders>(orders))
+			});
+
+			PayeeOrderRef::<T>::translate::<Vec<(T::AccountId, u32, u32)>, _>(|_, refs| {
+				translated += 1;
+				Some(keep_recent::<_, T::MaxOrders>(refs))
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let accounts = (PayerOrder::<T>::iter_keys().count() as u64, PayeeOrderRef::<T>::iter_keys().count() as u64);
+			Ok(accounts.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let (payers, payees) = <(u64, u64)>::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Values left over their bound fail to decode and are skipped
+			ensure!(PayerOrder::<T>::iter_values().count() as u64 == payers, "orders lost or over bound");
+			ensure!(PayeeOrderRef::<T>::iter_values().count() as u64 == payees, "order references lost or over bound");
+
+			Ok(())
+		}
+	}
+
+	fn keep_recent<V, S: Get<u32>>(mut values: Vec<V>) -> BoundedVec<V, S> {
+		let excess = values.len().saturating_sub(S::get() as usize);
+		values.drain(..excess);
+		BoundedVec::truncate_from(values)
+	}
+}
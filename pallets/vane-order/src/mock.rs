@@ -0,0 +1,116 @@
+// Test runtime for Vane orders
+
+use crate as vane_order;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, Everything},
+};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const PAYER: AccountId = 1;
+pub const PAYEE: AccountId = 2;
+pub const OTHER_PAYEE: AccountId = 3;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		VaneRegister: vane_register,
+		VaneOrder: vane_order,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+impl vane_register::Config for Test {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProducts = ConstU32<4>;
+	type MaxLinkLen = ConstU32<64>;
+	type WeightInfo = ();
+}
+
+impl vane_order::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MaxOrders = ConstU32<8>;
+	type WeightInfo = ();
+}
+
+// Registered payee listing `product_id`
+pub fn list_product(payee: AccountId, product_id: u32) {
+	assert_ok!(VaneRegister::register_payee(
+		RuntimeOrigin::signed(payee),
+		b"payee".to_vec(),
+		b"link".to_vec(),
+		b"location".to_vec()
+	));
+	assert_ok!(VaneRegister::update_products(RuntimeOrigin::signed(payee), product_id, b"link".to_vec(), 100, None));
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
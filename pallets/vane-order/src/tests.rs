@@ -0,0 +1,49 @@
+// Order placement tests
+
+use crate::{mock::*, Error, PayeeOrderRef, PayerOrder};
+use frame_support::{assert_noop, assert_ok};
+
+fn order_nos(payer: AccountId, payee: AccountId) -> Vec<u32> {
+	PayerOrder::<Test>::get(payer)
+		.iter()
+		.filter(|order| order.payee_id == payee)
+		.map(|order| order.order_no)
+		.collect()
+}
+
+#[test]
+fn first_order_with_a_payee_is_numbered_one() {
+	new_test_ext().execute_with(|| {
+		list_product(PAYEE, 7);
+
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+
+		assert_eq!(order_nos(PAYER, PAYEE), vec![1]);
+		assert_eq!(PayeeOrderRef::<Test>::get(PAYEE).into_inner(), vec![(PAYER, 7, 1)]);
+	});
+}
+
+#[test]
+fn orders_with_a_payee_are_numbered_in_sequence() {
+	new_test_ext().execute_with(|| {
+		list_product(PAYEE, 7);
+		list_product(OTHER_PAYEE, 7);
+
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, OTHER_PAYEE));
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+
+		assert_eq!(order_nos(PAYER, PAYEE), vec![1, 2, 3]);
+		assert_eq!(order_nos(PAYER, OTHER_PAYEE), vec![1]);
+	});
+}
+
+#[test]
+fn ordering_an_unlisted_product_fails() {
+	new_test_ext().execute_with(|| {
+		list_product(PAYEE, 7);
+
+		assert_noop!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 8, PAYEE), Error::<Test>::ProductDontExist);
+	});
+}
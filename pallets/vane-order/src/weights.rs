@@ -0,0 +1,50 @@
+//! Weights for `vane_order`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+//! `p` is the number of products the payee lists, `o` the number of orders already placed.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_order`.
+pub trait WeightInfo {
+	fn place_order(p: u32, o: u32, ) -> Weight;
+}
+
+/// Hand-written weights for `vane_order`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VaneRegister PayeeProducts (r:1 w:0)
+	/// Storage: VaneOrder PayerOrder (r:1 w:1)
+	/// Storage: VaneOrder PayeeOrderRef (r:1 w:1)
+	/// The range of component `p` is `[1, 255]`.
+	/// The range of component `o` is `[0, 255]`.
+	fn place_order(p: u32, o: u32, ) -> Weight {
+		Weight::from_parts(31_000_000, 4_200)
+			.saturating_add(Weight::from_parts(380_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(610_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(o.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn place_order(p: u32, o: u32, ) -> Weight {
+		Weight::from_parts(31_000_000, 4_200)
+			.saturating_add(Weight::from_parts(380_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(610_000, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(o.into()))
+	}
+}
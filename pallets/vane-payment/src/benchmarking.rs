@@ -0,0 +1,355 @@
+// Benchmarks for Vane payments
+// `r` is the number of receipts or order tickets the payee already holds, `p` the products the payee lists,
+// `o` the orders placed with the payee, `m` the executed payments kept for the payer
+// and `v` the council members who voted on a dispute
+
+use super::*;
+use crate::helper::{CallExecuted, TxnTicketOrder, XcmStatus};
+use frame_benchmarking::v2::*;
+use frame_support::{traits::{Currency, EnsureOrigin, ExistenceRequirement, Get}, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::{Percent, SaturatedConversion};
+use sp_std::{vec, vec::Vec};
+use vane_register::helper::ProductProfile;
+
+const SEED: u32 = 0;
+
+// Payment amount, well above the existential deposit the payer keeps free
+fn payment_amount<T: Config>() -> u128 {
+	let ed: u128 = <T as Config>::Currency::minimum_balance().saturated_into();
+	ed.saturating_mul(10).max(1_000_000)
+}
+
+fn funded_payer<T: Config>() -> T::AccountId {
+	let payer: T::AccountId = whitelisted_caller();
+	let balance = payment_amount::<T>().saturating_mul(100);
+	<T as Config>::Currency::make_free_balance_be(&payer, balance.saturated_into());
+	payer
+}
+
+// Payee already holding `r` receipts from other payers
+fn payee_with_receipts<T: Config>(r: u32) -> T::AccountId {
+	let payee: T::AccountId = account("payee", 0, SEED);
+	let filler: T::AccountId = account("filler", 0, SEED);
+
+	let receipts = (0..r)
+		.map(|_| TxnReceipt::<T>::new(
+			payee.clone(),
+			filler.clone(),
+			filler.clone(),
+			Default::default(),
+			payment_amount::<T>(),
+			payment_amount::<T>(),
+			None
+		))
+		.collect::<Vec<_>>();
+	PayeeTxnReceipt::<T>::insert(&payee, BoundedVec::<_, T::MaxPayeeReceipts>::truncate_from(receipts));
+
+	payee
+}
+
+// Payment waiting for the payee to confirm
+fn open_payment<T: Config>(r: u32) -> Result<(T::AccountId, T::AccountId, BoundedVec<u8, MAX_BYTES>), BenchmarkError> {
+	let payee = payee_with_receipts::<T>(r);
+	let payer = funded_payer::<T>();
+
+	Pallet::<T>::vane_pay(RawOrigin::Signed(payer.clone()).into(), payee.clone(), payment_amount::<T>(), None, None, None)?;
+
+	let reference = PayerTxnReceipt::<T>::iter_key_prefix(&payer)
+		.next()
+		.ok_or(BenchmarkError::Stop("payment not recorded"))?;
+
+	Ok((payer, payee, reference))
+}
+
+// Payment from a new payer which chose the legal team as its resolver
+fn payment_with_legal_team<T: Config>(
+	r: u32,
+) -> Result<(T::AccountId, T::AccountId, BoundedVec<u8, MAX_BYTES>, T::AccountId), BenchmarkError> {
+	let legal_team: T::AccountId = account("legal", 0, SEED);
+	ResolverSigner::<T>::put(&legal_team);
+
+	let payee = payee_with_receipts::<T>(r);
+	let payer = funded_payer::<T>();
+
+	Pallet::<T>::vane_pay(
+		RawOrigin::Signed(payer.clone()).into(),
+		payee.clone(),
+		payment_amount::<T>(),
+		None,
+		Some(ResolverChoice::LegalTeam),
+		None
+	)?;
+
+	let reference = PayerTxnReceipt::<T>::iter_key_prefix(&payer)
+		.next()
+		.ok_or(BenchmarkError::Stop("payment not recorded"))?;
+
+	Ok((payer, payee, reference, legal_team))
+}
+
+// Council of `v` members, each with a single vote
+fn council<T: Config>(v: u32) -> Vec<T::AccountId> {
+	(0..v)
+		.map(|i| {
+			let member: T::AccountId = account("council", i, SEED);
+			ResolverCouncil::<T>::insert(&member, 1);
+			member
+		})
+		.collect()
+}
+
+// Disputed payment waiting for the council's votes
+fn payment_under_vote<T: Config>(
+	v: u32,
+	r: u32,
+) -> Result<(T::AccountId, BoundedVec<u8, MAX_BYTES>, Vec<T::AccountId>), BenchmarkError> {
+	let members = council::<T>(v);
+
+	let payee = payee_with_receipts::<T>(r);
+	let payer = funded_payer::<T>();
+
+	Pallet::<T>::vane_pay(
+		RawOrigin::Signed(payer.clone()).into(),
+		payee.clone(),
+		payment_amount::<T>(),
+		None,
+		Some(ResolverChoice::Governance),
+		None
+	)?;
+
+	let reference = PayerTxnReceipt::<T>::iter_key_prefix(&payer)
+		.next()
+		.ok_or(BenchmarkError::Stop("payment not recorded"))?;
+	Pallet::<T>::raise_dispute(RawOrigin::Signed(payer.clone()).into(), reference.to_vec())?;
+
+	Ok((payer, reference, members))
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn vane_pay(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) {
+		let payee = payee_with_receipts::<T>(r);
+		let payer = funded_payer::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer), payee.clone(), payment_amount::<T>(), None, None, None);
+
+		assert_eq!(PayeeTxnReceipt::<T>::decode_len(&payee), Some(r as usize + 1));
+	}
+
+	// The ordered item is the seller's last listed product
+	#[benchmark]
+	fn vane_order_pay(
+		p: Linear<1, { <T as vane_register::Config>::MaxProducts::get() }>,
+		o: Linear<1, { <T as vane_order::Config>::MaxOrders::get() - 1 }>,
+		r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let buyer: T::AccountId = whitelisted_caller();
+		let seller: T::AccountId = account("seller", 0, SEED);
+
+		let ed = <T as vane_register::Config>::Currency::minimum_balance();
+		<T as vane_register::Config>::Currency::make_free_balance_be(&buyer, ed * 1_000u32.into());
+
+		let products = (0..p)
+			.map(|id| ProductProfile::<T>::new(
+				Some(BoundedVec::truncate_from(vec![0u8; 64])),
+				ed * 10u32.into(),
+				id,
+				seller.clone(),
+				BoundedVec::truncate_from(vec![0u8; 64])
+			))
+			.collect::<Vec<_>>();
+		vane_register::PayeeProducts::<T>::insert(
+			&seller,
+			BoundedVec::<_, <T as vane_register::Config>::MaxProducts>::truncate_from(products)
+		);
+
+		for _ in 0..o {
+			vane_order::Pallet::<T>::place_order(RawOrigin::Signed(buyer.clone()).into(), p - 1, seller.clone())?;
+		}
+
+		let tickets = (0..r)
+			.map(|_| TxnTicketOrder::<T>::new(seller.clone(), buyer.clone(), BoundedVec::truncate_from(vec![0u8; 6]), ed))
+			.collect::<Vec<_>>();
+		PayeeTxnTicketOrder::<T>::insert(&seller, BoundedVec::<_, T::MaxPayeeReceipts>::truncate_from(tickets));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), seller.clone(), p - 1, None);
+
+		assert_eq!(PayeeTxnTicketOrder::<T>::decode_len(&seller), Some(r as usize + 1));
+		Ok(())
+	}
+
+	// The payer's confirmation releases the payment
+	#[benchmark]
+	fn confirm_pay(m: Linear<0, { T::MaxAccountTxns::get() }>) -> Result<(), BenchmarkError> {
+		let (payer, payee, reference) = open_payment::<T>(0)?;
+		Pallet::<T>::confirm_pay(RawOrigin::Signed(payee.clone()).into(), Confirm::Payee, reference.to_vec())?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let executed = (0..m)
+			.map(|_| CallExecuted::<T>::new(payer.clone(), payee.clone(), payee.clone(), payee.clone(), Default::default(), now))
+			.collect::<Vec<_>>();
+		AccountMultiTxns::<T>::insert(&payer, BoundedVec::<_, T::MaxAccountTxns>::truncate_from(executed));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), Confirm::Payer, reference.to_vec());
+
+		assert_eq!(AccountMultiTxns::<T>::decode_len(&payer), Some((m as usize + 1).min(T::MaxAccountTxns::get() as usize)));
+		Ok(())
+	}
+
+	// A repeat offender changing their mind both pays the payee a penalty and is refunded the rest
+	#[benchmark]
+	fn revert_fund(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, payee, reference) = open_payment::<T>(r)?;
+		RevertedTxnPayer::<T>::insert(&payer, 1);
+		// An existing payee can receive the penalty
+		<T as Config>::Currency::make_free_balance_be(&payee, payment_amount::<T>().saturated_into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), reference.to_vec(), RevertReasons::ChangeOfDecision);
+
+		assert_eq!(RevertedTxnPayer::<T>::get(&payer), 2);
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Expired);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_resolver() -> Result<(), BenchmarkError> {
+		let origin = T::ResolverOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let legal_team: T::AccountId = account("legal", 0, SEED);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(legal_team.clone()));
+
+		assert_eq!(ResolverSigner::<T>::get(), Some(legal_team));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn raise_dispute() -> Result<(), BenchmarkError> {
+		let (_, payee, reference, _) = payment_with_legal_team::<T>(0)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payee), reference.to_vec());
+
+		assert!(Disputes::<T>::contains_key(&reference));
+		Ok(())
+	}
+
+	// A split both moves the payee's share and refunds the payer
+	#[benchmark]
+	fn resolve_dispute(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, _, reference, legal_team) = payment_with_legal_team::<T>(r)?;
+		Pallet::<T>::raise_dispute(RawOrigin::Signed(payer.clone()).into(), reference.to_vec())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(legal_team), reference.to_vec(), Verdict::Split(Percent::from_percent(50)));
+
+		assert!(!Disputes::<T>::contains_key(&reference));
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Completed);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_council_member() -> Result<(), BenchmarkError> {
+		let origin = T::ResolverOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let member: T::AccountId = account("council", 0, SEED);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, member.clone(), Some(1));
+
+		assert_eq!(ResolverCouncil::<T>::get(&member), Some(1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vote_dispute() -> Result<(), BenchmarkError> {
+		let (_, reference, members) = payment_under_vote::<T>(1, 0)?;
+		let voter = members[0].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), reference.to_vec(), Verdict::Split(Percent::from_percent(50)));
+
+		assert!(DisputeVoters::<T>::contains_key(&reference, &voter));
+		Ok(())
+	}
+
+	// Every member votes for a split, which both moves the payee's share and refunds the payer
+	#[benchmark]
+	fn close_dispute(
+		v: Linear<1, { T::MaxCouncilMembers::get() }>,
+		r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let (payer, reference, members) = payment_under_vote::<T>(v, r)?;
+		for member in members {
+			Pallet::<T>::vote_dispute(
+				RawOrigin::Signed(member).into(),
+				reference.to_vec(),
+				Verdict::Split(Percent::from_percent(50))
+			)?;
+		}
+
+		let tally = DisputeTallies::<T>::get(&reference).ok_or(BenchmarkError::Stop("vote not opened"))?;
+		frame_system::Pallet::<T>::set_block_number(tally.ends_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), reference.to_vec());
+
+		assert!(!DisputeTallies::<T>::contains_key(&reference));
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Completed);
+		Ok(())
+	}
+
+	// Single entry of the expiry queue processed in `on_idle`
+	#[benchmark]
+	fn expire_payment(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, _, reference) = open_payment::<T>(r)?;
+		let expiry = PaymentExpiry::<T>::get(&reference).ok_or(BenchmarkError::Stop("expiry not scheduled"))?;
+		frame_system::Pallet::<T>::set_block_number(expiry);
+
+		#[block]
+		{
+			Pallet::<T>::expire_payment(reference.clone())?;
+		}
+
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Expired);
+		Ok(())
+	}
+
+	// Pays the whole payment out of its multi_id to the payee
+	#[benchmark]
+	fn settle_review(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, _, reference) = open_payment::<T>(r)?;
+		let amount = payment_amount::<T>();
+
+		// Escrow left on the multi_id by a v1 payment
+		let signers = AllowedSigners::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("payment not open"))?;
+		let payee = signers.get_payee().clone();
+		let multi_id = Pallet::<T>::derive_multi_id(signers);
+		Pallet::<T>::release_held(&payer, &reference, amount)?;
+		<T as Config>::Currency::transfer(&payer, &multi_id, amount.saturated_into(), ExistenceRequirement::AllowDeath)?;
+		PaymentsUnderReview::<T>::insert(&reference, (payer.clone(), payee));
+
+		let origin = T::ResolverOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reference.to_vec(), amount, 0);
+
+		assert!(!PaymentsUnderReview::<T>::contains_key(&reference));
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Completed);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -5,9 +5,7 @@
 // Functionalities present
 // 1. Deriving multi account id
 // 2. Creation multi account id storage
-// 3. Defining of AccountSigner's object
-// 4. Defining AccountResolver's enum
-// 5.
+// 3. Driving the shared escrow engine, signers and receipts are defined in `vane_primitive::escrow`
 //
 
 use super::pallet::*;
@@ -24,202 +22,143 @@ pub mod utils {
 	use frame_support::{ dispatch::{
 		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
 		PostDispatchInfo, RawOrigin,
-	}, traits::{Currency, ExistenceRequirement}};
-	use frame_system::{Account, AccountInfo};
-	use sp_core::{ parameter_types};
-	use sp_io::hashing::blake2_256;
+	}, traits::{
+		fungible, fungibles,
+		tokens::{currency::Currency, DepositConsequence, Fortitude, Precision, Provenance, Restriction},
+		ExistenceRequirement,
+	}};
 	use sp_runtime::{
-		traits::{Dispatchable, StaticLookup, TrailingZeroInput, Zero},
-		DispatchError,
+		traits::{Dispatchable, One, Saturating, StaticLookup, TrailingZeroInput, Zero},
+		DispatchError, Perbill, Percent,
 	};
 	use sp_runtime::traits::UniqueSaturatedInto;
 	use vane_register::BalanceOf;
+	use crate::weights::WeightInfo;
 
 
-	// A struct by which it should be used as a source of signatures.
-	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	#[scale_info(skip_type_params(T))]
-	pub struct AccountSigners<T: Config> {
-		payee: T::AccountId,
-		payer: T::AccountId,
-		resolver: Option<Resolver<T>>,
-	}
+	// Types and rules shared with the other Vane payment pallets
+	pub use vane_primitive::escrow::{
+		Confirm, ReferenceNo, ResolverChoice, RevertReasons, Token, XcmStatus, MAX_BYTES, MAX_NO_TXNS,
+		MAX_REFERENCE_ATTEMPTS,
+	};
+	use vane_primitive::{AssetRegistry, CurrencyId};
+	use vane_primitive::escrow::{
+		self, ConfirmError, ConfirmOutcome, OnConfirmed, OnEscrowOpened, OnReleased, OnReverted,
+	};
 
-	// This will act as a dispute resolution methods. A user will have to choose which method
-	// is the best for a given dispute which may arise.
-	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub type AccountSigners<T> = escrow::AccountSigners<<T as frame_system::Config>::AccountId>;
+	pub type Resolver<T> = escrow::Resolver<<T as frame_system::Config>::AccountId>;
+	pub type TxnReceipt<T> = escrow::TxnReceipt<<T as frame_system::Config>::AccountId>;
+	pub type CallExecuted<T> = escrow::CallExecuted<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		BlockNumberFor<T>,
+	>;
+
+	// Ticket for Order as it uses BalanceOf<T> which depends on vane_register
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
-	pub enum Resolver<T: Config> {
-		// A legal team if chosen will be authorized to sign the transaction
-		LegalTeam(T::AccountId),
-		// A governance vote ( A Dao ) wil have to vote to favor which way the transaction
-		// should be signed
-		Governance,
-		//some future time feature
-		Both(T::AccountId),
-	}
 
-	// This should be used as a parameter for choosing which Resolving method should take place
-	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	pub enum ResolverChoice {
-		LegalTeam,
-		Governance,
+	pub struct TxnTicketOrder<T: Config> {
+		payee: T::AccountId,
+		payer: T::AccountId,
+		amount: BalanceOf<T>,
+		reference_no: BoundedVec<u8, MAX_BYTES>,
 	}
 
-	impl<T> AccountSigners<T>
-	where
-		T: Config,
-	{
+	impl<T: Config> TxnTicketOrder<T> {
 		pub fn new(
 			payee: T::AccountId,
 			payer: T::AccountId,
-			resolver: Option<Resolver<T>>,
+			ref_no: BoundedVec<u8, MAX_BYTES>,
+			amount: BalanceOf<T>,
 		) -> Self {
-			AccountSigners { payee, payer, resolver }
-		}
-		pub(super) fn get_payer(&self) -> &T::AccountId {
-			&self.payer
-		}
-
-		pub(super) fn get_payee(&self) -> &T::AccountId {
-			&self.payee
-		}
-
-		pub(super) fn get_resolver(&self) -> &Option<Resolver<T>> {
-			&self.resolver
+			Self { payee, payer, reference_no: ref_no, amount }
 		}
 
-		// refer here https://doc.rust-lang.org/stable/book/ch06-01-defining-an-enum.html?highlight=enum#enum-values
-		pub(super) fn get_legal_account(&self) -> Option<&T::AccountId> {
-			if let Some(Resolver::LegalTeam(account)) = &self.resolver {
-				Some(account)
-			} else {
-				None
-			}
+		// Tickets carry no status, a released payment keeps both confirmations and a refunded one drops its signers
+		pub fn is_settled(&self) -> bool {
+			!AllowedSigners::<T>::contains_key(&self.payer, &self.reference_no) ||
+				ConfirmedSigners::<T>::decode_len(&self.reference_no).unwrap_or(0) >= 2
 		}
 	}
 
-	// Call executed struct information
+	// Payment under arbitration, its escrow stays held until the resolver's verdict
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
-	pub struct CallExecuted<T: Config> {
-		payer: T::AccountId,
-		payee: T::AccountId,
-		allowed_multi_id: T::AccountId,
-		confirmed_multi_id: T::AccountId,
-		proof: T::Hash,
-		time: BlockNumberFor<T>,
+	pub struct Dispute<T: Config> {
+		pub payer: T::AccountId,
+		pub payee: T::AccountId,
+		pub raised_by: T::AccountId,
+		pub resolver: Resolver<T>,
+		pub opened_at: BlockNumberFor<T>,
 	}
 
-	impl<T> CallExecuted<T>
-	where
-		T: Config,
-	{
-		pub(super) fn new(
-			payer: T::AccountId,
-			payee: T::AccountId,
-			allowed_multi_id: T::AccountId,
-			confirmed_multi_id: T::AccountId,
-			proof: T::Hash,
-			time: BlockNumberFor<T>,
-		) -> Self {
-			CallExecuted { payer, payee, allowed_multi_id, confirmed_multi_id, proof, time }
-		}
-	}
-
-	// Revert Fund reasons enum
+	// Resolver's ruling on a disputed payment
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	pub enum RevertReasons {
-		// The fee will be refunded, the payer must show a proof of wrong address.
-		WrongPayeeAddress,
-		// We should introduce sort of punishment, This reason should be taken seriously and
-		// at the moment it should be only used in non trade operation.
-		ChangeOfDecision,
-		// Seller's fault, this is when a resolver intervene
-		PayeeMisbehaviour,
-	}
-
-	#[derive(Encode, Decode, Clone, PartialEq, Eq,MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub enum Token {
-		DOT,
-		USDT
-	}
-
-	#[derive(Encode, Decode, Clone, PartialEq, Eq,MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub enum XcmStatus {
-		Sent,
-		Completed,
-		Tbc
+	pub enum Verdict {
+		// The payee receives the whole payment
+		Release,
+		// The payer is refunded in full
+		Refund,
+		// The payee receives the given share, the rest is refunded
+		Split(Percent),
 	}
 
-	parameter_types! {
-		pub const MAX_BYTES: u8 = 50;
-		pub const MAX_NO_TXNS: u8 = 20;
+	impl Verdict {
+		// Only a full refund finds the payee at fault
+		pub fn revert_reason(&self) -> Option<RevertReasons> {
+			match self {
+				Verdict::Refund => Some(RevertReasons::PayeeMisbehaviour),
+				Verdict::Release | Verdict::Split(_) => None,
+			}
+		}
 	}
 
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug,MaxEncodedLen, TypeInfo)]
-	#[scale_info(skip_type_params(T))]
-
-	pub struct TxnReceipt<T: Config> {
-		payee: T::AccountId,
-		payer: T::AccountId,
-		pub multi_id: T::AccountId,
-		pub amount: u128,
-		pub reference_no: BoundedVec<u8,MAX_BYTES>,
-		currency: Option<Token>,
-		no_txn: BoundedVec<u128, MAX_NO_TXNS>,
-		pub xcm_status: XcmStatus
+	// Weighted council vote on a dispute which chose governance
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct DisputeTally<BlockNumber> {
+		pub release: u32,
+		pub refund: u32,
+		pub split: u32,
+		// Split shares weighted by their voters, the payee gets their average when split wins
+		pub split_shares: u64,
+		// Council weight when the vote opened, the quorum is measured against it
+		pub electorate: u32,
+		pub ends_at: BlockNumber,
 	}
 
-	impl<T: Config> TxnReceipt<T> {
-		pub fn new(
-			payee: T::AccountId,
-			payer: T::AccountId,
-			multi_id: T::AccountId,
-			ref_no: BoundedVec<u8,MAX_BYTES>,
-			amount: u128,
-			txn: u128,
-			currency:Option<Token>
-		) -> Self {
-
-			let mut no_txn = BoundedVec::new();
-			no_txn.to_vec().push(txn);
-
-			Self {
-				payee, payer, reference_no: ref_no,
-				amount,currency, no_txn,
-				xcm_status: XcmStatus::Tbc, multi_id
-			}
+	impl<BlockNumber> DisputeTally<BlockNumber> {
+		pub fn new(electorate: u32, ends_at: BlockNumber) -> Self {
+			Self { release: 0, refund: 0, split: 0, split_shares: 0, electorate, ends_at }
 		}
 
-		pub fn update_txn(&mut self, txn: (u128)){
-			self.no_txn.try_push(txn).unwrap() // Put error handling
+		pub fn add(&mut self, verdict: &Verdict, weight: u32) {
+			match verdict {
+				Verdict::Release => self.release = self.release.saturating_add(weight),
+				Verdict::Refund => self.refund = self.refund.saturating_add(weight),
+				Verdict::Split(share) => {
+					self.split = self.split.saturating_add(weight);
+					self.split_shares = self.split_shares
+						.saturating_add((share.deconstruct() as u64).saturating_mul(weight as u64));
+				},
+			}
 		}
 
-		pub fn update_amount(&mut self, amount: u128){
-			self.amount += amount
+		pub fn turnout(&self) -> u32 {
+			self.release.saturating_add(self.refund).saturating_add(self.split)
 		}
-	}
-
-	// Ticket for Order as it uses BalanceOf<T> which depends on vane_register
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-	#[scale_info(skip_type_params(T))]
-
-	pub struct TxnTicketOrder<T: Config> {
-		payee: T::AccountId,
-		payer: T::AccountId,
-		amount: BalanceOf<T>,
-		reference_no: Vec<u8>,
-	}
 
-	impl<T: Config> TxnTicketOrder<T> {
-		pub fn new(
-			payee: T::AccountId,
-			payer: T::AccountId,
-			ref_no: Vec<u8>,
-			amount: BalanceOf<T>,
-		) -> Self {
-			Self { payee, payer, reference_no: ref_no, amount }
+		// Heaviest verdict, ties go to the refund and then to the split
+		pub fn verdict(&self) -> Verdict {
+			if self.refund >= self.release && self.refund >= self.split {
+				Verdict::Refund
+			} else if self.split >= self.release {
+				let share = self.split_shares / (self.split as u64).max(1);
+				Verdict::Split(Percent::from_percent(share as u8))
+			} else {
+				Verdict::Release
+			}
 		}
 	}
 
@@ -229,29 +168,23 @@ pub mod utils {
 		PayerMisbehaviour,
 	}
 
-	// Confirmation enum which will be used to confirm the account_ids before dispatching multi-sig
-	// Call
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	pub enum Confirm {
-		Payer,
-		Payee,
-	}
-
 	impl<T: Config > Pallet<T> {
 		// Derive reference no
-		//#[cfg(feature = "std")]
+		// The per pair nonce makes every payment between the same accounts unique
 		pub fn derive_reference_no(
 			payer: T::AccountId,
 			payee: T::AccountId,
 			multi_id: T::AccountId,
-		) -> BoundedVec<u8,MAX_BYTES> {
-			let mut buffer = Vec::new();
-			buffer.append(&mut payer.using_encoded(blake2_256).to_vec());
-			buffer.append(&mut payee.using_encoded(blake2_256).to_vec());
-			buffer.append(&mut multi_id.using_encoded(blake2_256).to_vec());
+		) -> Result<BoundedVec<u8,MAX_BYTES>, Error<T>> {
+			let nonce = PairNonce::<T>::get(&payer,&payee);
+
+			let (reference_no, nonce) = escrow::derive_reference_no(
+				&payer, &payee, &multi_id, nonce,
+				|reference_no| ReceiptIndex::<T>::contains_key(reference_no)
+			).ok_or(Error::<T>::ReferenceCollision)?;
 
-			let reference = blake2_256(&buffer[..]);
-			return reference[20..26].to_vec().try_into().unwrap(); // Proper error handling should be done
+			PairNonce::<T>::insert(&payer,&payee,nonce);
+			Ok(reference_no)
 		}
 		// Call if there are all confirmed signers
 
@@ -259,79 +192,643 @@ pub mod utils {
 
 		// Inner functionality for the opening of multi-sig account
 
-		pub fn inner_vane_pay_wo_resolver(
+		pub fn inner_vane_pay(
 			payer: T::AccountId,
 			payee: T::AccountId,
 			amount: u128,
-			currency: Option<Token>
-		) -> DispatchResult {
-			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone(), None);
+			resolver: Option<Resolver<T>>,
+			currency: Option<CurrencyId>,
+			confirmation_window: Option<BlockNumberFor<T>>
+		) -> Result<ReferenceNo, DispatchError> {
+			// Payments are made in the native currency or in an enabled registered asset
+			if let Some(currency_id) = &currency {
+				ensure!(T::AssetRegistry::location(currency_id).is_some(), Error::<T>::UnsupportedCurrency);
+			}
+
+			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone(), resolver);
 			let multi_id = Self::derive_multi_id(accounts.clone());
 
-			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone());
+			// Every payment gets its own reference and receipt, even between the same pair
+			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone())?;
 
-			AllowedSigners::<T>::insert(&payer, ref_no.to_vec(), accounts);
+			AllowedSigners::<T>::insert(&payer, &ref_no, accounts);
 
 
 			let receipt =
-				TxnReceipt::<T>::new(payee.clone(), payer.clone(), multi_id.clone(),ref_no.clone(), amount,(amount),currency);
+				TxnReceipt::<T>::new(
+					payee.clone(),
+					payer.clone(),
+					multi_id.clone(),
+					ref_no.clone(),
+					amount,
+					amount,
+					currency.and_then(|currency_id| Token::try_from(currency_id).ok())
+				);
 			// Store to each storage item for txntickets
 			// Useful for getting reference no for TXN confirmation
-			// Start with the payee storage
-
-			PayeeTxnReceipt::<T>::mutate(&payee, |p_vec|{
-				// Check if the multi_id already exists in the receipts and get its index of the receipt
-				let index = p_vec.iter().position(|mut receipt| receipt.multi_id == multi_id);
-				if let Some(idx) = index {
-					// Get the receipt
-					let mut receipt = p_vec.get_mut(idx).ok_or(Error::<T>::UnexpectedError).unwrap();
-					receipt.update_txn(amount);
-					receipt.update_amount(amount);
-
-				}else{
-					p_vec.push(receipt.clone())
+			// Call weights are charged for a payee holding the maximum number of receipts
+			PayeeTxnReceipt::<T>::try_mutate(&payee, |p_vec| Self::push_payee_receipt(p_vec, receipt.clone()))?;
+
+			PayerTxnReceipt::<T>::insert(&payer,&ref_no,receipt);
+
+			ReceiptIndex::<T>::insert(&ref_no, (payer.clone(), payee.clone()));
+
+			if let Some(currency_id) = currency {
+				PaymentAsset::<T>::insert(&ref_no, currency_id);
+			}
+
+			Self::hold_payment(payer.clone(), amount, &ref_no)?;
+
+			// Refund the payer if the payee does not confirm in time
+			Self::schedule_expiry(ref_no.clone(), confirmation_window)?;
+
+			T::OnEscrowOpened::on_escrow_opened(&payer, &payee, &ref_no, amount);
+
+			Ok(ref_no)
+		}
+
+		// The payee confirms first, the payment is released as soon as the payer confirms after
+		pub fn confirm_payment(
+			who: T::AccountId,
+			role: Confirm,
+			reference_no: ReferenceNo,
+		) -> Result<ConfirmOutcome<T::AccountId>, DispatchError> {
+			// A disputed payment is settled by its resolver only
+			ensure!(!Disputes::<T>::contains_key(&reference_no), Error::<T>::PaymentDisputed);
+			ensure!(!PaymentsUnderReview::<T>::contains_key(&reference_no), Error::<T>::PaymentUnderReview);
+
+			let (payer, _) =
+				ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::TxnReceiptUnavailable)?;
+			let allowed_signers = AllowedSigners::<T>::get(&payer, &reference_no)
+				.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
+
+			let outcome = ConfirmedSigners::<T>::try_mutate(&reference_no, |signers| {
+				escrow::confirm(signers, &allowed_signers, who.clone(), role.clone())
+			})
+			.map_err(|error| match error {
+				ConfirmError::PayeeAlreadyConfirmed => Error::<T>::PayeeAlreadyConfirmed,
+				ConfirmError::WaitForPayeeToConfirm => Error::<T>::WaitForPayeeToConfirm,
+				ConfirmError::ExceededSigners => Error::<T>::ExceededSigners,
+				ConfirmError::NotThePayee => Error::<T>::NotThePayee,
+				ConfirmError::NotThePayer => Error::<T>::NotThePayer,
+			})?;
+
+			T::OnConfirmed::on_confirmed(&who, &role, &reference_no);
+
+			let time = <frame_system::Pallet<T>>::block_number();
+
+			match outcome {
+				ConfirmOutcome::PayeeConfirmed => {
+					Self::deposit_event(Event::PayeeAddressConfirmed {
+						account_id: who,
+						timestamp: time,
+						reference_no: reference_no.to_vec(),
+					});
+				},
+				ConfirmOutcome::Confirmed { .. } => {
+					Self::deposit_event(Event::PayerAddressConfirmed {
+						account_id: who,
+						timestamp: time,
+						reference_no: reference_no.to_vec(),
+					});
+
+					Self::release_payment(reference_no)?;
+				},
+			};
+
+			Ok(outcome)
+		}
+
+		// Pays out once the confirmed signers match the ones allowed when the payment was opened
+		pub fn release_payment(reference_no: ReferenceNo) -> DispatchResult {
+			// Its escrow is not held on the payer, the hold of another payment would pay it out
+			ensure!(!PaymentsUnderReview::<T>::contains_key(&reference_no), Error::<T>::PaymentUnderReview);
+
+			let signers = ConfirmedSigners::<T>::get(&reference_no);
+			let (Some(payee), Some(payer)) = (signers.get(0).cloned(), signers.get(1).cloned()) else {
+				return Err(Error::<T>::WaitForPayerToConfirm.into())
+			};
+
+			let allowed_signers = AllowedSigners::<T>::get(&payer, &reference_no)
+				.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
+
+			// The resolver chosen by the payer is part of the payment's multi_id
+			let confirmed_multi_id = Self::derive_multi_id(AccountSigners::<T>::new(
+				payee.clone(),
+				payer.clone(),
+				allowed_signers.get_resolver().clone(),
+			));
+			let allowed_multi_id = Self::derive_multi_id(allowed_signers);
+
+			ensure!(confirmed_multi_id == allowed_multi_id, Error::<T>::FailedToMatchAccounts);
+
+			let proof = escrow::confirmation_proof(&allowed_multi_id, &confirmed_multi_id)
+				.ok_or(Error::<T>::UnexpectedError)?;
+
+			Self::dispatch_transfer_call(
+				proof,
+				payer.clone(),
+				payee.clone(),
+				&reference_no,
+				allowed_multi_id,
+				confirmed_multi_id,
+			)?;
+
+			Self::set_receipt_status(&payer, &payee, &reference_no, XcmStatus::Completed);
+
+			let amount = Self::escrowed_amount(&payer, &payee, &reference_no)?;
+			T::OnReleased::on_released(&payer, &payee, &reference_no, amount);
+
+			Ok(())
+		}
+
+		// Refunds a payment before it is released.
+		// The payee never confirming proves a wrong address, a change of decision pays the payee a penalty.
+		// Misbehaviour is taken to the payment's resolver and counts against the payee once it signs off
+		pub fn revert_payment(
+			payer: T::AccountId,
+			reference_no: ReferenceNo,
+			reason: RevertReasons,
+		) -> DispatchResult {
+			let (_, payee) =
+				ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::TxnReceiptUnavailable)?;
+			// Order payments keep a ticket instead of a receipt
+			let amount = Self::escrowed_amount(&payer, &payee, &reference_no)?;
+			let settled = PayerTxnReceipt::<T>::get(&payer, &reference_no)
+				.map_or(false, |receipt| receipt.xcm_status != XcmStatus::Tbc);
+			ensure!(!PaymentsUnderReview::<T>::contains_key(&reference_no), Error::<T>::PaymentUnderReview);
+
+			if reason == RevertReasons::PayeeMisbehaviour {
+				return Self::open_dispute(payer, reference_no)
+			}
+
+			// Released or already refunded payments cannot be reverted
+			ensure!(
+				!settled && AllowedSigners::<T>::contains_key(&payer, &reference_no),
+				Error::<T>::NotAllowedPayeeOrPaymentNotInitialized
+			);
+			ensure!(!Disputes::<T>::contains_key(&reference_no), Error::<T>::PaymentDisputed);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let offences = RevertedTxnPayer::<T>::get(&payer);
+
+			let penalty = match reason {
+				RevertReasons::WrongPayeeAddress => {
+					ensure!(
+						ConfirmedSigners::<T>::decode_len(&reference_no).unwrap_or(0) == 0,
+						Error::<T>::PayeeAlreadyConfirmed
+					);
+					ensure!(
+						RevertCooldowns::<T>::get(&payer).map_or(true, |until| now >= until),
+						Error::<T>::RevertOnCooldown
+					);
+					0
+				},
+				_ => {
+					let penalty = Self::revert_penalty(offences).mul_floor(amount);
+
+					// A penalty the payee cannot receive, e.g. below the existential deposit, is waived
+					match Self::can_receive(&payee, &reference_no, penalty)? {
+						true => penalty,
+						false => 0,
+					}
+				},
+			};
+			let refunded = amount.saturating_sub(penalty);
+
+			Self::transfer_held(&payer, &payee, &reference_no, penalty)?;
+			Self::release_held(&payer, &reference_no, refunded)?;
+			PaymentAsset::<T>::remove(&reference_no);
+
+			// The payee can no longer confirm this payment
+			AllowedSigners::<T>::remove(&payer, &reference_no);
+			ConfirmedSigners::<T>::remove(&reference_no);
+
+			// Refunded payments settle like expired ones
+			Self::set_receipt_status(&payer, &payee, &reference_no, XcmStatus::Expired);
+
+			let offences = offences.saturating_add(1);
+			RevertedTxnPayer::<T>::insert(&payer, offences);
+
+			if reason == RevertReasons::WrongPayeeAddress {
+				let cooldown = T::RevertCooldown::get().saturating_mul(offences.into());
+				RevertCooldowns::<T>::insert(&payer, now.saturating_add(cooldown));
+			}
+
+			T::OnReverted::on_reverted(&payer, &payee, &reference_no, refunded, Some(reason.clone()));
+
+			Self::deposit_event(Event::FundsReverted {
+				reference_no: reference_no.to_vec(),
+				payer,
+				reason,
+				amount: refunded,
+				penalty,
+			});
+
+			Ok(())
+		}
+
+		// Share of the payment a payer changing their mind leaves, growing with the reverts they already made
+		pub fn revert_penalty(offences: u32) -> Perbill {
+			let parts = T::RevertPenalty::get().deconstruct().saturating_mul(offences.saturating_add(1));
+			Perbill::from_parts(parts).min(T::MaxRevertPenalty::get())
+		}
+
+		pub fn schedule_expiry(
+			reference_no: BoundedVec<u8, MAX_BYTES>,
+			confirmation_window: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let window = confirmation_window.unwrap_or_else(T::ConfirmationWindow::get);
+			ensure!(
+				!window.is_zero() && window <= T::MaxConfirmationWindow::get(),
+				Error::<T>::InvalidConfirmationWindow
+			);
+
+			let expiry = <frame_system::Pallet<T>>::block_number().saturating_add(window);
+
+			ExpiryQueue::<T>::try_mutate(expiry, |queue| queue.try_push(reference_no.clone()))
+				.map_err(|_| Error::<T>::ExpiryQueueFull)?;
+			PaymentExpiry::<T>::insert(&reference_no, expiry);
+
+			// Processing resumes from the earliest scheduled block,
+			// a short window opened after a longer one expires before it
+			ExpiryCursor::<T>::mutate(|cursor| *cursor = Some(cursor.map_or(expiry, |cursor| cursor.min(expiry))));
+
+			Self::deposit_event(Event::ExpiryScheduled { reference_no: reference_no.to_vec(), expiry });
+
+			Ok(())
+		}
+
+		// Walks the expiry queue up to `now`, stopping once the remaining weight is used.
+		// Unprocessed entries stay queued and the cursor resumes from them next block
+		pub fn process_expiry_queue(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let Some(mut cursor) = ExpiryCursor::<T>::get() else { return Weight::zero() };
+
+			let queue_weight = T::DbWeight::get().reads_writes(2, 2);
+			let entry_weight = Self::expiry_weight();
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+
+			while cursor <= now {
+				if used.saturating_add(queue_weight).any_gt(remaining_weight) {
+					break;
+				}
+				used = used.saturating_add(queue_weight);
+
+				let mut queue = ExpiryQueue::<T>::take(cursor);
+
+				while let Some(reference_no) = queue.last().cloned() {
+					if used.saturating_add(entry_weight).any_gt(remaining_weight) {
+						break;
+					}
+					used = used.saturating_add(entry_weight);
+					queue.pop();
+
+					// A failing refund must not leave a partially expired payment behind
+					let result = frame_support::storage::with_storage_layer(|| {
+						Self::expire_payment(reference_no.clone())
+					});
+
+					if let Err(error) = result {
+						log::warn!(
+							target: "vane-payment",
+							"Failed to expire payment {:?}: {:?}",
+							reference_no, error,
+						);
+
+						// The payment stays open, its expiry is retried next block while that block has room
+						let retry = now.saturating_add(One::one());
+						let retry_at = ExpiryQueue::<T>::try_mutate(retry, |queue| queue.try_push(reference_no.clone()))
+							.ok()
+							.map(|_| retry);
+
+						Self::deposit_event(Event::ExpiryFailed { reference_no: reference_no.to_vec(), error, retry_at });
+					}
+				}
+
+				if !queue.is_empty() {
+					ExpiryQueue::<T>::insert(cursor, queue);
+					break;
+				}
+
+				cursor = cursor.saturating_add(One::one());
+			}
+
+			ExpiryCursor::<T>::put(cursor);
+
+			used
+		}
+
+		// A full payee list drops its oldest settled receipt, open payments are never dropped
+		pub fn push_payee_receipt(
+			receipts: &mut BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>,
+			receipt: TxnReceipt<T>
+		) -> Result<(), Error<T>> {
+			if receipts.len() >= T::MaxPayeeReceipts::get() as usize {
+				let settled = receipts.iter()
+					.position(|receipt| receipt.is_settled())
+					.ok_or(Error::<T>::TooManyReceipts)?;
+				receipts.remove(settled);
+			}
+
+			receipts.try_push(receipt).map_err(|_| Error::<T>::TooManyReceipts)
+		}
+
+		pub fn set_receipt_status(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>,
+			status: XcmStatus
+		) {
+			PayerTxnReceipt::<T>::mutate(payer, reference_no, |maybe_receipt| {
+				if let Some(receipt) = maybe_receipt {
+					receipt.xcm_status = status.clone();
 				}
 			});
 
-			// Update for Payer/Sender Txn receipt
-			let existing_payer_receipt = PayerTxnReceipt::<T>::get(&payer,&payee);
+			PayeeTxnReceipt::<T>::mutate(payee, |p_vec| {
+				p_vec.iter_mut()
+					.filter(|receipt| &receipt.reference_no == reference_no)
+					.for_each(|receipt| receipt.xcm_status = status.clone());
+			});
+		}
+
+		// Refunds a payment whose confirmation window passed
+		pub fn expire_payment(reference_no: BoundedVec<u8, MAX_BYTES>) -> DispatchResult {
+			let (payer, payee) =
+				ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::TxnReceiptUnavailable)?;
+			// Order payments keep a ticket instead of a receipt
+			let settled = PayerTxnReceipt::<T>::get(&payer, &reference_no)
+				.map_or(false, |receipt| receipt.xcm_status != XcmStatus::Tbc);
+
+			let expired = PaymentExpiry::<T>::get(&reference_no)
+				.map_or(false, |expiry| expiry <= <frame_system::Pallet<T>>::block_number());
+			if expired {
+				PaymentExpiry::<T>::remove(&reference_no);
+			}
+
+			// Confirmed, reverted, disputed or reviewed payments are left alone
+			if settled ||
+				!AllowedSigners::<T>::contains_key(&payer, &reference_no) ||
+				ConfirmedSigners::<T>::get(&reference_no).len() >= 2 ||
+				Disputes::<T>::contains_key(&reference_no) ||
+				PaymentsUnderReview::<T>::contains_key(&reference_no)
+			{
+				return Ok(())
+			}
+
+			// Only refunded once its confirmation window passed
+			ensure!(expired, Error::<T>::PaymentNotExpired);
 
-			if let Some(receipt) = existing_payer_receipt {
+			let amount = Self::escrowed_amount(&payer, &payee, &reference_no)?;
+			Self::release_held(&payer, &reference_no, amount)?;
+			PaymentAsset::<T>::remove(&reference_no);
 
-				PayerTxnReceipt::<T>::mutate(&payer, &payee, |receipt_inner|{
-					receipt_inner.clone().unwrap().update_txn(amount)
-				});
+			AllowedSigners::<T>::remove(&payer, &reference_no);
+			ConfirmedSigners::<T>::remove(&reference_no);
 
-			}else{
+			Self::set_receipt_status(&payer, &payee, &reference_no, XcmStatus::Expired);
 
-				PayerTxnReceipt::<T>::insert(&payer,&payee,receipt);
+			T::OnReverted::on_reverted(&payer, &payee, &reference_no, amount, None);
+
+			Self::deposit_event(Event::PaymentExpired {
+				reference_no: reference_no.to_vec(),
+				payer,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		// Upper bound of a single expiry, receipts, signers and the refund transfer
+		pub fn expiry_weight() -> Weight {
+			<T as Config>::WeightInfo::expire_payment(T::MaxPayeeReceipts::get())
+		}
+
+		// Disputes
+
+		// Resolver the payer chose when opening a payment
+		pub fn choose_resolver(choice: Option<ResolverChoice>) -> Result<Option<Resolver<T>>, Error<T>> {
+			match choice {
+				None => Ok(None),
+				Some(ResolverChoice::LegalTeam) => {
+					let legal_team = ResolverSigner::<T>::get().ok_or(Error::<T>::NoLegalTeam)?;
+					Ok(Some(Resolver::LegalTeam(legal_team)))
+				},
+				Some(ResolverChoice::Governance) => {
+					ensure!(ResolverCouncil::<T>::count() > 0, Error::<T>::NoResolverCouncil);
+					Ok(Some(Resolver::Governance))
+				},
 			}
+		}
 
+		// Either side of an open payment can take it to the resolver the payer chose
+		pub fn open_dispute(who: T::AccountId, reference_no: ReferenceNo) -> DispatchResult {
+			let (payer, payee) =
+				ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::TxnReceiptUnavailable)?;
+			ensure!(who == payer || who == payee, Error::<T>::NotAPaymentParticipant);
+			ensure!(!Disputes::<T>::contains_key(&reference_no), Error::<T>::AlreadyDisputed);
+			ensure!(!PaymentsUnderReview::<T>::contains_key(&reference_no), Error::<T>::PaymentUnderReview);
+
+			// Released payments keep both confirmations, refunded ones drop their signers
+			let signers = AllowedSigners::<T>::get(&payer, &reference_no).ok_or(Error::<T>::PaymentNotOpen)?;
+			ensure!(ConfirmedSigners::<T>::decode_len(&reference_no).unwrap_or(0) < 2, Error::<T>::PaymentNotOpen);
+
+			let resolver = signers.get_resolver().clone().ok_or(Error::<T>::NoResolverForPayment)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			match &resolver {
+				Resolver::LegalTeam(_) => (),
+				// The council votes from now on, the weight of its current members is the electorate
+				Resolver::Governance => {
+					let electorate = ResolverCouncil::<T>::iter_values().fold(0u32, |total, weight| total.saturating_add(weight));
+					ensure!(electorate > 0, Error::<T>::NoResolverCouncil);
+
+					let ends_at = now + T::VotingPeriod::get();
+					DisputeTallies::<T>::insert(&reference_no, DisputeTally::new(electorate, ends_at));
+
+					Self::deposit_event(Event::DisputeVoteOpened {
+						reference_no: reference_no.to_vec(),
+						electorate,
+						ends_at,
+					});
+				},
+				Resolver::Both(_) => Err(Error::<T>::ResolverNotSupported)?,
+			}
 
-			Self::create_multi_account(multi_id.clone())?;
+			Disputes::<T>::insert(&reference_no, Dispute::<T> {
+				payer,
+				payee,
+				raised_by: who.clone(),
+				resolver: resolver.clone(),
+				opened_at: now,
+			});
 
-			let time = <frame_system::Pallet<T>>::block_number();
+			Self::deposit_event(Event::DisputeRaised {
+				reference_no: reference_no.to_vec(),
+				raised_by: who,
+				resolver,
+			});
 
-			Self::deposit_event(Event::MultiAccountCreated {
-				account_id: multi_id.clone(),
-				timestamp: time,
+			Ok(())
+		}
+
+		// Council member's vote, weighted by the member's council weight
+		pub fn cast_vote(who: T::AccountId, reference_no: ReferenceNo, verdict: Verdict) -> DispatchResult {
+			let weight = ResolverCouncil::<T>::get(&who).ok_or(Error::<T>::NotACouncilMember)?;
+			ensure!(!DisputeVoters::<T>::contains_key(&reference_no, &who), Error::<T>::AlreadyVoted);
+
+			DisputeTallies::<T>::try_mutate(&reference_no, |maybe_tally| -> DispatchResult {
+				let tally = maybe_tally.as_mut().ok_or(Error::<T>::DisputeVoteNotFound)?;
+				ensure!(<frame_system::Pallet<T>>::block_number() < tally.ends_at, Error::<T>::VotingClosed);
+
+				tally.add(&verdict, weight);
+				Ok(())
+			})?;
+
+			DisputeVoters::<T>::insert(&reference_no, &who, verdict.clone());
+
+			Self::deposit_event(Event::DisputeVoted {
+				reference_no: reference_no.to_vec(),
+				voter: who,
+				verdict,
+				weight,
 			});
 
-			let balance: BalanceOfPay<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+			Ok(())
+		}
 
-			// Transfer balance from Payer to Multi_Id
-			<T as Config>::Currency::transfer(
-				&payer,
-				&multi_id,
-				balance,
-				ExistenceRequirement::KeepAlive,
-			)?;
+		// Settles a dispute once its voting period is over, the payer is refunded when the quorum is not met
+		pub fn close_vote(reference_no: ReferenceNo) -> DispatchResult {
+			let tally = DisputeTallies::<T>::get(&reference_no).ok_or(Error::<T>::DisputeVoteNotFound)?;
+			ensure!(<frame_system::Pallet<T>>::block_number() >= tally.ends_at, Error::<T>::VotingOngoing);
 
-			Self::deposit_event(Event::BalanceTransferredAndLocked {
-				to_multi_id: multi_id,
-				from: payer,
-				timestamp: time,
-				reference_no: ref_no.to_vec(),
+			let turnout = tally.turnout();
+			let quorum = T::GovernanceQuorum::get().mul_ceil(tally.electorate);
+			let quorum_reached = turnout >= quorum;
+
+			let (verdict, reason) = if quorum_reached {
+				let verdict = tally.verdict();
+				let reason = verdict.revert_reason();
+				(verdict, reason)
+			} else {
+				// Nobody is at fault when the council did not decide
+				(Verdict::Refund, None)
+			};
+
+			let (released, refunded) = Self::settle_dispute(&reference_no, &verdict, reason)?;
+
+			DisputeTallies::<T>::remove(&reference_no);
+			let _ = DisputeVoters::<T>::clear_prefix(&reference_no, T::MaxCouncilMembers::get(), None);
+
+			Self::deposit_event(Event::DisputeVoteClosed {
+				reference_no: reference_no.to_vec(),
+				verdict,
+				turnout,
+				quorum_reached,
+				released,
+				refunded,
+			});
+
+			Ok(())
+		}
+
+		// A legal team which did not rule within `DisputeTimeout` leaves the payer refunded, nobody is at fault
+		pub fn refund_unruled_dispute(reference_no: ReferenceNo) -> Result<u128, DispatchError> {
+			let dispute = Disputes::<T>::get(&reference_no).ok_or(Error::<T>::DisputeNotFound)?;
+			// Council disputes are closed by their vote
+			ensure!(matches!(dispute.resolver, Resolver::LegalTeam(_)), Error::<T>::NotALegalTeamDispute);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= dispute.opened_at.saturating_add(T::DisputeTimeout::get()),
+				Error::<T>::DisputeNotTimedOut
+			);
+
+			let (_, refunded) = Self::settle_dispute(&reference_no, &Verdict::Refund, None)?;
+
+			Ok(refunded)
+		}
+
+		// Moves the payee's share of the escrow to the payee and refunds the rest.
+		// A full refund counts against the payee when the ruling gives a `reason`. Returns the released and refunded amounts
+		pub fn settle_dispute(
+			reference_no: &ReferenceNo,
+			verdict: &Verdict,
+			reason: Option<RevertReasons>,
+		) -> Result<(u128, u128), DispatchError> {
+			let dispute = Disputes::<T>::take(reference_no).ok_or(Error::<T>::DisputeNotFound)?;
+			let (payer, payee) = (dispute.payer, dispute.payee);
+
+			let amount = Self::escrowed_amount(&payer, &payee, reference_no)?;
+			let released = match verdict {
+				Verdict::Release => amount,
+				Verdict::Refund => 0,
+				Verdict::Split(share) => share.mul_floor(amount),
+			};
+			// A share the payee cannot receive, e.g. below the existential deposit, is refunded instead
+			let released = match Self::can_receive(&payee, reference_no, released)? {
+				true => released,
+				false => 0,
+			};
+			let refunded = amount.saturating_sub(released);
+
+			Self::transfer_held(&payer, &payee, reference_no, released)?;
+			Self::release_held(&payer, reference_no, refunded)?;
+			PaymentAsset::<T>::remove(reference_no);
+
+			// Neither side can confirm, revert or dispute the payment anymore
+			AllowedSigners::<T>::remove(&payer, reference_no);
+			ConfirmedSigners::<T>::remove(reference_no);
+
+			if released.is_zero() {
+				Self::set_receipt_status(&payer, &payee, reference_no, XcmStatus::Expired);
+				if reason.is_some() {
+					RevertedTxnPayee::<T>::mutate(&payee, |count| *count = count.saturating_add(1));
+				}
+				T::OnReverted::on_reverted(&payer, &payee, reference_no, refunded, reason);
+			} else {
+				Self::set_receipt_status(&payer, &payee, reference_no, XcmStatus::Completed);
+				T::OnReleased::on_released(&payer, &payee, reference_no, released);
+			}
+
+			Ok((released, refunded))
+		}
+
+		// Payments under review
+
+		// Pays out a payment the v2 migration listed for review. Its escrow is whatever the v1 release
+		// left on the payment's multi_id, `released` goes to the payee and `refunded` to the payer
+		pub fn settle_reviewed_payment(reference_no: &ReferenceNo, released: u128, refunded: u128) -> DispatchResult {
+			let (payer, payee) = PaymentsUnderReview::<T>::get(reference_no).ok_or(Error::<T>::NotUnderReview)?;
+
+			let amount = Self::escrowed_amount(&payer, &payee, reference_no)?;
+			ensure!(released.saturating_add(refunded) <= amount, Error::<T>::SettlementExceedsPayment);
+
+			let signers = AllowedSigners::<T>::get(&payer, reference_no).ok_or(Error::<T>::PaymentNotOpen)?;
+			let multi_id = Self::derive_multi_id(signers);
+
+			for (who, share) in [(&payee, released), (&payer, refunded)] {
+				if share.is_zero() {
+					continue
+				}
+				let balance: BalanceOfPay<T> = share.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+				<T as Config>::Currency::transfer(&multi_id, who, balance, ExistenceRequirement::AllowDeath)?;
+			}
+
+			PaymentsUnderReview::<T>::remove(reference_no);
+			AllowedSigners::<T>::remove(&payer, reference_no);
+			ConfirmedSigners::<T>::remove(reference_no);
+
+			if released.is_zero() {
+				Self::set_receipt_status(&payer, &payee, reference_no, XcmStatus::Expired);
+				T::OnReverted::on_reverted(&payer, &payee, reference_no, refunded, None);
+			} else {
+				Self::set_receipt_status(&payer, &payee, reference_no, XcmStatus::Completed);
+				T::OnReleased::on_released(&payer, &payee, reference_no, released);
+			}
+
+			Self::deposit_event(Event::ReviewSettled {
+				reference_no: reference_no.to_vec(),
+				released,
+				refunded,
 			});
 
 			Ok(())
@@ -339,85 +836,222 @@ pub mod utils {
 
 		// For orders type payment
 
-		pub(crate) fn inner_vane_order_pay_wo_resolver(
+		pub(crate) fn inner_vane_order_pay(
 			payer: T::AccountId,
 			payee: T::AccountId,
 			amount: BalanceOf<T>, // type alias for vane_register
+			resolver: Option<Resolver<T>>,
 		) -> DispatchResult {
-			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone(), None);
+			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone(), resolver);
 			let multi_id = Self::derive_multi_id(accounts.clone());
 
-			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone());
+			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone())?;
+
+			// Reserve the reference so later payments skip it
+			ReceiptIndex::<T>::insert(&ref_no, (payer.clone(), payee.clone()));
 
 			// Double keys to allow multiple txns
-			AllowedSigners::<T>::insert(&payer, ref_no.to_vec(), accounts);
+			AllowedSigners::<T>::insert(&payer, &ref_no, accounts);
 
 			let ticket =
-				TxnTicketOrder::new(payee.clone(), payer.clone(), ref_no.clone().to_vec(), amount.clone());
+				TxnTicketOrder::new(payee.clone(), payer.clone(), ref_no.clone(), amount.clone());
 			// Store to each storage item for txntickets
 			// Useful for getting refrence no for TXN confirmation
-			PayeeTxnTicketOrder::<T>::mutate(&payee, |p_vec| p_vec.push(ticket.clone()));
+			// Call weights are charged for a payee holding the maximum number of tickets
+			PayeeTxnTicketOrder::<T>::try_mutate(&payee, |p_vec| Self::push_order_ticket(p_vec, ticket.clone()))?;
 
-			PayerTxnTicketOrder::<T>::mutate(&payer, &payee, |p_vec| p_vec.push(ticket.clone()));
+			PayerTxnTicketOrder::<T>::try_mutate(&payer, &payee, |p_vec| Self::push_order_ticket(p_vec, ticket.clone()))?;
 
-			Self::create_multi_account(multi_id.clone())?;
+			let amount: u128 = amount.unique_saturated_into();
+			Self::hold_payment(payer.clone(), amount, &ref_no)?;
 
-			let time = <frame_system::Pallet<T>>::block_number();
+			// Refund the payer if the payee does not confirm in time
+			Self::schedule_expiry(ref_no.clone(), None)?;
 
-			Self::deposit_event(Event::MultiAccountCreated {
-				account_id: multi_id.clone(),
-				timestamp: time,
-			});
+			T::OnEscrowOpened::on_escrow_opened(&payer, &payee, &ref_no, amount);
 
-			// Transfer balance from Payer to Multi_Id
-			<T as vane_register::Config>::Currency::transfer(
-				&payer,
-				&multi_id,
-				amount,
-				ExistenceRequirement::KeepAlive,
-			)?;
+			Ok(())
+		}
 
-			Self::deposit_event(Event::BalanceTransferredAndLocked {
-				to_multi_id: multi_id,
+		// A full ticket list drops its oldest settled ticket, like `push_payee_receipt`
+		pub fn push_order_ticket(
+			tickets: &mut BoundedVec<TxnTicketOrder<T>, T::MaxPayeeReceipts>,
+			ticket: TxnTicketOrder<T>
+		) -> Result<(), Error<T>> {
+			if tickets.len() >= T::MaxPayeeReceipts::get() as usize {
+				let settled = tickets.iter()
+					.position(|ticket| ticket.is_settled())
+					.ok_or(Error::<T>::TooManyReceipts)?;
+				tickets.remove(settled);
+			}
+
+			tickets.try_push(ticket).map_err(|_| Error::<T>::TooManyReceipts)
+		}
+
+		// Escrow
+
+		// The payment stays on the payer's account, held until it is released or refunded.
+		// Native payments are held by `T::Escrow`, asset payments by `T::Assets` in their `PaymentAsset`.
+		// The asset is forgotten once the payment is released, refunded or settled
+		pub fn hold_payment(payer: T::AccountId, amount: u128, reference_no: &BoundedVec<u8, MAX_BYTES>) -> DispatchResult {
+			let reason: T::RuntimeHoldReason = HoldReason::VaneEscrow.into();
+
+			match PaymentAsset::<T>::get(reference_no) {
+				None => {
+					let balance: EscrowBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Escrow as fungible::MutateHold<_>>::hold(&reason, &payer, balance)?;
+				},
+				Some(currency_id) => {
+					let balance: AssetBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Assets as fungibles::MutateHold<_>>::hold(currency_id, &reason, &payer, balance)?;
+				},
+			}
+
+			Self::deposit_event(Event::BalanceHeld {
 				from: payer,
-				timestamp: time,
-				reference_no: ref_no.to_vec(),
+				amount,
+				timestamp: <frame_system::Pallet<T>>::block_number(),
+				reference_no: reference_no.to_vec(),
 			});
 
 			Ok(())
 		}
+
+		// Hands `amount` of a payment's hold back to the payer
+		pub fn release_held(payer: &T::AccountId, reference_no: &BoundedVec<u8, MAX_BYTES>, amount: u128) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+			let reason: T::RuntimeHoldReason = HoldReason::VaneEscrow.into();
+
+			match PaymentAsset::<T>::get(reference_no) {
+				None => {
+					let balance: EscrowBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Escrow as fungible::MutateHold<_>>::release(&reason, payer, balance, Precision::Exact)?;
+				},
+				Some(currency_id) => {
+					let balance: AssetBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Assets as fungibles::MutateHold<_>>::release(currency_id, &reason, payer, balance, Precision::Exact)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		// Moves `amount` of a payment's hold to the payee's free balance
+		pub fn transfer_held(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>,
+			amount: u128,
+		) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+			let reason: T::RuntimeHoldReason = HoldReason::VaneEscrow.into();
+
+			match PaymentAsset::<T>::get(reference_no) {
+				None => {
+					let balance: EscrowBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Escrow as fungible::MutateHold<_>>::transfer_on_hold(
+						&reason,
+						payer,
+						payee,
+						balance,
+						Precision::Exact,
+						Restriction::Free,
+						Fortitude::Polite,
+					)?;
+				},
+				Some(currency_id) => {
+					let balance: AssetBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Assets as fungibles::MutateHold<_>>::transfer_on_hold(
+						currency_id,
+						&reason,
+						payer,
+						payee,
+						balance,
+						Precision::Exact,
+						Restriction::Free,
+						Fortitude::Polite,
+					)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		// Whether the payee can be paid `amount` of a payment, e.g. it is not below the existential deposit
+		pub fn can_receive(
+			payee: &T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>,
+			amount: u128,
+		) -> Result<bool, DispatchError> {
+			let consequence = match PaymentAsset::<T>::get(reference_no) {
+				None => {
+					let balance: EscrowBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Escrow as fungible::Inspect<_>>::can_deposit(payee, balance, Provenance::Extant)
+				},
+				Some(currency_id) => {
+					let balance: AssetBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+					<T::Assets as fungibles::Inspect<_>>::can_deposit(currency_id, payee, balance, Provenance::Extant)
+				},
+			};
+
+			Ok(consequence == DepositConsequence::Success)
+		}
+
+		// Amount held for a single payment, from its receipt or its order ticket
+		pub fn escrowed_amount(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>
+		) -> Result<u128, Error<T>> {
+			if let Some(receipt) = PayerTxnReceipt::<T>::get(payer, reference_no) {
+				return Ok(receipt.amount);
+			}
+
+			PayerTxnTicketOrder::<T>::get(payer, payee)
+				.iter()
+				.find(|ticket| &ticket.reference_no == reference_no)
+				.map(|ticket| ticket.amount.unique_saturated_into())
+				.ok_or(Error::<T>::TxnReceiptUnavailable)
+		}
 		// Dispatching Call helper
 		pub(crate) fn dispatch_transfer_call(
 			proof: T::Hash,
 			payer: T::AccountId,
 			payee: T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>,
 			allowed_multi_id: T::AccountId,
 			confirmed_multi_id: T::AccountId,
 		) -> DispatchResult {
 			// Store the proof and associated data of call execution
-			// construct transfer call from pallet balances
-			let acc_payee = payee.clone();
-			let payee = <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(payee);
+			// Only this payment's hold moves to the payee
+			let amount = Self::escrowed_amount(&payer, &payee, reference_no)?;
 
-			<pallet_balances::Pallet<T, ()>>::transfer_all(
-				RawOrigin::Signed(allowed_multi_id.clone()).into(),
-				payee,
-				false,
-			)
-			.map_err(|_| Error::<T>::MultiSigCallFailed)?;
+			Self::transfer_held(&payer, &payee, reference_no, amount)
+				.map_err(|_| Error::<T>::MultiSigCallFailed)?;
+			PaymentAsset::<T>::remove(reference_no);
 
 			let time = <frame_system::Pallet<T>>::block_number();
 
 			let call_exe_object = CallExecuted::<T>::new(
 				payer.clone(),
-				acc_payee,
+				payee,
 				allowed_multi_id,
 				confirmed_multi_id.clone(),
 				proof,
 				time,
 			);
 
-			AccountMultiTxns::<T>::mutate(payer, |vec| vec.push(call_exe_object));
+			AccountMultiTxns::<T>::try_mutate(payer, |vec| {
+				if vec.len() >= T::MaxAccountTxns::get() as usize {
+					vec.remove(0);
+				}
+				vec.try_push(call_exe_object)
+			})
+			.map_err(|_| Error::<T>::UnexpectedError)?;
 
 			Self::deposit_event(Event::CallExecuted {
 				multi_id: confirmed_multi_id,
@@ -427,44 +1061,9 @@ pub mod utils {
 			Ok(())
 		}
 
-		// Takes in a multi_id account and register it to Account storage in system pallet
-
-		pub fn create_multi_account(multi_id: T::AccountId) -> DispatchResult {
-			let account_info = AccountInfo::<T::Nonce, T::AccountData> { ..Default::default() };
-
-			// Ensure the multi_id account is not yet registered in the storage
-			if <frame_system::Pallet<T>>::account_exists(&multi_id) {
-				return Ok(());
-			} else {
-				// Register to frame_system Account Storage item;
-				<frame_system::Account<T>>::set(multi_id, account_info);
-				Ok(())
-			}
-		}
-
 		// Now , we are only focusing legal team Resolver variant in multi_id generation
-		// We can do better on this function definition
 		pub  fn derive_multi_id(account_object: AccountSigners<T>) -> T::AccountId {
-			let (acc1, acc2, opt_acc3) = match account_object.get_resolver() {
-				Some(_resolver) => (
-					account_object.get_payee(),
-					account_object.get_payer(),
-					account_object.get_legal_account(),
-				),
-				None => (account_object.get_payee(), account_object.get_payer(), None),
-			};
-
-			let multi_account = if let Some(acc3) = opt_acc3 {
-				let entropy = (b"vane/salt", acc1, acc2, acc3).using_encoded(blake2_256);
-				Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-					.expect("infinite length input; no invalid inputs for type; qed")
-			} else {
-				let entropy = (b"vane/salt", acc1, acc2).using_encoded(blake2_256);
-				Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-					.expect("infinite length input; no invalid inputs for type; qed")
-			};
-
-			multi_account
+			escrow::derive_multi_id(&account_object)
 		}
 	}
 }
@@ -25,38 +25,58 @@
 pub use pallet::*;
 
 pub mod helper;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 // A multi-signature implementation for `Vane Payment System`
 
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::helper::{TxnTicketOrder};
+	use crate::helper::{Dispute, DisputeTally, Resolver, TxnTicketOrder};
+	use crate::weights::WeightInfo;
 
 	pub use super::helper::{
-		AccountSigners, CallExecuted, Confirm, ResolverChoice, RevertReasons, TxnReceipt,
+		AccountSigners, CallExecuted, Confirm, ReferenceNo, ResolverChoice, RevertReasons, TxnReceipt,
+		Verdict, MAX_BYTES,
 	};
 	use frame_support::{
-		pallet, pallet_prelude::*, parameter_types, traits::tokens::currency::Currency,
+		pallet, pallet_prelude::*,
+		traits::{fungible, fungibles, tokens::currency::Currency},
 		Blake2_128Concat,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
 
 	//use vane_primitive::OrderTrait;
-	use sp_io::hashing::blake2_256;
-	use sp_runtime::traits::{StaticLookup, TrailingZeroInput};
+	use sp_runtime::{traits::StaticLookup, Perbill};
 	use sp_std::vec::Vec;
+	use vane_primitive::{AssetRegistry, CurrencyId};
+	use vane_primitive::escrow::{
+		ConfirmOutcome, MaxSigners, OnConfirmed, OnEscrowOpened, OnReleased, OnReverted, VaneEscrow,
+	};
 	use vane_order;
 	use vane_register::{self, BalanceOf};
 
 	pub(super) type AccountOf<T> = <T as frame_system::Config>::AccountId;
 	pub type BalanceOfPay<T> = <<T as Config>::Currency as Currency<AccountOf<T>>>::Balance;
+	pub type EscrowBalanceOf<T> = <<T as Config>::Escrow as fungible::Inspect<AccountOf<T>>>::Balance;
+	pub type AssetBalanceOf<T> = <<T as Config>::Assets as fungibles::Inspect<AccountOf<T>>>::Balance;
 
-	// Max signers for Confirm Signers Bounded Vec
-	parameter_types! {
-		pub const MaxSigners: u16 = 2;
-	}
+	// Receipts, tickets, signers and executed payments moved to bounded storage in v1,
+	// escrows left on the shared multi_id accounts moved to per payment holds in v2
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
@@ -67,38 +87,154 @@ pub mod pallet {
 
 		//type Order: OrderTrait + TypeInfo + Decode + Encode + Clone + PartialEq + Debug;
 		type Currency: Currency<Self::AccountId>;
+
+		// Blocks the payee has to confirm a payment before it is refunded, used when the payer sets none
+		#[pallet::constant]
+		type ConfirmationWindow: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type MaxConfirmationWindow: Get<BlockNumberFor<Self>>;
+
+		// Payments which can expire in the same block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		// Receipts and order tickets a payee can hold, bounds the weight of every call touching them
+		// Settled receipts are dropped first once full
+		#[pallet::constant]
+		type MaxPayeeReceipts: Get<u32>;
+
+		// Executed payments kept per payer, the oldest is dropped once full
+		#[pallet::constant]
+		type MaxAccountTxns: Get<u32>;
+
+		type RuntimeHoldReason: From<HoldReason>;
+
+		// Payments stay on the payer's account under `HoldReason::VaneEscrow` until released or refunded
+		type Escrow: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		// Payments in assets, e.g. DOT or USDT, are held the same way on the payer's asset balance
+		type Assets: fungibles::MutateHold<Self::AccountId, AssetId = CurrencyId, Reason = Self::RuntimeHoldReason>;
+
+		// Only enabled registered assets can be paid with
+		type AssetRegistry: AssetRegistry;
+
+		// Origin appointing the legal team and the resolver council which arbitrate disputed payments
+		type ResolverOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		// Blocks the legal team has to rule on a dispute, the payer can be refunded afterwards
+		#[pallet::constant]
+		type DisputeTimeout: Get<BlockNumberFor<Self>>;
+
+		// Blocks the resolver council votes on a dispute which chose governance
+		#[pallet::constant]
+		type VotingPeriod: Get<BlockNumberFor<Self>>;
+
+		// Share of the council's weight which has to vote for its verdict to count,
+		// the payer is refunded otherwise
+		#[pallet::constant]
+		type GovernanceQuorum: Get<Perbill>;
+
+		#[pallet::constant]
+		type MaxCouncilMembers: Get<u32>;
+
+		// Share of a payment a payer changing their mind leaves to the payee,
+		// multiplied by the reverts the payer already made
+		#[pallet::constant]
+		type RevertPenalty: Get<Perbill>;
+
+		#[pallet::constant]
+		type MaxRevertPenalty: Get<Perbill>;
+
+		// Blocks a payer waits before the next full refund for a wrong address,
+		// multiplied by the reverts the payer made
+		#[pallet::constant]
+		type RevertCooldown: Get<BlockNumberFor<Self>>;
+
+		// Payment lifecycle hooks for pallets building on Vane payments
+		type OnEscrowOpened: OnEscrowOpened<Self::AccountId>;
+
+		type OnConfirmed: OnConfirmed<Self::AccountId>;
+
+		type OnReleased: OnReleased<Self::AccountId>;
+
+		type OnReverted: OnReverted<Self::AccountId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	// Reasons for holding funds in this pallet
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		// Payment waiting for its release or refund
+		#[codec(index = 0)]
+		VaneEscrow,
 	}
 
 	pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 
-	// Not yet implemented
+	// Legal team of the payments choosing `ResolverChoice::LegalTeam`,
+	// a payment keeps the legal team it was opened with
 	#[pallet::storage]
 	#[pallet::getter(fn get_resolver)]
-	pub(super) type ResolverSigner<T: Config> = StorageValue<_, T::AccountId>;
+	pub type ResolverSigner<T: Config> = StorageValue<_, T::AccountId>;
+
+	// Asset an open payment is held in, payments without one are made in the native currency.
+	// Removed once the payment is released, refunded or settled
+	// Key ----> reference_no
+	#[pallet::storage]
+	#[pallet::getter(fn get_payment_asset)]
+	pub type PaymentAsset<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, CurrencyId>;
+
+	// Payments taken to their resolver
+	// Key ----> reference_no
+	#[pallet::storage]
+	#[pallet::getter(fn get_dispute)]
+	pub type Disputes<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, Dispute<T>>;
+
+	// Resolver council voting on the disputes of payments choosing `ResolverChoice::Governance`
+	// Key ----> member
+	// Value ----> vote weight
+	#[pallet::storage]
+	pub type ResolverCouncil<T: Config> = CountedStorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	// Running tally of the council vote on a dispute
+	#[pallet::storage]
+	#[pallet::getter(fn get_dispute_tally)]
+	pub type DisputeTallies<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, DisputeTally<BlockNumberFor<T>>>;
+
+	// Council members who voted on a dispute and their verdict
+	#[pallet::storage]
+	pub type DisputeVoters<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, MAX_BYTES>,
+		Blake2_128Concat,
+		T::AccountId,
+		Verdict,
+	>;
 
 	// Number of multi-sig transactions done by a specific account_id
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_account_multitxns)]
 	pub type AccountMultiTxns<T: Config> =
-		StorageMap<_, Blake2_256, T::AccountId, Vec<CallExecuted<T>>, ValueQuery>;
+		StorageMap<_, Blake2_256, T::AccountId, BoundedVec<CallExecuted<T>, T::MaxAccountTxns>, ValueQuery>;
 
 	// Signers which will be stored when payer initiates the transaction,
-	//This will be used to create a multi_id account which is shared with both payer and specified payee
+	// their multi_id identifies the payer and payee pair when confirming
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_allowed_signers)]
 	pub type AllowedSigners<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, Vec<u8>, AccountSigners<T>>;
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, BoundedVec<u8, MAX_BYTES>, AccountSigners<T>>;
 
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_signers)]
 
 	// Signers who have confirmed the transaction which will be compared to allowed signers as verification process
 	pub type ConfirmedSigners<T: Config> =
-		StorageMap<_, Twox64Concat, Vec<u8>, BoundedVec<T::AccountId, MaxSigners>, ValueQuery>;
+		StorageMap<_, Twox64Concat, BoundedVec<u8, MAX_BYTES>, BoundedVec<T::AccountId, MaxSigners>, ValueQuery>;
 
 	// Number of reverted or faulty transaction a payer did
 	#[pallet::storage]
@@ -110,6 +246,11 @@ pub mod pallet {
 	#[pallet::getter(fn get_failed_txn_payee)]
 	pub type RevertedTxnPayee<T: Config> = StorageMap<_, Blake2_256, T::AccountId, u32, ValueQuery>;
 
+	// Block from which a payer can claim a full refund for a wrong address again
+	#[pallet::storage]
+	#[pallet::getter(fn get_revert_cooldown)]
+	pub type RevertCooldowns<T: Config> = StorageMap<_, Blake2_256, T::AccountId, BlockNumberFor<T>>;
+
 	// Order txn confirmation tracker
 	// Key --> Payee_id
 	// Value ----> (payer_id,payee_id)
@@ -125,7 +266,7 @@ pub mod pallet {
 
 	// TxnTicket Payer
 	// Showing pending uncofirmed txn
-	// Keys ->(payer, payee) , allowing related txn to be in one place
+	// Keys ->(payer, reference_no) , one receipt per payment
 
 	#[pallet::storage]
 	pub type PayerTxnReceipt<T: Config> = StorageDoubleMap<
@@ -133,35 +274,70 @@ pub mod pallet {
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
-		T::AccountId,
+		BoundedVec<u8, MAX_BYTES>,
 		TxnReceipt<T>
 	>;
 
+	// Reference number lookup
+	// Key ----> reference_no
+	// Value ----> (payer, payee)
+	#[pallet::storage]
+	#[pallet::getter(fn get_receipt_index)]
+	pub type ReceiptIndex<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, (T::AccountId, T::AccountId)>;
+
+	// Number of payments made between a payer and a payee, used to derive unique reference numbers
+	#[pallet::storage]
+	pub type PairNonce<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	// TxnTicket Payee
 	// This is used to notify the payee as their is new pending transaction which needs confirmation
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayeeTxnReceipt<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<TxnReceipt<T>>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>, ValueQuery>;
+
+	// Payments waiting for confirmation, keyed by the block they expire at
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<BoundedVec<u8, MAX_BYTES>, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	// Next block of the expiry queue to be processed
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	// Block a payment expires at, cleared once its expiry is processed
+	#[pallet::storage]
+	pub type PaymentExpiry<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, BlockNumberFor<T>>;
+
+	// Payments the v2 migration could not match to their escrow, they cannot be confirmed, reverted,
+	// disputed or expired until `settle_review` pays them out
+	// Value ----> (payer, payee)
+	#[pallet::storage]
+	pub type PaymentsUnderReview<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, (T::AccountId, T::AccountId)>;
 
 	// Ignore the Order txn at the moment
 	// Ticket for Order transactions
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayerTxnTicketOrder<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
 		T::AccountId,
-		Vec<TxnTicketOrder<T>>,
+		BoundedVec<TxnTicketOrder<T>, T::MaxPayeeReceipts>,
 		ValueQuery,
 	>;
 	// TxnTicket Payee
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayeeTxnTicketOrder<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<TxnTicketOrder<T>>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<TxnTicketOrder<T>, T::MaxPayeeReceipts>, ValueQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -171,14 +347,9 @@ pub mod pallet {
 			timestamp: BlockNumberFor<T>,
 		},
 
-		MultiAccountCreated {
-			account_id: T::AccountId,
-			timestamp: BlockNumberFor<T>,
-		},
-
-		BalanceTransferredAndLocked {
-			to_multi_id: T::AccountId,
+		BalanceHeld {
 			from: T::AccountId,
+			amount: u128,
 			timestamp: BlockNumberFor<T>,
 			reference_no: Vec<u8>,
 		},
@@ -199,9 +370,91 @@ pub mod pallet {
 			from_account: T::AccountId,
 			to_account: T::AccountId,
 			amount: u128,
+			currency: Option<CurrencyId>,
 			resolver: Option<ResolverChoice>,
 			timestamp: BlockNumberFor<T>,
 		},
+		ExpiryScheduled {
+			reference_no: Vec<u8>,
+			expiry: BlockNumberFor<T>,
+		},
+		// `retry_at` is `None` when the next block's queue is full, the payment then has to be reverted
+		ExpiryFailed {
+			reference_no: Vec<u8>,
+			error: DispatchError,
+			retry_at: Option<BlockNumberFor<T>>,
+		},
+
+		PaymentExpired {
+			reference_no: Vec<u8>,
+			payer: T::AccountId,
+			amount: u128,
+		},
+
+		FundsReverted {
+			reference_no: Vec<u8>,
+			payer: T::AccountId,
+			reason: RevertReasons,
+			amount: u128,
+			penalty: u128,
+		},
+
+		ResolverSet {
+			resolver: Option<T::AccountId>,
+		},
+
+		DisputeRaised {
+			reference_no: Vec<u8>,
+			raised_by: T::AccountId,
+			resolver: Resolver<T>,
+		},
+
+		DisputeResolved {
+			reference_no: Vec<u8>,
+			resolver: T::AccountId,
+			verdict: Verdict,
+			released: u128,
+			refunded: u128,
+		},
+
+		CouncilMemberSet {
+			who: T::AccountId,
+			weight: Option<u32>,
+		},
+
+		DisputeVoteOpened {
+			reference_no: Vec<u8>,
+			electorate: u32,
+			ends_at: BlockNumberFor<T>,
+		},
+
+		DisputeVoted {
+			reference_no: Vec<u8>,
+			voter: T::AccountId,
+			verdict: Verdict,
+			weight: u32,
+		},
+
+		DisputeVoteClosed {
+			reference_no: Vec<u8>,
+			verdict: Verdict,
+			turnout: u32,
+			quorum_reached: bool,
+			released: u128,
+			refunded: u128,
+		},
+
+		DisputeTimedOut {
+			reference_no: Vec<u8>,
+			refunded: u128,
+		},
+
+		ReviewSettled {
+			reference_no: Vec<u8>,
+			released: u128,
+			refunded: u128,
+		},
+
 		SubmittedOrderPayment {
 			from_account: T::AccountId,
 			to_account: T::AccountId,
@@ -241,45 +494,116 @@ pub mod pallet {
 		ProductNotFound,
 
 		OrderNotFound,
+
+		ReferenceCollision,
+
+		InvalidConfirmationWindow,
+
+		ExpiryQueueFull,
+
+		TooManyReceipts,
+
+		NoLegalTeam,
+
+		ResolverNotSupported,
+
+		NoResolverForPayment,
+
+		NotAPaymentParticipant,
+
+		PaymentNotOpen,
+
+		AlreadyDisputed,
+
+		PaymentDisputed,
+
+		DisputeNotFound,
+
+		NotTheResolver,
+
+		NoResolverCouncil,
+
+		TooManyCouncilMembers,
+
+		NotACouncilMember,
+
+		AlreadyVoted,
+
+		DisputeVoteNotFound,
+
+		VotingClosed,
+
+		VotingOngoing,
+
+		RevertOnCooldown,
+
+		UnsupportedCurrency,
+
+		NotThePayee,
+
+		NotThePayer,
+
+		PaymentUnderReview,
+
+		NotALegalTeamDispute,
+
+		DisputeNotTimedOut,
+
+		PaymentNotExpired,
+
+		NotUnderReview,
+
+		SettlementExceedsPayment,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// Expired payments are refunded with the weight left in the block
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_expiry_queue(now, remaining_weight)
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Responsible for normal payments
+		/// Responsible for normal payments, in the native currency or in a registered asset
 		#[pallet::call_index(0)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::vane_pay(T::MaxPayeeReceipts::get()))]
 		pub fn vane_pay(
 			origin: OriginFor<T>,
 			payee: T::AccountId,
 			amount: u128,
+			currency: Option<CurrencyId>, // Defaults to the native currency
 			resolver: Option<ResolverChoice>,
+			confirmation_window: Option<BlockNumberFor<T>>, // Defaults to ConfirmationWindow
 		) -> DispatchResult {
 			// 1. Check if the Payee is in the Register Storage
 			// 2.
 			let payer = ensure_signed(origin)?;
+			let chosen = Self::choose_resolver(resolver.clone())?;
 
-			match resolver {
-				Option::None => {
-					Self::inner_vane_pay_wo_resolver(payer.clone(), payee.clone(), amount,None)?;
-					let time = <frame_system::Pallet<T>>::block_number();
-
-					Self::deposit_event(Event::SubmittedPayment {
-						from_account: payer,
-						to_account: payee,
-						amount,
-						resolver: None,
-						timestamp: time,
-					})
-				},
-				_ => (),
-			}
+			Self::inner_vane_pay(payer.clone(), payee.clone(), amount, chosen, currency, confirmation_window)?;
+			let time = <frame_system::Pallet<T>>::block_number();
+
+			Self::deposit_event(Event::SubmittedPayment {
+				from_account: payer,
+				to_account: payee,
+				amount,
+				currency,
+				resolver,
+				timestamp: time,
+			});
 
 			Ok(())
 		}
 
 		/// Responsible for handling order type payments
 		#[pallet::call_index(1)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::vane_order_pay(
+			<T as vane_register::Config>::MaxProducts::get(),
+			<T as vane_order::Config>::MaxOrders::get(),
+			T::MaxPayeeReceipts::get()
+		))]
 		pub fn vane_order_pay(
 			origin: OriginFor<T>,
 			seller: T::AccountId,
@@ -287,55 +611,55 @@ pub mod pallet {
 			resolver: Option<ResolverChoice>,
 		) -> DispatchResult {
 			let buyer_id = ensure_signed(origin)?;
-			match resolver {
-				Option::None => {
-					// Every item_no is linked with seller_id, as there can be same order_no referencing
-					// different seller_ids.
-					// seller_id -------> item_no ------(i)
-					// Verifying If the Item is actually there ---- (ii)
-					// PayeeProducts ---> [ProductProfile]
-					let vec_products = <vane_register::PayeeProducts<T>>::get(&seller);
-					let check_product =
-						vec_products.iter().find(|product| product.product_id == item_no);
-					ensure!(matches!(check_product, Some(_prod)), Error::<T>::ProductNotFound);
-
-					// Check if if the order does exist -----(iii)
-					// order_no + payer_id ------> Check order existence
-
-					// Storage entities
-					// PayeeRef ----> seller_id --> [(buyer_id,item_no,order_no)]
-					// Iterate over and look for matching (buyer_id + item_no) and take  the order_no
-					let vec_ref = <vane_order::PayeeOrderRef<T>>::get(&seller);
-					let (_, _, order_no) = vec_ref
-						.iter()
-						.find(|(buyer_id, item_no, _)| buyer_id == buyer_id && item_no == item_no)
-						.unwrap();
-
-					// Payer -----> buyer_id --> [Order]
-					// Iterate over and look for matching order_no + seller_id
-					// Take the Order object ,, NOTE: Dont delete yet untill confirmation period
-					let vec_order = <vane_order::PayerOrder<T>>::get(&buyer_id);
-					let order = vec_order.iter().find(|ord| ord.order_no == *order_no);
-					ensure!(matches!(order, Some(_ord)), Error::<T>::OrderNotFound);
-					let order = order.unwrap();
-					// -------------------------------------------
-					Self::inner_vane_order_pay_wo_resolver(
-						order.payer_id.clone(),
-						order.payee_id.clone(),
-						order.amount.clone(),
-					)?;
-					let time = <frame_system::Pallet<T>>::block_number();
-
-					Self::deposit_event(Event::SubmittedOrderPayment {
-						from_account: order.payer_id.clone(),
-						to_account: order.payee_id.clone(),
-						resolver: None,
-						timestamp: time,
-						amount: order.amount,
-					});
-				},
-				_ => (),
-			}
+			let chosen = Self::choose_resolver(resolver.clone())?;
+
+			// Every item_no is linked with seller_id, as there can be same order_no referencing
+			// different seller_ids.
+			// seller_id -------> item_no ------(i)
+			// Verifying If the Item is actually there ---- (ii)
+			// PayeeProducts ---> [ProductProfile]
+			let vec_products = <vane_register::PayeeProducts<T>>::get(&seller);
+			ensure!(
+				vec_products.iter().any(|product| product.product_id == item_no),
+				Error::<T>::ProductNotFound
+			);
+
+			// Check if if the order does exist -----(iii)
+			// order_no + payer_id ------> Check order existence
+
+			// Storage entities
+			// PayeeRef ----> seller_id --> [(buyer_id,item_no,order_no)]
+			// Iterate over and look for matching (buyer_id + item_no) and take  the order_no
+			let vec_ref = <vane_order::PayeeOrderRef<T>>::get(&seller);
+			let (_, _, order_no) = vec_ref
+				.iter()
+				.find(|(order_buyer, order_item, _)| *order_buyer == buyer_id && *order_item == item_no)
+				.ok_or(Error::<T>::OrderNotFound)?;
+
+			// Payer -----> buyer_id --> [Order]
+			// Iterate over and look for matching order_no + seller_id
+			// Take the Order object ,, NOTE: Dont delete yet untill confirmation period
+			let vec_order = <vane_order::PayerOrder<T>>::get(&buyer_id);
+			let order = vec_order
+				.iter()
+				.find(|ord| ord.order_no == *order_no && ord.payee_id == seller)
+				.ok_or(Error::<T>::OrderNotFound)?;
+			// -------------------------------------------
+			Self::inner_vane_order_pay(
+				order.payer_id.clone(),
+				order.payee_id.clone(),
+				order.amount.clone(),
+				chosen,
+			)?;
+			let time = <frame_system::Pallet<T>>::block_number();
+
+			Self::deposit_event(Event::SubmittedOrderPayment {
+				from_account: order.payer_id.clone(),
+				to_account: order.payee_id.clone(),
+				resolver,
+				timestamp: time,
+				amount: order.amount,
+			});
 
 			Ok(())
 		}
@@ -347,7 +671,7 @@ pub mod pallet {
 		// 		2. Then next steps will follow after this,
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_pay(T::MaxAccountTxns::get()))]
 		pub fn confirm_pay(
 			origin: OriginFor<T>,
 			who: Confirm,
@@ -362,117 +686,196 @@ pub mod pallet {
 			// confirm first
 
 			let user_account = ensure_signed(origin)?;
-			// Check the storage
-			let b_vec = ConfirmedSigners::<T>::get(reference_no.clone());
-
-			if let Some(addr) = b_vec.get(0) {
-				if addr.eq(&user_account) {
-					return Err(Error::<T>::PayeeAlreadyConfirmed.into());
-
-				// Else for checking if payee tries to confirm twice.
-				} else {
-					ConfirmedSigners::<T>::try_mutate(reference_no.clone(), |vec| {
-						vec.try_push(user_account.clone())
-					})
-					.map_err(|_| Error::<T>::ExceededSigners)?;
-
-					let time = <frame_system::Pallet<T>>::block_number();
-
-					Self::deposit_event(Event::PayerAddressConfirmed {
-						account_id: user_account,
-						timestamp: time,
-						reference_no: reference_no.clone(),
-					});
-
-					// Construct AccountSigner object from ConfirmedSigners storage
-
-					let confirmed_acc_signers = AccountSigners::<T>::new(
-						ConfirmedSigners::<T>::get(reference_no.clone())
-							.get(0)
-							.ok_or(Error::<T>::UnexpectedError)?
-							.clone(),
-						ConfirmedSigners::<T>::get(reference_no.clone())
-							.get(1)
-							.ok_or(Error::<T>::UnexpectedError)?
-							.clone(),
-						// The default resolver is none but logic will be made to be customizable
-						None,
-					);
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.try_into()
+				.map_err(|_| Error::<T>::TxnReceiptUnavailable)?;
 
-					// Derive the multi_id of newly constructed AccountSigner and one from
-					// AllowedSigners
-					let confirmed_multi_id = Self::derive_multi_id(confirmed_acc_signers);
-
-					// Get the AllowedSigners from storage
-					let payer = ConfirmedSigners::<T>::get(reference_no.clone())
-						.get(1)
-						.ok_or(Error::<T>::UnexpectedError)?
-						.clone();
-
-					let payee = ConfirmedSigners::<T>::get(reference_no.clone())
-						.get(0)
-						.ok_or(Error::<T>::UnexpectedError)?
-						.clone();
-
-					let allowed_signers =
-						AllowedSigners::<T>::get(payer.clone(), reference_no.clone())
-							.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
-
-					let allowed_multi_id = Self::derive_multi_id(allowed_signers);
-					// Compute the hash of both multi_ids (proof)
-					if confirmed_multi_id.eq(&allowed_multi_id) {
-						let encoded_proof = (allowed_multi_id.clone(), confirmed_multi_id.clone())
-							.using_encoded(blake2_256);
-						let proof =
-							Decode::decode(&mut TrailingZeroInput::new(encoded_proof.as_ref()))
-								.map_err(|_| Error::<T>::UnexpectedError)?;
-
-						Self::dispatch_transfer_call(
-							proof,
-							payer,
-							payee,
-							allowed_multi_id,
-							confirmed_multi_id,
-						)?;
-					} else {
-						return Err(Error::<T>::FailedToMatchAccounts.into());
-					}
-				}
-
-			// Else block from If let Some()
-			} else {
-				match who {
-					Confirm::Payer => return Err(Error::<T>::WaitForPayeeToConfirm.into()),
-
-					Confirm::Payee => {
-						ConfirmedSigners::<T>::try_mutate(reference_no.clone(), |vec| {
-							vec.try_push(user_account.clone())
-						})
-						.map_err(|_| Error::<T>::ExceededSigners)?;
-
-						let time = <frame_system::Pallet<T>>::block_number();
-
-						Self::deposit_event(Event::PayeeAddressConfirmed {
-							account_id: user_account,
-							timestamp: time,
-							reference_no,
-						});
-					},
-				};
-			};
+			<Self as VaneEscrow<T::AccountId>>::confirm(&user_account, who, &reference)?;
 
 			Ok(())
 		}
 
 		// If the payer accidently makes a mistake due to RevertReasons the funds can be refunded
 		// back Punishment will occur if the reason is personal.
-
-		// We should introduce some sort of limit for WrongAddress reason occurrence.
-		// Fee punishments on wrong address to limit spamming (ideally 1% of amount)
+		// 		1. `WrongPayeeAddress` refunds in full as long as the payee did not confirm,
+		// 		   repeated claims wait for a cooldown growing with the payer's reverts
+		// 		2. `ChangeOfDecision` leaves a penalty to the payee growing with the payer's reverts
+		// 		3. `PayeeMisbehaviour` raises a dispute, the payer is refunded once the resolver signs off
 		#[pallet::call_index(5)]
-		#[pallet::weight(10)]
-		pub fn revert_fund(origin: OriginFor<T>, reason: RevertReasons) -> DispatchResult {
+		#[pallet::weight(
+			<T as Config>::WeightInfo::revert_fund(T::MaxPayeeReceipts::get())
+				.max(<T as Config>::WeightInfo::raise_dispute())
+		)]
+		pub fn revert_fund(origin: OriginFor<T>, reference_no: Vec<u8>, reason: RevertReasons) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.try_into().map_err(|_| Error::<T>::TxnReceiptUnavailable)?;
+
+			Self::revert_payment(payer, reference, reason)
+		}
+
+		// Appoints the legal team of new payments choosing `ResolverChoice::LegalTeam`,
+		// `None` stops payments from choosing one
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_resolver())]
+		pub fn set_resolver(origin: OriginFor<T>, resolver: Option<T::AccountId>) -> DispatchResult {
+			T::ResolverOrigin::ensure_origin(origin)?;
+
+			match &resolver {
+				Some(account) => ResolverSigner::<T>::put(account),
+				None => ResolverSigner::<T>::kill(),
+			}
+
+			Self::deposit_event(Event::ResolverSet { resolver });
+
+			Ok(())
+		}
+
+		// The payer or the payee takes an open payment to the resolver chosen when it was made.
+		// The escrow stays held, confirmations, reverts and expiry are suspended until the verdict
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::raise_dispute())]
+		pub fn raise_dispute(origin: OriginFor<T>, reference_no: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.try_into().map_err(|_| Error::<T>::TxnReceiptUnavailable)?;
+
+			Self::open_dispute(who, reference)
+		}
+
+		// The legal team settles a disputed payment, releasing, refunding or splitting the escrow
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::resolve_dispute(T::MaxPayeeReceipts::get()))]
+		pub fn resolve_dispute(origin: OriginFor<T>, reference_no: Vec<u8>, verdict: Verdict) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.clone().try_into().map_err(|_| Error::<T>::DisputeNotFound)?;
+
+			let dispute = Disputes::<T>::get(&reference).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(
+				matches!(&dispute.resolver, Resolver::LegalTeam(legal_team) if legal_team == &who),
+				Error::<T>::NotTheResolver
+			);
+
+			let (released, refunded) = Self::settle_dispute(&reference, &verdict, verdict.revert_reason())?;
+
+			Self::deposit_event(Event::DisputeResolved {
+				reference_no,
+				resolver: who,
+				verdict,
+				released,
+				refunded,
+			});
+
 			Ok(())
 		}
+
+		// Adds, reweighs or removes (`None`) a member of the resolver council.
+		// Votes already cast keep the weight they were cast with
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_council_member())]
+		pub fn set_council_member(origin: OriginFor<T>, who: T::AccountId, weight: Option<u32>) -> DispatchResult {
+			T::ResolverOrigin::ensure_origin(origin)?;
+
+			match weight.filter(|weight| *weight > 0) {
+				Some(weight) => {
+					ensure!(
+						ResolverCouncil::<T>::contains_key(&who) ||
+							ResolverCouncil::<T>::count() < T::MaxCouncilMembers::get(),
+						Error::<T>::TooManyCouncilMembers
+					);
+					ResolverCouncil::<T>::insert(&who, weight);
+				},
+				None => ResolverCouncil::<T>::remove(&who),
+			}
+
+			Self::deposit_event(Event::CouncilMemberSet { who, weight });
+
+			Ok(())
+		}
+
+		// A council member votes on a dispute which chose governance, once and before its voting period ends
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::vote_dispute())]
+		pub fn vote_dispute(origin: OriginFor<T>, reference_no: Vec<u8>, verdict: Verdict) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.try_into().map_err(|_| Error::<T>::DisputeVoteNotFound)?;
+
+			Self::cast_vote(who, reference, verdict)
+		}
+
+		// Anyone can settle a dispute by the council's verdict once its voting period is over
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_dispute(T::MaxCouncilMembers::get(), T::MaxPayeeReceipts::get()))]
+		pub fn close_dispute(origin: OriginFor<T>, reference_no: Vec<u8>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.try_into().map_err(|_| Error::<T>::DisputeVoteNotFound)?;
+
+			Self::close_vote(reference)
+		}
+
+		// Settles a payment listed for review by the v2 migration, paying `released` to the payee and
+		// `refunded` to the payer out of what its multi_id kept. Both zero closes a payment already swept
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_review(T::MaxPayeeReceipts::get()))]
+		pub fn settle_review(origin: OriginFor<T>, reference_no: Vec<u8>, released: u128, refunded: u128) -> DispatchResult {
+			T::ResolverOrigin::ensure_origin(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.try_into().map_err(|_| Error::<T>::NotUnderReview)?;
+
+			Self::settle_reviewed_payment(&reference, released, refunded)
+		}
+
+		// Anyone can refund the payer of a dispute the legal team left without a ruling for `DisputeTimeout`
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::resolve_dispute(T::MaxPayeeReceipts::get()))]
+		pub fn time_out_dispute(origin: OriginFor<T>, reference_no: Vec<u8>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> =
+				reference_no.clone().try_into().map_err(|_| Error::<T>::DisputeNotFound)?;
+
+			let refunded = Self::refund_unruled_dispute(reference)?;
+
+			Self::deposit_event(Event::DisputeTimedOut { reference_no, refunded });
+
+			Ok(())
+		}
+	}
+
+	// Escrow lifecycle shared with the other Vane payment pallets, `None` pays in the native currency
+	impl<T: Config> VaneEscrow<T::AccountId> for Pallet<T> {
+		type Asset = Option<CurrencyId>;
+		type BlockNumber = BlockNumberFor<T>;
+
+		fn open(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			asset: Option<CurrencyId>,
+			amount: u128,
+			confirmation_window: Option<BlockNumberFor<T>>,
+		) -> Result<ReferenceNo, DispatchError> {
+			Self::inner_vane_pay(payer.clone(), payee.clone(), amount, None, asset, confirmation_window)
+		}
+
+		fn confirm(
+			who: &T::AccountId,
+			role: Confirm,
+			reference_no: &ReferenceNo,
+		) -> Result<ConfirmOutcome<T::AccountId>, DispatchError> {
+			Self::confirm_payment(who.clone(), role, reference_no.clone())
+		}
+
+		fn release(reference_no: &ReferenceNo) -> DispatchResult {
+			Self::release_payment(reference_no.clone())
+		}
+
+		fn revert(payer: &T::AccountId, reference_no: &ReferenceNo, reason: RevertReasons) -> DispatchResult {
+			Self::revert_payment(payer.clone(), reference_no.clone(), reason)
+		}
+
+		fn expire(reference_no: &ReferenceNo) -> DispatchResult {
+			Self::expire_payment(reference_no.clone())
+		}
 	}
 }
@@ -0,0 +1,383 @@
+// Storage migrations for Vane payments
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use helper::{CallExecuted, TxnReceipt, TxnTicketOrder};
+use sp_std::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	// Receipts before references were unique to a payment, one per payer and payee
+	#[frame_support::storage_alias]
+	pub(crate) type PayerTxnReceipt<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		TxnReceipt<T>,
+	>;
+
+	// Re-keys the payer receipts by their reference number and indexes the reference.
+	// Runs before `MigrateToV1`, which bumps the storage version past the old keys
+	pub struct RekeyPayerReceipts<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for RekeyPayerReceipts<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 0 {
+				log::info!(target: "vane-payment", "RekeyPayerReceipts skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			// Receipts already keyed by their reference fail to decode and are left alone
+			let receipts = PayerTxnReceipt::<T>::iter().collect::<Vec<_>>();
+			let rekeyed = receipts.len() as u64;
+
+			for (payer, payee, receipt) in receipts {
+				PayerTxnReceipt::<T>::remove(&payer, &payee);
+				ReceiptIndex::<T>::insert(&receipt.reference_no, (payer.clone(), payee));
+				crate::PayerTxnReceipt::<T>::insert(&payer, receipt.reference_no.clone(), receipt);
+			}
+
+			log::info!(target: "vane-payment", "RekeyPayerReceipts re-keyed {} receipts", rekeyed);
+
+			T::DbWeight::get().reads_writes(rekeyed.saturating_add(1), rekeyed.saturating_mul(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((PayerTxnReceipt::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let receipts = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+			ensure!(PayerTxnReceipt::<T>::iter_keys().next().is_none(), "receipts left under their payee");
+
+			// Receipts keyed by their reference before the upgrade are counted too
+			let mut rekeyed = 0u64;
+			for (payer, reference_no, receipt) in crate::PayerTxnReceipt::<T>::iter() {
+				ensure!(receipt.reference_no == reference_no, "receipt keyed by another reference");
+				ensure!(
+					ReceiptIndex::<T>::get(&reference_no) == Some((payer, receipt.payee().clone())),
+					"receipt reference not indexed"
+				);
+				rekeyed += 1;
+			}
+			ensure!(rekeyed >= receipts, "payer receipts lost");
+
+			Ok(())
+		}
+	}
+
+	// Moves the payee receipts, order tickets and executed payments into their bounded vectors.
+	// Signers and tickets keep their encoding, a reference number encodes the same as a `Vec<u8>`
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 0 {
+				log::info!(target: "vane-payment", "MigrateToV1 skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			let mut dropped = 0u64;
+
+			PayeeTxnReceipt::<T>::translate::<Vec<TxnReceipt<T>>, _>(|_, receipts| {
+				translated += 1;
+				let (kept, lost) = bound_receipts::<T>(receipts);
+				dropped += lost;
+				Some(kept)
+			});
+
+			AccountMultiTxns::<T>::translate::<Vec<CallExecuted<T>>, _>(|_, mut executed| {
+				translated += 1;
+				// Keep the most recent payments
+				let max = T::MaxAccountTxns::get() as usize;
+				let excess = executed.len().saturating_sub(max);
+				dropped += excess as u64;
+				executed.drain(..excess);
+				Some(BoundedVec::truncate_from(executed))
+			});
+
+			// Tickets have no status, the most recent ones are kept
+			PayeeTxnTicketOrder::<T>::translate::<Vec<TxnTicketOrder<T>>, _>(|_, tickets| {
+				translated += 1;
+				let (kept, lost) = bound_tickets::<T>(tickets);
+				dropped += lost;
+				Some(kept)
+			});
+
+			PayerTxnTicketOrder::<T>::translate::<Vec<TxnTicketOrder<T>>, _>(|_, _, tickets| {
+				translated += 1;
+				let (kept, lost) = bound_tickets::<T>(tickets);
+				dropped += lost;
+				Some(kept)
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "vane-payment", "MigrateToV1 bounded {} entries, dropped {} records", translated, dropped);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let payees = PayeeTxnReceipt::<T>::iter_keys().count() as u64;
+			Ok(payees.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let payees = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Values left over their bound fail to decode and are skipped
+			ensure!(PayeeTxnReceipt::<T>::iter_values().count() as u64 == payees, "payee receipts lost or over bound");
+			ensure!(
+				AccountMultiTxns::<T>::iter_values().count() == AccountMultiTxns::<T>::iter_keys().count(),
+				"executed payments over bound"
+			);
+			ensure!(
+				PayeeTxnTicketOrder::<T>::iter_values().count() == PayeeTxnTicketOrder::<T>::iter_keys().count(),
+				"payee tickets over bound"
+			);
+			ensure!(
+				PayerTxnTicketOrder::<T>::iter_values().count() == PayerTxnTicketOrder::<T>::iter_keys().count(),
+				"payer tickets over bound"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Settled receipts go first, then the oldest ones. The payer's receipt of a dropped entry stays
+	fn bound_receipts<T: Config>(mut receipts: Vec<TxnReceipt<T>>) -> (BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>, u64) {
+		let max = T::MaxPayeeReceipts::get() as usize;
+		let mut excess = receipts.len().saturating_sub(max);
+		let dropped = excess as u64;
+
+		receipts.retain(|receipt| {
+			if excess > 0 && receipt.is_settled() {
+				excess -= 1;
+				false
+			} else {
+				true
+			}
+		});
+		receipts.drain(..excess);
+
+		(BoundedVec::truncate_from(receipts), dropped)
+	}
+
+	fn bound_tickets<T: Config>(mut tickets: Vec<TxnTicketOrder<T>>) -> (BoundedVec<TxnTicketOrder<T>, T::MaxPayeeReceipts>, u64) {
+		let excess = tickets.len().saturating_sub(T::MaxPayeeReceipts::get() as usize);
+		tickets.drain(..excess);
+
+		(BoundedVec::truncate_from(tickets), excess as u64)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::{
+		fungible::{InspectHold, MutateHold},
+		tokens::currency::Currency,
+		ExistenceRequirement,
+	};
+	use helper::ReferenceNo;
+	use sp_runtime::{traits::Zero, SaturatedConversion};
+	use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+	// Payments opened before v2 sent their escrow to the multi_id shared by the payer and payee,
+	// and confirming one of them released the whole multi_id balance to the payee.
+	// 		1. An open payment still covered by its multi_id is moved back to the payer and held there
+	// 		2. One opened after the escrow moved to holds is matched, by reference, to a share of the
+	// 		   payer's hold no other payment claimed
+	// 		3. Any other open payment cannot be told apart from one swept by another release, it is
+	// 		   listed in `PaymentsUnderReview` and its multi_id is left untouched
+	// 		4. What is left on the other multi_ids is dust of the payer's escrows, it goes back to the
+	// 		   payer and the emptied multi_id account is reaped. Payments no longer fund multi_id accounts
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 1 {
+				log::info!(target: "vane-payment", "MigrateToV2 skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let payments = legacy_payments::<T>();
+			let mut multi_ids = BTreeMap::new();
+			let mut under_review = BTreeSet::new();
+			let (mut held, mut matched, mut reviewed, mut drained) = (0u64, 0u64, 0u64, 0u64);
+
+			// Holds of each payer before any escrow is moved onto them, every payment claims its share once
+			let mut unclaimed_holds: BTreeMap<T::AccountId, u128> = BTreeMap::new();
+			for (payer, ..) in payments.iter() {
+				unclaimed_holds.entry(payer.clone()).or_insert_with(|| {
+					T::Escrow::balance_on_hold(&HoldReason::VaneEscrow.into(), payer).saturated_into()
+				});
+			}
+
+			for (payer, payee, reference_no, multi_id, amount) in payments.iter() {
+				multi_ids.insert(multi_id.clone(), payer.clone());
+
+				if !is_open::<T>(reference_no) {
+					continue
+				}
+
+				let available: u128 = <T as Config>::Currency::free_balance(multi_id).saturated_into();
+				let unclaimed = unclaimed_holds.entry(payer.clone()).or_default();
+
+				if available >= *amount {
+					// Nothing moves when the payer cannot hold the amount, the escrow stays in the multi_id
+					let result = frame_support::storage::with_storage_layer(|| {
+						escrow_on_payer::<T>(payer, multi_id, *amount)
+					});
+
+					match result {
+						Ok(()) => held += 1,
+						Err(error) => log::warn!(
+							target: "vane-payment",
+							"MigrateToV2 failed to hold {:?} for {:?}: {:?}",
+							amount, payer, error,
+						),
+					}
+				} else if available.is_zero() && *unclaimed >= *amount {
+					// Opened after the escrow moved to holds
+					*unclaimed = unclaimed.saturating_sub(*amount);
+					matched += 1;
+				} else {
+					PaymentsUnderReview::<T>::insert(reference_no, (payer.clone(), payee.clone()));
+					under_review.insert(multi_id.clone());
+					reviewed += 1;
+					log::warn!(
+						target: "vane-payment",
+						"MigrateToV2 found {:?} of {:?} escrowed for {:?}, listed for review",
+						available, amount, reference_no,
+					);
+				}
+			}
+
+			for (multi_id, payer) in multi_ids.iter() {
+				// Kept as evidence for the payments under review
+				if under_review.contains(multi_id) {
+					continue
+				}
+
+				let dust = <T as Config>::Currency::free_balance(multi_id);
+				if dust.is_zero() {
+					continue
+				}
+
+				match <T as Config>::Currency::transfer(multi_id, payer, dust, ExistenceRequirement::AllowDeath) {
+					Ok(()) => drained += 1,
+					Err(error) => log::warn!(
+						target: "vane-payment",
+						"MigrateToV2 failed to return {:?} left on {:?}: {:?}",
+						dust, multi_id, error,
+					),
+				}
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: "vane-payment",
+				"MigrateToV2 held {} escrows, matched {} to holds, listed {} for review, drained {} of {} multi_id accounts",
+				held, matched, reviewed, drained, multi_ids.len(),
+			);
+
+			let reads = (payments.len() as u64)
+				.saturating_mul(5)
+				.saturating_add(multi_ids.len() as u64)
+				.saturating_add(unclaimed_holds.len() as u64);
+			let writes = held.saturating_mul(4).saturating_add(reviewed).saturating_add(drained.saturating_mul(2));
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let open = legacy_payments::<T>()
+				.into_iter()
+				.filter(|(_, _, reference_no, _, _)| is_open::<T>(reference_no))
+				.count() as u64;
+			Ok(open.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+
+			let open_before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Every payment still open is held on its payer or listed for review
+			let mut open = 0u64;
+			let mut escrowed: BTreeMap<T::AccountId, u128> = BTreeMap::new();
+			for (payer, _, reference_no, _, amount) in legacy_payments::<T>() {
+				if !is_open::<T>(&reference_no) {
+					continue
+				}
+				open += 1;
+				if !PaymentsUnderReview::<T>::contains_key(&reference_no) {
+					let total = escrowed.entry(payer).or_default();
+					*total = total.saturating_add(amount);
+				}
+			}
+			ensure!(open == open_before, "open payments changed");
+
+			for (payer, amount) in escrowed {
+				let on_hold: u128 =
+					T::Escrow::balance_on_hold(&HoldReason::VaneEscrow.into(), &payer).saturated_into();
+				ensure!(on_hold >= amount, "escrow not held on the payer");
+			}
+
+			Ok(())
+		}
+	}
+
+	// Payments which were not refunded, with the multi_id derived from their signers
+	// Value ----> [(payer, payee, reference_no, multi_id, amount)]
+	fn legacy_payments<T: Config>() -> Vec<(T::AccountId, T::AccountId, ReferenceNo, T::AccountId, u128)> {
+		AllowedSigners::<T>::iter()
+			// Asset payments were always held
+			.filter(|(_, reference_no, _)| !PaymentAsset::<T>::contains_key(reference_no))
+			.filter_map(|(payer, reference_no, signers)| {
+				let payee = signers.get_payee().clone();
+				let amount = Pallet::<T>::escrowed_amount(&payer, &payee, &reference_no).ok()?;
+				let multi_id = Pallet::<T>::derive_multi_id(signers);
+				Some((payer, payee, reference_no, multi_id, amount))
+			})
+			.collect()
+	}
+
+	// Released payments keep both confirmations
+	fn is_open<T: Config>(reference_no: &ReferenceNo) -> bool {
+		ConfirmedSigners::<T>::decode_len(reference_no).unwrap_or(0) < 2
+	}
+
+	fn escrow_on_payer<T: Config>(payer: &T::AccountId, multi_id: &T::AccountId, amount: u128) -> DispatchResult {
+		let balance: BalanceOfPay<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+		<T as Config>::Currency::transfer(multi_id, payer, balance, ExistenceRequirement::AllowDeath)?;
+
+		let held: EscrowBalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+		T::Escrow::hold(&HoldReason::VaneEscrow.into(), payer, held)
+	}
+}
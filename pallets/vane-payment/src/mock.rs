@@ -0,0 +1,230 @@
+// Test runtime for Vane payments.
+// Native payments are held by `Balances`, asset payments by the derived assets of `Assets`
+
+use crate as vane_payment;
+use crate::helper::ReferenceNo;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{fungible, AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, Hooks},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
+};
+use vane_primitive::{CurrencyId, MultiCurrencyAsset};
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const PAYER: AccountId = 1;
+pub const PAYEE: AccountId = 2;
+pub const STRANGER: AccountId = 3;
+pub const LEGAL_TEAM: AccountId = 4;
+// Issuer of the derived assets
+pub const ISSUER: AccountId = 9;
+
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		VaneRegister: vane_register,
+		VaneOrder: vane_order,
+		VanePayment: vane_payment,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<CurrencyId> for AssetsBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> CurrencyId {
+		CurrencyId::Foreign(id)
+	}
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = CurrencyId;
+	type AssetIdParameter = CurrencyId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<32>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetsBenchmarkHelper;
+}
+
+impl vane_register::Config for Test {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProducts = ConstU32<8>;
+	type MaxLinkLen = ConstU32<64>;
+	type WeightInfo = ();
+}
+
+impl vane_order::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MaxOrders = ConstU32<8>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ConfirmationWindow: u64 = 10;
+	pub const MaxConfirmationWindow: u64 = 100;
+	pub const VotingPeriod: u64 = 5;
+	pub const DisputeTimeout: u64 = 20;
+	pub const GovernanceQuorum: Perbill = Perbill::from_percent(50);
+	pub const RevertPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxRevertPenalty: Perbill = Perbill::from_percent(30);
+	pub const RevertCooldown: u64 = 10;
+}
+
+pub type Escrow = Balances;
+pub type EscrowAssets = MultiCurrencyAsset<Test, (), RuntimeHoldReason>;
+
+impl vane_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ConfirmationWindow = ConfirmationWindow;
+	type MaxConfirmationWindow = MaxConfirmationWindow;
+	type MaxExpiriesPerBlock = ConstU32<8>;
+	type MaxPayeeReceipts = ConstU32<8>;
+	type MaxAccountTxns = ConstU32<8>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Escrow = Escrow;
+	type Assets = EscrowAssets;
+	type AssetRegistry = ();
+	type ResolverOrigin = EnsureRoot<AccountId>;
+	type DisputeTimeout = DisputeTimeout;
+	type VotingPeriod = VotingPeriod;
+	type GovernanceQuorum = GovernanceQuorum;
+	type MaxCouncilMembers = ConstU32<4>;
+	type RevertPenalty = RevertPenalty;
+	type MaxRevertPenalty = MaxRevertPenalty;
+	type RevertCooldown = RevertCooldown;
+	type OnEscrowOpened = ();
+	type OnConfirmed = ();
+	type OnReleased = ();
+	type OnReverted = ();
+	type WeightInfo = ();
+}
+
+// Native balance held on `who` for Vane payments
+pub fn escrowed(who: &AccountId) -> Balance {
+	<Escrow as fungible::InspectHold<AccountId>>::balance_on_hold(&vane_payment::HoldReason::VaneEscrow.into(), who)
+}
+
+// Reference of the last payment opened
+pub fn last_reference() -> ReferenceNo {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::VanePayment(vane_payment::Event::BalanceHeld { reference_no, .. }) => {
+				Some(ReferenceNo::truncate_from(reference_no))
+			},
+			_ => None,
+		})
+		.expect("no payment opened")
+}
+
+// Moves to block `n`, every block gives its idle weight to the expiry queue
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		VanePayment::on_idle(next, Weight::MAX);
+	}
+}
+
+// Payer and payee funded in the native currency, the payer also holds every built in asset
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let accounts = [PAYER, PAYEE, STRANGER, ISSUER];
+	pallet_balances::GenesisConfig::<Test> {
+		balances: accounts.iter().map(|account| (*account, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let currencies = [CurrencyId::DOT, CurrencyId::USDT, CurrencyId::USDC];
+	pallet_assets::GenesisConfig::<Test> {
+		assets: currencies.iter().map(|currency_id| (*currency_id, ISSUER, true, 1)).collect(),
+		metadata: vec![],
+		accounts: currencies.iter().map(|currency_id| (*currency_id, PAYER, INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
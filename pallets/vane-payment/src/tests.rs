@@ -0,0 +1,485 @@
+// Payment lifecycle and migration tests
+
+use crate::{
+	helper::{Confirm, ReferenceNo, ResolverChoice, RevertReasons, Token, TxnReceipt, Verdict, XcmStatus},
+	migrations::{
+		v1::{self, RekeyPayerReceipts},
+		v2::MigrateToV2,
+	},
+	mock::*,
+	AllowedSigners, Call, Error, Pallet, PayerTxnReceipt, PaymentAsset, PaymentsUnderReview, ReceiptIndex,
+	RevertedTxnPayee,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::{traits::Dispatchable, DispatchError, Percent};
+use vane_primitive::{escrow::VaneEscrow, CurrencyId};
+
+const AMOUNT: u128 = 100_000;
+
+// Runs a migration with its try-runtime checks when they are built
+fn upgrade<M: OnRuntimeUpgrade>() {
+	#[cfg(feature = "try-runtime")]
+	let state = M::pre_upgrade().unwrap();
+	M::on_runtime_upgrade();
+	#[cfg(feature = "try-runtime")]
+	M::post_upgrade(state).unwrap();
+}
+
+// Confirmation
+
+#[test]
+fn only_the_payee_confirms_first() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let reference = last_reference();
+
+		assert_noop!(
+			VanePayment::confirm_pay(RuntimeOrigin::signed(STRANGER), Confirm::Payee, reference.to_vec()),
+			Error::<Test>::NotThePayee
+		);
+		assert_noop!(
+			VanePayment::confirm_pay(RuntimeOrigin::signed(PAYER), Confirm::Payee, reference.to_vec()),
+			Error::<Test>::NotThePayee
+		);
+	});
+}
+
+#[test]
+fn only_the_payer_confirms_last() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let reference = last_reference();
+		assert_ok!(VanePayment::confirm_pay(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+
+		assert_noop!(
+			VanePayment::confirm_pay(RuntimeOrigin::signed(STRANGER), Confirm::Payer, reference.to_vec()),
+			Error::<Test>::NotThePayer
+		);
+
+		assert_ok!(VanePayment::confirm_pay(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+		assert_eq!(escrowed(&PAYER), 0);
+		assert_eq!(Balances::free_balance(PAYEE), INITIAL_BALANCE + AMOUNT);
+	});
+}
+
+// Expiry
+
+#[test]
+fn short_window_expires_before_an_earlier_longer_one() {
+	new_test_ext().execute_with(|| {
+		let long = MaxConfirmationWindow::get();
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, Some(long)));
+		run_to_block(2);
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, Some(5)));
+		let short = last_reference();
+
+		run_to_block(7);
+
+		assert_eq!(receipt_status(&short), XcmStatus::Expired);
+		assert_eq!(escrowed(&PAYER), AMOUNT);
+	});
+}
+
+#[test]
+fn open_payment_cannot_be_expired_early() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let reference = last_reference();
+
+		assert_noop!(<VanePayment as VaneEscrow<AccountId>>::expire(&reference), Error::<Test>::PaymentNotExpired);
+
+		run_to_block(1 + ConfirmationWindow::get());
+		assert_eq!(receipt_status(&reference), XcmStatus::Expired);
+	});
+}
+
+// Asset payments
+
+#[test]
+fn usdc_payment_is_recorded_in_usdc() {
+	new_test_ext().execute_with(|| {
+		let usdc = Some(CurrencyId::USDC);
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, usdc, None, None));
+		let reference = last_reference();
+
+		assert_eq!(PayerTxnReceipt::<Test>::get(PAYER, &reference).unwrap().currency(), Some(Token::USDC));
+		assert_eq!(PaymentAsset::<Test>::get(&reference), Some(CurrencyId::USDC));
+	});
+}
+
+#[test]
+fn released_asset_payment_forgets_its_asset() {
+	new_test_ext().execute_with(|| {
+		let usdt = Some(CurrencyId::USDT);
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, usdt, None, None));
+		let reference = last_reference();
+
+		assert_ok!(VanePayment::confirm_pay(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VanePayment::confirm_pay(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+		assert_eq!(Assets::balance(CurrencyId::USDT, PAYEE), AMOUNT);
+		assert!(!PaymentAsset::<Test>::contains_key(&reference));
+	});
+}
+
+#[test]
+fn expired_asset_payment_forgets_its_asset() {
+	new_test_ext().execute_with(|| {
+		let usdt = Some(CurrencyId::USDT);
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, usdt, None, None));
+		let reference = last_reference();
+
+		run_to_block(1 + ConfirmationWindow::get());
+
+		assert_eq!(Assets::balance(CurrencyId::USDT, PAYER), INITIAL_BALANCE);
+		assert!(!PaymentAsset::<Test>::contains_key(&reference));
+	});
+}
+
+// Order payments
+
+// Payee listing `products` as (product id, price)
+fn list_products(products: &[(u32, u128)]) {
+	assert_ok!(VaneRegister::register_payee(
+		RuntimeOrigin::signed(PAYEE),
+		b"payee".to_vec(),
+		b"link".to_vec(),
+		b"location".to_vec()
+	));
+	for (product_id, price) in products {
+		assert_ok!(VaneRegister::update_products(
+			RuntimeOrigin::signed(PAYEE),
+			*product_id,
+			b"link".to_vec(),
+			*price,
+			None
+		));
+	}
+}
+
+#[test]
+fn order_payment_holds_the_price_of_the_paid_item() {
+	new_test_ext().execute_with(|| {
+		list_products(&[(7, 100), (8, 300)]);
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 8, PAYEE));
+
+		assert_ok!(VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 8, None));
+		assert_eq!(escrowed(&PAYER), 300);
+	});
+}
+
+#[test]
+fn paying_an_item_not_ordered_fails() {
+	new_test_ext().execute_with(|| {
+		list_products(&[(7, 100)]);
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(STRANGER), 7, PAYEE));
+
+		assert_noop!(
+			VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 7, None),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn unconfirmed_order_payment_is_refunded_after_the_window() {
+	new_test_ext().execute_with(|| {
+		list_products(&[(7, 100)]);
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+		assert_ok!(VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 7, None));
+
+		run_to_block(ConfirmationWindow::get());
+		assert_eq!(escrowed(&PAYER), 100);
+
+		run_to_block(1 + ConfirmationWindow::get());
+		assert_eq!(escrowed(&PAYER), 0);
+		assert_eq!(Balances::free_balance(PAYER), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn order_payment_can_be_reverted() {
+	new_test_ext().execute_with(|| {
+		list_products(&[(7, 100)]);
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+		assert_ok!(VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 7, None));
+		let reference = last_reference();
+
+		assert_ok!(VanePayment::revert_fund(RuntimeOrigin::signed(PAYER), reference.to_vec(), RevertReasons::WrongPayeeAddress));
+		assert_eq!(escrowed(&PAYER), 0);
+		assert_eq!(Balances::free_balance(PAYER), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn settled_order_tickets_make_room_for_new_ones() {
+	new_test_ext().execute_with(|| {
+		list_products(&[(7, 100)]);
+		assert_ok!(VaneOrder::place_order(RuntimeOrigin::signed(PAYER), 7, PAYEE));
+
+		let mut references = Vec::new();
+		for _ in 0..<Test as crate::Config>::MaxPayeeReceipts::get() {
+			assert_ok!(VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 7, None));
+			references.push(last_reference());
+		}
+
+		// Every ticket belongs to an open payment, dispatched so the reserved reference is rolled back
+		let order_pay = RuntimeCall::VanePayment(Call::vane_order_pay { seller: PAYEE, item_no: 7, resolver: None });
+		assert_noop!(order_pay.dispatch(RuntimeOrigin::signed(PAYER)), Error::<Test>::TooManyReceipts);
+
+		assert_ok!(VanePayment::revert_fund(RuntimeOrigin::signed(PAYER), references[0].to_vec(), RevertReasons::ChangeOfDecision));
+		assert_ok!(VanePayment::vane_order_pay(RuntimeOrigin::signed(PAYER), PAYEE, 7, None));
+	});
+}
+
+// Disputes
+
+// Disputed payment before the legal team
+fn disputed_payment() -> ReferenceNo {
+	assert_ok!(VanePayment::set_resolver(RuntimeOrigin::root(), Some(LEGAL_TEAM)));
+	assert_ok!(VanePayment::vane_pay(
+		RuntimeOrigin::signed(PAYER),
+		PAYEE,
+		AMOUNT,
+		None,
+		Some(ResolverChoice::LegalTeam),
+		None
+	));
+	let reference = last_reference();
+	assert_ok!(VanePayment::raise_dispute(RuntimeOrigin::signed(PAYER), reference.to_vec()));
+	reference
+}
+
+#[test]
+fn refund_ruling_counts_against_the_payee() {
+	new_test_ext().execute_with(|| {
+		let reference = disputed_payment();
+
+		assert_ok!(VanePayment::resolve_dispute(RuntimeOrigin::signed(LEGAL_TEAM), reference.to_vec(), Verdict::Refund));
+		assert_eq!(escrowed(&PAYER), 0);
+		assert_eq!(Balances::free_balance(PAYER), INITIAL_BALANCE);
+		assert_eq!(RevertedTxnPayee::<Test>::get(PAYEE), 1);
+	});
+}
+
+#[test]
+fn split_ruling_does_not_count_against_the_payee() {
+	new_test_ext().execute_with(|| {
+		let reference = disputed_payment();
+
+		let nothing = Verdict::Split(Percent::zero());
+		assert_ok!(VanePayment::resolve_dispute(RuntimeOrigin::signed(LEGAL_TEAM), reference.to_vec(), nothing));
+		assert_eq!(Balances::free_balance(PAYER), INITIAL_BALANCE);
+		assert_eq!(RevertedTxnPayee::<Test>::get(PAYEE), 0);
+	});
+}
+
+#[test]
+fn share_the_payee_cannot_receive_is_refunded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::set_resolver(RuntimeOrigin::root(), Some(LEGAL_TEAM)));
+		let usdc = Some(CurrencyId::USDC);
+		assert_ok!(VanePayment::vane_pay(
+			RuntimeOrigin::signed(PAYER),
+			PAYEE,
+			AMOUNT,
+			usdc,
+			Some(ResolverChoice::LegalTeam),
+			None
+		));
+		let reference = last_reference();
+		assert_ok!(VanePayment::raise_dispute(RuntimeOrigin::signed(PAYEE), reference.to_vec()));
+
+		// Half of the payment is below the minimum balance of a new USDC account
+		assert_ok!(Assets::force_asset_status(RuntimeOrigin::root(), CurrencyId::USDC, ISSUER, ISSUER, ISSUER, ISSUER, AMOUNT, true, false));
+
+		let half = Verdict::Split(Percent::from_percent(50));
+		assert_ok!(VanePayment::resolve_dispute(RuntimeOrigin::signed(LEGAL_TEAM), reference.to_vec(), half));
+
+		assert_eq!(Assets::balance(CurrencyId::USDC, PAYEE), 0);
+		assert_eq!(Assets::balance(CurrencyId::USDC, PAYER), INITIAL_BALANCE);
+		assert_eq!(receipt_status(&reference), XcmStatus::Expired);
+	});
+}
+
+#[test]
+fn unruled_dispute_is_refunded_after_the_timeout() {
+	new_test_ext().execute_with(|| {
+		let reference = disputed_payment();
+
+		assert_noop!(
+			VanePayment::time_out_dispute(RuntimeOrigin::signed(STRANGER), reference.to_vec()),
+			Error::<Test>::DisputeNotTimedOut
+		);
+
+		run_to_block(1 + DisputeTimeout::get());
+		assert_ok!(VanePayment::time_out_dispute(RuntimeOrigin::signed(STRANGER), reference.to_vec()));
+
+		assert_eq!(escrowed(&PAYER), 0);
+		assert_eq!(Balances::free_balance(PAYER), INITIAL_BALANCE);
+		assert_eq!(RevertedTxnPayee::<Test>::get(PAYEE), 0);
+	});
+}
+
+// Migrations
+
+#[test]
+fn rekey_moves_legacy_receipts_under_their_reference() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		let reference = ReferenceNo::truncate_from(vec![7u8; 6]);
+		let receipt = TxnReceipt::<Test>::new(PAYEE, PAYER, ISSUER, reference.clone(), AMOUNT, AMOUNT, None);
+		v1::PayerTxnReceipt::<Test>::insert(PAYER, PAYEE, receipt.clone());
+
+		upgrade::<RekeyPayerReceipts<Test>>();
+
+		assert!(!v1::PayerTxnReceipt::<Test>::contains_key(PAYER, PAYEE));
+		assert_eq!(PayerTxnReceipt::<Test>::get(PAYER, &reference), Some(receipt));
+		assert_eq!(ReceiptIndex::<Test>::get(&reference), Some((PAYER, PAYEE)));
+	});
+}
+
+#[test]
+fn rekey_leaves_receipts_keyed_by_their_reference() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let reference = last_reference();
+		let receipt = PayerTxnReceipt::<Test>::get(PAYER, &reference);
+		assert!(receipt.is_some());
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		upgrade::<RekeyPayerReceipts<Test>>();
+
+		assert_eq!(PayerTxnReceipt::<Test>::get(PAYER, &reference), receipt);
+		assert_eq!(ReceiptIndex::<Test>::get(&reference), Some((PAYER, PAYEE)));
+		assert_eq!(escrowed(&PAYER), AMOUNT);
+	});
+}
+
+// Pre v2 payment, its escrow moved from the payer's hold to the multi_id, which kept `left` of it
+fn legacy_payment(left: u128) -> (ReferenceNo, AccountId) {
+	assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+	let reference = last_reference();
+	let multi_id = Pallet::<Test>::derive_multi_id(AllowedSigners::<Test>::get(PAYER, &reference).unwrap());
+
+	assert_ok!(VanePayment::release_held(&PAYER, &reference, AMOUNT));
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(PAYER), multi_id, left));
+	(reference, multi_id)
+}
+
+fn receipt_status(reference: &ReferenceNo) -> XcmStatus {
+	PayerTxnReceipt::<Test>::get(PAYER, reference).unwrap().xcm_status
+}
+
+#[test]
+fn escrow_left_on_the_multi_id_is_held_on_the_payer() {
+	new_test_ext().execute_with(|| {
+		let (reference, multi_id) = legacy_payment(AMOUNT);
+		StorageVersion::new(1).put::<Pallet<Test>>();
+
+		upgrade::<MigrateToV2<Test>>();
+
+		assert_eq!(escrowed(&PAYER), AMOUNT);
+		assert_eq!(Balances::free_balance(multi_id), 0);
+		assert!(!PaymentsUnderReview::<Test>::contains_key(&reference));
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 2);
+	});
+}
+
+#[test]
+fn a_hold_is_matched_to_one_payment_only() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let first = last_reference();
+		assert_ok!(VanePayment::vane_pay(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, None, None, None));
+		let second = last_reference();
+		// Only one of the two payments is still held
+		assert_ok!(VanePayment::release_held(&PAYER, &second, AMOUNT));
+		StorageVersion::new(1).put::<Pallet<Test>>();
+
+		upgrade::<MigrateToV2<Test>>();
+
+		assert_eq!(escrowed(&PAYER), AMOUNT);
+		let reviewed = [&first, &second]
+			.into_iter()
+			.filter(|reference| PaymentsUnderReview::<Test>::contains_key(*reference))
+			.count();
+		assert_eq!(reviewed, 1);
+	});
+}
+
+#[test]
+fn short_multi_id_is_listed_for_review() {
+	new_test_ext().execute_with(|| {
+		let (reference, multi_id) = legacy_payment(AMOUNT / 2);
+		StorageVersion::new(1).put::<Pallet<Test>>();
+
+		upgrade::<MigrateToV2<Test>>();
+
+		assert_eq!(PaymentsUnderReview::<Test>::get(&reference), Some((PAYER, PAYEE)));
+		assert_eq!(receipt_status(&reference), XcmStatus::Tbc);
+		// Nothing is settled or drained until it is reviewed
+		assert_eq!(Balances::free_balance(multi_id), AMOUNT / 2);
+		assert_eq!(escrowed(&PAYER), 0);
+	});
+}
+
+// Payment the v2 migration listed for review, its multi_id kept half of the escrow
+fn payment_under_review() -> (ReferenceNo, AccountId) {
+	let (reference, multi_id) = legacy_payment(AMOUNT / 2);
+	StorageVersion::new(1).put::<Pallet<Test>>();
+	upgrade::<MigrateToV2<Test>>();
+	assert!(PaymentsUnderReview::<Test>::contains_key(&reference));
+	(reference, multi_id)
+}
+
+#[test]
+fn payment_under_review_is_not_settled_by_its_participants() {
+	new_test_ext().execute_with(|| {
+		let (reference, multi_id) = payment_under_review();
+
+		assert_noop!(
+			VanePayment::confirm_pay(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()),
+			Error::<Test>::PaymentUnderReview
+		);
+		assert_noop!(
+			VanePayment::revert_fund(RuntimeOrigin::signed(PAYER), reference.to_vec(), RevertReasons::ChangeOfDecision),
+			Error::<Test>::PaymentUnderReview
+		);
+
+		run_to_block(1 + ConfirmationWindow::get());
+		assert_eq!(receipt_status(&reference), XcmStatus::Tbc);
+		assert_eq!(Balances::free_balance(multi_id), AMOUNT / 2);
+	});
+}
+
+#[test]
+fn review_is_settled_out_of_the_multi_id() {
+	new_test_ext().execute_with(|| {
+		let (reference, multi_id) = payment_under_review();
+		let payer_balance = Balances::free_balance(PAYER);
+
+		assert_noop!(
+			VanePayment::settle_review(RuntimeOrigin::signed(PAYER), reference.to_vec(), 0, AMOUNT / 2),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			VanePayment::settle_review(RuntimeOrigin::root(), reference.to_vec(), AMOUNT, 1),
+			Error::<Test>::SettlementExceedsPayment
+		);
+
+		assert_ok!(VanePayment::settle_review(RuntimeOrigin::root(), reference.to_vec(), AMOUNT / 4, AMOUNT / 4));
+
+		assert_eq!(Balances::free_balance(PAYEE), INITIAL_BALANCE + AMOUNT / 4);
+		assert_eq!(Balances::free_balance(PAYER), payer_balance + AMOUNT / 4);
+		assert_eq!(Balances::free_balance(multi_id), 0);
+		assert_eq!(receipt_status(&reference), XcmStatus::Completed);
+		assert!(!PaymentsUnderReview::<Test>::contains_key(&reference));
+		assert!(!AllowedSigners::<Test>::contains_key(PAYER, &reference));
+	});
+}
@@ -0,0 +1,289 @@
+//! Weights for `vane_payment`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+//! `r` is the number of receipts or order tickets the payee already holds,
+//! `p` the products the payee lists, `o` the orders placed with the payee
+//! and `m` the executed payments kept for the payer.
+//! `v` is the number of council members who voted on a dispute.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_payment`.
+pub trait WeightInfo {
+	fn vane_pay(r: u32, ) -> Weight;
+	fn vane_order_pay(p: u32, o: u32, r: u32, ) -> Weight;
+	fn confirm_pay(m: u32, ) -> Weight;
+	fn revert_fund(r: u32, ) -> Weight;
+	fn expire_payment(r: u32, ) -> Weight;
+	fn set_resolver() -> Weight;
+	fn raise_dispute() -> Weight;
+	fn resolve_dispute(r: u32, ) -> Weight;
+	fn set_council_member() -> Weight;
+	fn vote_dispute() -> Weight;
+	fn close_dispute(v: u32, r: u32, ) -> Weight;
+	fn settle_review(r: u32, ) -> Weight;
+}
+
+/// Hand-written weights for `vane_payment`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment PairNonce (r:1 w:1)
+	/// Storage: VanePayment ReceiptIndex (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:0 w:1)
+	/// Storage: VanePayment PayerTxnReceipt (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment ExpiryQueue (r:1 w:1)
+	/// Storage: VanePayment ExpiryCursor (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn vane_pay(r: u32, ) -> Weight {
+		Weight::from_parts(78_000_000, 7_400)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneRegister PayeeProducts (r:1 w:0)
+	/// Storage: VaneOrder PayeeOrderRef (r:1 w:0)
+	/// Storage: VaneOrder PayerOrder (r:1 w:0)
+	/// Storage: VanePayment PayeeTxnTicketOrder (r:1 w:1)
+	/// Storage: VanePayment PayerTxnTicketOrder (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:512 w:0)
+	/// Storage: VanePayment ConfirmedSigners (r:512 w:0)
+	/// Storage: VanePayment PairNonce (r:1 w:1)
+	/// Storage: VanePayment ReceiptIndex (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment ExpiryQueue (r:1 w:1)
+	/// Storage: VanePayment ExpiryCursor (r:1 w:1)
+	/// The range of component `p` is `[1, 255]`.
+	/// The range of component `o` is `[1, 255]`.
+	/// The range of component `r` is `[0, 512]`.
+	fn vane_order_pay(p: u32, o: u32, r: u32, ) -> Weight {
+		Weight::from_parts(84_000_000, 8_200)
+			.saturating_add(Weight::from_parts(380_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(620_000, 0).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 320).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(0, 260).saturating_mul(r.into()))
+	}
+	/// Storage: VanePayment ConfirmedSigners (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment AccountMultiTxns (r:1 w:1)
+	/// The range of component `m` is `[0, 256]`.
+	fn confirm_pay(m: u32, ) -> Weight {
+		Weight::from_parts(71_000_000, 6_300)
+			.saturating_add(Weight::from_parts(520_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(m.into()))
+	}
+	/// Storage: VanePayment ReceiptIndex (r:1 w:0)
+	/// Storage: VanePayment PayerTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:1 w:1)
+	/// Storage: VanePayment Disputes (r:1 w:0)
+	/// Storage: VanePayment RevertedTxnPayer (r:1 w:1)
+	/// Storage: VanePayment ConfirmedSigners (r:0 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn revert_fund(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7_000)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VanePayment ReceiptIndex (r:1 w:0)
+	/// Storage: VanePayment PayerTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:1 w:1)
+	/// Storage: VanePayment ConfirmedSigners (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn expire_payment(r: u32, ) -> Weight {
+		Weight::from_parts(66_000_000, 7_000)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VanePayment ResolverSigner (r:0 w:1)
+	fn set_resolver() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VanePayment ReceiptIndex (r:1 w:0)
+	/// Storage: VanePayment Disputes (r:1 w:1)
+	/// Storage: VanePayment AllowedSigners (r:1 w:0)
+	/// Storage: VanePayment ConfirmedSigners (r:1 w:0)
+	fn raise_dispute() -> Weight {
+		Weight::from_parts(34_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VanePayment Disputes (r:1 w:1)
+	/// Storage: VanePayment PayerTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment PayerTxnTicketOrder (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment AllowedSigners (r:0 w:1)
+	/// Storage: VanePayment ConfirmedSigners (r:0 w:1)
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment RevertedTxnPayee (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn resolve_dispute(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7_200)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VanePayment ResolverCouncil (r:1 w:1)
+	/// Storage: VanePayment CounterForResolverCouncil (r:1 w:1)
+	fn set_council_member() -> Weight {
+		Weight::from_parts(18_000_000, 3_500)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VanePayment ResolverCouncil (r:1 w:0)
+	/// Storage: VanePayment DisputeVoters (r:1 w:1)
+	/// Storage: VanePayment DisputeTallies (r:1 w:1)
+	fn vote_dispute() -> Weight {
+		Weight::from_parts(30_000_000, 3_700)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VanePayment DisputeTallies (r:1 w:1)
+	/// Storage: VanePayment Disputes (r:1 w:1)
+	/// Storage: VanePayment PayerTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment PayerTxnTicketOrder (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: VanePayment AllowedSigners (r:0 w:1)
+	/// Storage: VanePayment ConfirmedSigners (r:0 w:1)
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment DisputeVoters (r:0 w:256)
+	/// The range of component `v` is `[0, 256]`.
+	/// The range of component `r` is `[0, 512]`.
+	fn close_dispute(v: u32, r: u32, ) -> Weight {
+		Weight::from_parts(80_000_000, 7_400)
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VanePayment PaymentsUnderReview (r:1 w:1)
+	/// Storage: VanePayment PayerTxnReceipt (r:1 w:1)
+	/// Storage: VanePayment PayerTxnTicketOrder (r:1 w:0)
+	/// Storage: VanePayment AllowedSigners (r:1 w:1)
+	/// Storage: System Account (r:3 w:3)
+	/// Storage: VanePayment ConfirmedSigners (r:0 w:1)
+	/// Storage: VanePayment PayeeTxnReceipt (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn settle_review(r: u32, ) -> Weight {
+		Weight::from_parts(82_000_000, 8_600)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn vane_pay(r: u32, ) -> Weight {
+		Weight::from_parts(78_000_000, 7_400)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn vane_order_pay(p: u32, o: u32, r: u32, ) -> Weight {
+		Weight::from_parts(84_000_000, 8_200)
+			.saturating_add(Weight::from_parts(380_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(620_000, 0).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 320).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(0, 260).saturating_mul(r.into()))
+	}
+	fn confirm_pay(m: u32, ) -> Weight {
+		Weight::from_parts(71_000_000, 6_300)
+			.saturating_add(Weight::from_parts(520_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(m.into()))
+	}
+	fn revert_fund(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7_000)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn expire_payment(r: u32, ) -> Weight {
+		Weight::from_parts(66_000_000, 7_000)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn set_resolver() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn raise_dispute() -> Weight {
+		Weight::from_parts(34_000_000, 3_900)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn resolve_dispute(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 7_200)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn set_council_member() -> Weight {
+		Weight::from_parts(18_000_000, 3_500)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn vote_dispute() -> Weight {
+		Weight::from_parts(30_000_000, 3_700)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn close_dispute(v: u32, r: u32, ) -> Weight {
+		Weight::from_parts(80_000_000, 7_400)
+			.saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn settle_review(r: u32, ) -> Weight {
+		Weight::from_parts(82_000_000, 8_600)
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+}
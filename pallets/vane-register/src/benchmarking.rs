@@ -0,0 +1,69 @@
+// Benchmarks for Vane registration
+// `p` is the number of products the payee already lists
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
+use crate::helper::ProductProfile;
+
+// Profile fields at the length a client would usually submit
+fn field() -> Vec<u8> {
+	vec![0u8; 64]
+}
+
+// Product links at their maximum length
+fn link<T: Config>() -> Vec<u8> {
+	vec![0u8; T::MaxLinkLen::get() as usize]
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_payer() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(field()), Some(field()), Some(field()));
+
+		assert!(PayerStorage::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn register_payee() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), field(), field(), field());
+
+		assert!(PayeeStorage::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn update_products(p: Linear<0, { T::MaxProducts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let seller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::register_payee(RawOrigin::Signed(seller.clone()).into(), field(), field(), field())?;
+
+		let products = (0..p)
+			.map(|id| ProductProfile::<T>::new(
+				Some(BoundedVec::truncate_from(link::<T>())),
+				100u32.into(),
+				id,
+				seller.clone(),
+				BoundedVec::truncate_from(link::<T>())
+			))
+			.collect::<Vec<_>>();
+		PayeeProducts::<T>::insert(&seller, BoundedVec::<_, T::MaxProducts>::truncate_from(products));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), p, link::<T>(), 100u32.into(), Some(link::<T>()));
+
+		assert_eq!(PayeeProducts::<T>::decode_len(&seller), Some(p as usize + 1));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
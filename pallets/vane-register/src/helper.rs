@@ -50,18 +50,18 @@ impl<T:Config> PayerAccountProfile<T>{
 
 
 //Product Profile
-#[derive(Encode,Decode,RuntimeDebug,Clone,TypeInfo,PartialEq)]
+#[derive(Encode,Decode,RuntimeDebug,Clone,TypeInfo,MaxEncodedLen,PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct ProductProfile<T:Config>{
-	pub image_url:Option<Vec<u8>>,
+	pub image_url:Option<BoundedVec<u8,T::MaxLinkLen>>,
 	pub product_id:u32,
 	pub seller: T::AccountId,
-	pub link: Vec<u8>,
+	pub link: BoundedVec<u8,T::MaxLinkLen>,
 	pub amount: BalanceOf<T>,
 	
 }
 impl<T:Config> ProductProfile<T>{
-	pub fn new(image_url:Option<Vec<u8>>,amount: BalanceOf<T>, product_id:u32,seller:T::AccountId,link:Vec<u8>) -> Self{
+	pub fn new(image_url:Option<BoundedVec<u8,T::MaxLinkLen>>,amount: BalanceOf<T>, product_id:u32,seller:T::AccountId,link:BoundedVec<u8,T::MaxLinkLen>) -> Self{
 		Self{
 			image_url,
 			product_id,
@@ -3,6 +3,18 @@
 
 pub use pallet::*;
 pub mod helper;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 mod pallet{
@@ -16,11 +28,22 @@ mod pallet{
 	use frame_support::{traits::{Currency, ExistenceRequirement}};
     use crate::helper::{PayeeAccountProfile,ProductProfile, PayerAccountProfile};
 	use crate::helper::utils::{Confirm};
+	use crate::weights::WeightInfo;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config{
 		type Currency: Currency<Self::AccountId>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		// Products a payee can list
+		#[pallet::constant]
+		type MaxProducts: Get<u32>;
+
+		// Bytes of a product link or image url
+		#[pallet::constant]
+		type MaxLinkLen: Get<u32>;
+
+		type WeightInfo: WeightInfo;
 	}
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -33,13 +56,19 @@ mod pallet{
 	pub struct VaneId(pub BoundedVec<u8,MaxVaneId>);
 
 
+	// Products moved to bounded storage in v1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::error]
 	pub enum Error<T>{
 		AccountAlreadyRegistered,
-		UserIsNotRegistered
+		UserIsNotRegistered,
+		TooManyProducts,
+		LinkTooLong
 	}
 
 	#[pallet::event]
@@ -70,15 +99,14 @@ mod pallet{
 
 
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayeeProducts<T: Config> =
-	StorageMap<_,Blake2_128Concat,T::AccountId,Vec<ProductProfile<T>>, ValueQuery>;
+	StorageMap<_,Blake2_128Concat,T::AccountId,BoundedVec<ProductProfile<T>, T::MaxProducts>, ValueQuery>;
 
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T>{
 		#[pallet::call_index(0)]
-		#[pallet::weight(10)]
+		#[pallet::weight(T::WeightInfo::register_payer())]
 		pub fn register_payer(
 
 			origin: OriginFor<T>,
@@ -89,15 +117,16 @@ mod pallet{
 		) -> DispatchResult{
 
 			let signer = ensure_signed(origin)?;
-			ensure!(PayerStorage::<T>::contains_key(signer.clone()),Error::<T>::AccountAlreadyRegistered);
+			ensure!(!PayerStorage::<T>::contains_key(signer.clone()),Error::<T>::AccountAlreadyRegistered);
 
 			let time = <frame_system::Pallet<T>>::block_number();
 			// Generate a VaneId
 			// Without checking the kyc 
+			// The id is the first `MaxVaneId` bytes of the kyc hash
 			let hash = (kyc.unwrap()).using_encoded(blake2_128);
-			let five_bits_hash =  hash.to_vec()[..6].to_vec();
+			let five_bits_hash =  hash[..MaxVaneId::get() as usize].to_vec();
 
-			let vane_id = VaneId(five_bits_hash.try_into().unwrap());
+			let vane_id = VaneId(BoundedVec::truncate_from(five_bits_hash));
 						
 			let acc_profile = PayerAccountProfile::<T>::new(name,vane_id,email,signer.clone(),time);
 
@@ -108,7 +137,7 @@ mod pallet{
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(10)]
+		#[pallet::weight(T::WeightInfo::register_payee())]
 		pub fn register_payee(
 			origin:OriginFor<T>,
 			name:Vec<u8>,ig_link:Vec<u8>,
@@ -117,7 +146,7 @@ mod pallet{
 		) -> DispatchResult {
 
 			let signer = ensure_signed(origin)?;
-			ensure!(PayeeStorage::<T>::contains_key(signer.clone()),Error::<T>::AccountAlreadyRegistered);
+			ensure!(!PayeeStorage::<T>::contains_key(signer.clone()),Error::<T>::AccountAlreadyRegistered);
 
 
 				let time = <frame_system::Pallet<T>>::block_number();
@@ -131,7 +160,7 @@ mod pallet{
 
 		// A reference of product
 		#[pallet::call_index(3)]
-		#[pallet::weight(10)]
+		#[pallet::weight(T::WeightInfo::update_products(T::MaxProducts::get()))]
 		pub fn update_products(
 			origin:OriginFor<T>,
 			product_id:u32,
@@ -143,11 +172,13 @@ mod pallet{
 			// Check if the seller is registered
 			ensure!(<PayeeStorage<T>>::contains_key(seller.clone()), Error::<T>::UserIsNotRegistered);
 
+			let link = link.try_into().map_err(|_| Error::<T>::LinkTooLong)?;
+			let image_url = image_url.map(|url| url.try_into()).transpose().map_err(|_| Error::<T>::LinkTooLong)?;
+
 			// Construct Product Object
 			let product = ProductProfile::<T>::new(image_url,amount,product_id,seller.clone(),link);
-			PayeeProducts::<T>::mutate(&seller,|p_vec|{
-				p_vec.push(product)
-			});
+			PayeeProducts::<T>::try_mutate(&seller,|p_vec| p_vec.try_push(product))
+				.map_err(|_| Error::<T>::TooManyProducts)?;
 			Ok(())
 		}
 		//Idea on registering products
@@ -0,0 +1,76 @@
+// Storage migrations for Vane registration
+
+use super::*;
+use crate::helper::ProductProfile;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldProductProfile<T: Config> {
+		image_url: Option<Vec<u8>>,
+		product_id: u32,
+		seller: T::AccountId,
+		link: Vec<u8>,
+		amount: BalanceOf<T>,
+	}
+
+	// Moves the products into bounded vectors.
+	// Products with a link over `MaxLinkLen` are dropped, the first `MaxProducts` are kept
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+
+			PayeeProducts::<T>::translate::<Vec<OldProductProfile<T>>, _>(|_, products| {
+				translated += 1;
+
+				let products = products
+					.into_iter()
+					.filter_map(|old| {
+						let link = old.link.try_into().ok()?;
+						let image_url = old.image_url.map(|url| url.try_into()).transpose().ok()?;
+						Some(ProductProfile::<T>::new(image_url, old.amount, old.product_id, old.seller, link))
+					})
+					.take(T::MaxProducts::get() as usize)
+					.collect::<Vec<_>>();
+
+				Some(BoundedVec::truncate_from(products))
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let payees = PayeeProducts::<T>::iter_keys().count() as u64;
+			Ok(payees.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let payees = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Values left over their bound fail to decode and are skipped
+			ensure!(PayeeProducts::<T>::iter_values().count() as u64 == payees, "products lost or over bound");
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,61 @@
+// Registration tests
+
+use crate::{helper::PayerAccountProfile, mock::*, Error, PayeeProducts, PayeeStorage, PayerStorage, VaneId};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_io::hashing::blake2_128;
+
+fn register_payer(who: AccountId) -> frame_support::dispatch::DispatchResult {
+	VaneRegister::register_payer(RuntimeOrigin::signed(who), Some(b"payer".to_vec()), None, Some(b"kyc".to_vec()))
+}
+
+fn register_payee(who: AccountId) -> frame_support::dispatch::DispatchResult {
+	VaneRegister::register_payee(RuntimeOrigin::signed(who), b"payee".to_vec(), b"link".to_vec(), b"location".to_vec())
+}
+
+#[test]
+fn new_accounts_register_once_as_payer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register_payer(PAYER));
+		assert!(PayerStorage::<Test>::contains_key(PAYER));
+
+		assert_noop!(register_payer(PAYER), Error::<Test>::AccountAlreadyRegistered);
+	});
+}
+
+#[test]
+fn vane_id_is_the_head_of_the_kyc_hash() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register_payer(PAYER));
+
+		let hash = b"kyc".to_vec().using_encoded(blake2_128);
+		let vane_id = VaneId(BoundedVec::truncate_from(hash[..5].to_vec()));
+		let profile = PayerAccountProfile::<Test>::new(Some(b"payer".to_vec()), vane_id, None, PAYER, 1);
+
+		assert_eq!(PayerStorage::<Test>::get(PAYER), Some(profile));
+	});
+}
+
+#[test]
+fn new_accounts_register_once_as_payee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(register_payee(PAYEE));
+		assert!(PayeeStorage::<Test>::contains_key(PAYEE));
+
+		assert_noop!(register_payee(PAYEE), Error::<Test>::AccountAlreadyRegistered);
+	});
+}
+
+#[test]
+fn only_registered_payees_list_products() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			VaneRegister::update_products(RuntimeOrigin::signed(PAYEE), 1, b"link".to_vec(), 100, None),
+			Error::<Test>::UserIsNotRegistered
+		);
+
+		assert_ok!(register_payee(PAYEE));
+		assert_ok!(VaneRegister::update_products(RuntimeOrigin::signed(PAYEE), 1, b"link".to_vec(), 100, None));
+		assert_eq!(PayeeProducts::<Test>::decode_len(PAYEE), Some(1));
+	});
+}
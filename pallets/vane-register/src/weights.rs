@@ -0,0 +1,68 @@
+//! Weights for `vane_register`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+//! `p` is the number of products the payee already lists.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_register`.
+pub trait WeightInfo {
+	fn register_payer() -> Weight;
+	fn register_payee() -> Weight;
+	fn update_products(p: u32, ) -> Weight;
+}
+
+/// Hand-written weights for `vane_register`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VaneRegister PayerStorage (r:1 w:1)
+	fn register_payer() -> Weight {
+		Weight::from_parts(24_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneRegister PayeeStorage (r:1 w:1)
+	fn register_payee() -> Weight {
+		Weight::from_parts(23_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneRegister PayeeStorage (r:1 w:0)
+	/// Storage: VaneRegister PayeeProducts (r:1 w:1)
+	/// The range of component `p` is `[0, 255]`.
+	fn update_products(p: u32, ) -> Weight {
+		Weight::from_parts(26_000_000, 3_800)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_payer() -> Weight {
+		Weight::from_parts(24_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn register_payee() -> Weight {
+		Weight::from_parts(23_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn update_products(p: u32, ) -> Weight {
+		Weight::from_parts(26_000_000, 3_800)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
+	}
+}
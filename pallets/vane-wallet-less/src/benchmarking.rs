@@ -0,0 +1,119 @@
+// Benchmarks for wallet-less payments
+// `c` is the number of calls already pending under the password
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::{Currency, Get}, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::SaturatedConversion;
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+// Password at its maximum length
+fn password<T: Config>() -> BoundedVec<u8, T::MaxPasswordLen> {
+	BoundedVec::truncate_from(vec![1u8; T::MaxPasswordLen::get() as usize])
+}
+
+fn pending<T: Config>() -> usize {
+	PaymentCallMemPool::<T>::decode_len(password::<T>()).unwrap_or(0)
+}
+
+// Payment amount, large enough to open the multi_id account
+fn payment_amount<T: Config>() -> u128 {
+	let ed: u128 = <T as vane_payment::Config>::Currency::minimum_balance().saturated_into();
+	ed.saturating_mul(10).max(1_000_000)
+}
+
+fn delegated_signer<T: Config>() -> T::AccountId {
+	let delegated: T::AccountId = whitelisted_caller();
+	DelegatedSigner::<T>::put(&delegated);
+	delegated
+}
+
+fn funded_signer<T: Config>() -> T::AccountId {
+	let signer: T::AccountId = account("signer", 0, SEED);
+	let balance = payment_amount::<T>().saturating_mul(100);
+	<T as vane_payment::Config>::Currency::make_free_balance_be(&signer, balance.saturated_into());
+	signer
+}
+
+// Fills the password's queue with `c` payments
+fn pending_calls<T: Config>(c: u32, signer: &T::AccountId, payee: &T::AccountId) {
+	let calls = (0..c)
+		.map(|_| PaymentCalls::<T>::VanePay {
+			signer: signer.clone(),
+			payee: payee.clone(),
+			amount: payment_amount::<T>(),
+			resolver: None,
+		})
+		.collect::<Vec<_>>();
+	PaymentCallMemPool::<T>::insert(password::<T>(), BoundedVec::<_, T::MaxPendingCalls>::truncate_from(calls));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_delegated_signer() {
+		let delegated: T::AccountId = account("delegated", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, delegated.clone());
+
+		assert_eq!(DelegatedSigner::<T>::get(), Some(delegated));
+	}
+
+	#[benchmark]
+	fn place_vane_pay(c: Linear<0, { T::MaxPendingCalls::get() - 1 }>) {
+		let delegated = delegated_signer::<T>();
+		let signer = funded_signer::<T>();
+		let payee: T::AccountId = account("payee", 0, SEED);
+		pending_calls::<T>(c, &signer, &payee);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegated), signer, payee, payment_amount::<T>(), password::<T>().to_vec());
+
+		assert_eq!(pending::<T>(), c as usize + 1);
+	}
+
+	// The payee confirms an open payment
+	#[benchmark]
+	fn place_vane_confirm(c: Linear<0, { T::MaxPendingCalls::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let delegated = delegated_signer::<T>();
+		let payer = funded_signer::<T>();
+		let payee: T::AccountId = account("payee", 0, SEED);
+
+		vane_payment::Pallet::<T>::vane_pay(
+			RawOrigin::Signed(payer.clone()).into(),
+			payee.clone(),
+			payment_amount::<T>(),
+			None,
+			None,
+			None
+		)?;
+		let reference = vane_payment::PayerTxnReceipt::<T>::iter_key_prefix(&payer)
+			.next()
+			.ok_or(BenchmarkError::Stop("payment not recorded"))?;
+
+		pending_calls::<T>(c, &payer, &payee);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegated), payee, reference.to_vec(), 1, password::<T>().to_vec());
+
+		assert_eq!(pending::<T>(), c as usize + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn place_vane_revert() {
+		let delegated = delegated_signer::<T>();
+		let signer: T::AccountId = account("signer", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegated), signer);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
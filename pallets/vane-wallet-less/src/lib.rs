@@ -1,8 +1,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 // Setting up OCW
 
+pub mod migrations;
 pub mod ocw;
+pub mod weights;
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -13,13 +25,14 @@ pub mod pallet {
 		Blake2_128Concat,
 	};
 	use frame_system::{ensure_root, offchain::AppCrypto, pallet_prelude::*, RawOrigin};
+	use crate::weights::WeightInfo;
 
 	use frame_system::offchain::{
 		CreateSignedTransaction, SendSignedTransaction, SignedPayload, Signer, SigningTypes,
 		SubmitTransaction,
 	};
 
-	use vane_payment::{BalanceOfPay, Confirm, ResolverChoice};
+	use vane_payment::{BalanceOfPay, Confirm, ResolverChoice, MAX_BYTES, WeightInfo as PaymentWeightInfo};
 	use vane_register;
 
 	use sp_std::vec::Vec;
@@ -30,9 +43,23 @@ pub mod pallet {
 	{
 		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		// Calls kept per password
+		#[pallet::constant]
+		type MaxPendingCalls: Get<u32>;
+
+		// Bytes of the password keying the pending calls
+		#[pallet::constant]
+		type MaxPasswordLen: Get<u32>;
+
+		type WeightInfo: WeightInfo;
 	}
 
+	// Pending calls moved to bounded storage in v1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -42,7 +69,7 @@ pub mod pallet {
 
 	// Call mapping
 	//(Pallet Index, Call Index)
-	#[derive(Encode, Decode, TypeInfo, RuntimeDebug, Clone)]
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug, Clone)]
 	#[scale_info(skip_type_params(T))]
 	pub enum PaymentCalls<T: Config> {
 		VanePay {
@@ -54,7 +81,7 @@ pub mod pallet {
 		ConfirmPay {
 			signer: T::AccountId,
 			who: Confirm,
-			reference: Vec<u8>,
+			reference: BoundedVec<u8, MAX_BYTES>,
 		},
 	}
 
@@ -68,9 +95,13 @@ pub mod pallet {
 
 	// Key is the user password
 	#[pallet::storage]
-	#[pallet::unbounded]
-	pub type PaymentCallMemPool<T: Config> =
-		StorageMap<_, Blake2_128Concat, Vec<u8>, Vec<PaymentCalls<T>>, ValueQuery>;
+	pub type PaymentCallMemPool<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxPasswordLen>,
+		BoundedVec<PaymentCalls<T>, T::MaxPendingCalls>,
+		ValueQuery,
+	>;
 
 	// Storing the delegant signer using Sudo
 	#[pallet::storage]
@@ -87,6 +118,9 @@ pub mod pallet {
 		CallPlacingFailed,
 		UnAuthorized,
 		CallDispatchFailed,
+		TooManyCalls,
+		PasswordTooLong,
+		InvalidReference,
 		UnexpectedError, // System Error
 	}
 
@@ -107,7 +141,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		// Set delagated signer
 		#[pallet::call_index(0)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_delegated_signer())]
 		pub fn set_delegated_signer(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
 			let _caller = ensure_root(origin)?;
 			DelegatedSigner::<T>::set(Some(account));
@@ -115,7 +149,10 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(10)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::place_vane_pay(T::MaxPendingCalls::get())
+				.saturating_add(<T as vane_payment::Config>::WeightInfo::vane_pay(T::MaxPayeeReceipts::get()))
+		)]
 		pub fn place_vane_pay(
 			origin: OriginFor<T>,
 			signer: T::AccountId,
@@ -136,14 +173,19 @@ pub mod pallet {
 				resolver: None,
 			};
 			// Store in the CallsMemPool
-			PaymentCallMemPool::<T>::try_mutate(password, |calls| {
-				calls.push(call);
-				Ok(())
-			})
-			.map_err(|_: Error<T>| Error::<T>::UnAuthorized)?;
+			let password: BoundedVec<u8, T::MaxPasswordLen> =
+				password.try_into().map_err(|_| Error::<T>::PasswordTooLong)?;
+			PaymentCallMemPool::<T>::try_mutate(password, |calls| calls.try_push(call))
+				.map_err(|_| Error::<T>::TooManyCalls)?;
 
 			// Try manual dispatch
-			let VaneCall = vane_payment::Call::<T>::vane_pay { payee, amount, resolver: None }
+			let VaneCall = vane_payment::Call::<T>::vane_pay {
+				payee,
+				amount,
+				currency: None,
+				resolver: None,
+				confirmation_window: None,
+			}
 				.dispatch_bypass_filter(RawOrigin::Signed(signer).into())
 				.map_err(|_| Error::<T>::CallDispatchFailed)?;
 
@@ -151,7 +193,10 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(10)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::place_vane_confirm(T::MaxPendingCalls::get())
+				.saturating_add(<T as vane_payment::Config>::WeightInfo::confirm_pay(T::MaxAccountTxns::get()))
+		)]
 		pub fn place_vane_confirm(
 			origin: OriginFor<T>,
 			signer: T::AccountId,
@@ -175,16 +220,15 @@ pub mod pallet {
 			let call = PaymentCalls::<T>::ConfirmPay {
 				signer: signer.clone(),
 				who: confirm.clone(),
-				reference: reference.clone(),
+				reference: reference.clone().try_into().map_err(|_| Error::<T>::InvalidReference)?,
 			};
 
 			// Storage
 			// Store in the CallsMemPool
-			PaymentCallMemPool::<T>::try_mutate(password, |calls| {
-				calls.push(call);
-				Ok(())
-			})
-			.map_err(|_: Error<T>| Error::<T>::UnAuthorized)?;
+			let password: BoundedVec<u8, T::MaxPasswordLen> =
+				password.try_into().map_err(|_| Error::<T>::PasswordTooLong)?;
+			PaymentCallMemPool::<T>::try_mutate(password, |calls| calls.try_push(call))
+				.map_err(|_| Error::<T>::TooManyCalls)?;
 
 			// Try manual dispatch
 			let VaneCall =
@@ -196,7 +240,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_vane_revert())]
 		pub fn place_vane_revert(origin: OriginFor<T>, signer: T::AccountId) -> DispatchResult {
 			Ok(())
 		}
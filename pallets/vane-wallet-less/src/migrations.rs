@@ -0,0 +1,89 @@
+// Storage migrations for wallet-less payments
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use sp_std::vec::Vec;
+use vane_payment::{Confirm, ResolverChoice};
+
+pub mod v1 {
+	use super::*;
+
+	#[derive(Decode)]
+	pub enum OldPaymentCalls<T: Config> {
+		VanePay { signer: T::AccountId, payee: T::AccountId, amount: u128, resolver: Option<ResolverChoice> },
+		ConfirmPay { signer: T::AccountId, who: Confirm, reference: Vec<u8> },
+	}
+
+	#[frame_support::storage_alias]
+	pub type PaymentCallMemPool<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, Vec<u8>, Vec<OldPaymentCalls<T>>, ValueQuery>;
+
+	// Moves the pending calls into bounded vectors.
+	// Passwords over `MaxPasswordLen` lose their calls, the most recent `MaxPendingCalls` are kept
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let old = PaymentCallMemPool::<T>::drain().collect::<Vec<_>>();
+			let translated = old.len() as u64;
+			let mut dropped = 0u64;
+
+			for (password, calls) in old {
+				let Ok(password) = BoundedVec::<u8, T::MaxPasswordLen>::try_from(password) else {
+					dropped += 1;
+					continue
+				};
+
+				let mut calls = calls
+					.into_iter()
+					.filter_map(|call| match call {
+						OldPaymentCalls::VanePay { signer, payee, amount, resolver } =>
+							Some(PaymentCalls::<T>::VanePay { signer, payee, amount, resolver }),
+						OldPaymentCalls::ConfirmPay { signer, who, reference } =>
+							Some(PaymentCalls::<T>::ConfirmPay { signer, who, reference: reference.try_into().ok()? }),
+					})
+					.collect::<Vec<_>>();
+
+				let excess = calls.len().saturating_sub(T::MaxPendingCalls::get() as usize);
+				calls.drain(..excess);
+
+				crate::PaymentCallMemPool::<T>::insert(password, BoundedVec::<_, T::MaxPendingCalls>::truncate_from(calls));
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "vane-wallet-less", "MigrateToV1 bounded {} passwords, dropped {}", translated, dropped);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_mul(2).saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let kept = PaymentCallMemPool::<T>::iter_keys()
+				.filter(|password| password.len() <= T::MaxPasswordLen::get() as usize)
+				.count() as u64;
+			Ok(kept.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let kept = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Passwords over their bound are dropped, every other one keeps its calls
+			ensure!(crate::PaymentCallMemPool::<T>::iter_values().count() as u64 == kept, "pending calls lost");
+
+			Ok(())
+		}
+	}
+}
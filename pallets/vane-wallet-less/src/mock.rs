@@ -0,0 +1,236 @@
+// Test runtime for wallet-less payments.
+// Placed calls are dispatched to `VanePayment`, which holds native payments on `Balances`
+
+use crate as vane_wallet_less;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{fungible, AsEnsureOriginWithArg, ConstU128, ConstU32, Everything},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestXt,
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentityLookup},
+	AccountId32, BuildStorage, MultiSignature, MultiSigner, Perbill,
+};
+use vane_primitive::{CurrencyId, MultiCurrencyAsset};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+type Extrinsic = TestXt<RuntimeCall, ()>;
+
+pub const PAYER: AccountId = AccountId32::new([1u8; 32]);
+pub const PAYEE: AccountId = AccountId32::new([2u8; 32]);
+// Account the off-chain worker signs with
+pub const DELEGATED: AccountId = AccountId32::new([3u8; 32]);
+pub const STRANGER: AccountId = AccountId32::new([4u8; 32]);
+// Issuer of the derived assets
+pub const ISSUER: AccountId = AccountId32::new([9u8; 32]);
+
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		VaneRegister: vane_register,
+		VaneOrder: vane_order,
+		VanePayment: vane_payment,
+		VaneWalletLess: vane_wallet_less,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = MultiSigner;
+	type Signature = MultiSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: MultiSigner,
+		_account: AccountId,
+		nonce: u64,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<CurrencyId> for AssetsBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> CurrencyId {
+		CurrencyId::Foreign(id)
+	}
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = CurrencyId;
+	type AssetIdParameter = CurrencyId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<32>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetsBenchmarkHelper;
+}
+
+impl vane_register::Config for Test {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProducts = ConstU32<8>;
+	type MaxLinkLen = ConstU32<64>;
+	type WeightInfo = ();
+}
+
+impl vane_order::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MaxOrders = ConstU32<8>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ConfirmationWindow: u64 = 10;
+	pub const MaxConfirmationWindow: u64 = 100;
+	pub const VotingPeriod: u64 = 5;
+	pub const DisputeTimeout: u64 = 20;
+	pub const GovernanceQuorum: Perbill = Perbill::from_percent(50);
+	pub const RevertPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxRevertPenalty: Perbill = Perbill::from_percent(30);
+	pub const RevertCooldown: u64 = 10;
+}
+
+pub type Escrow = Balances;
+
+impl vane_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ConfirmationWindow = ConfirmationWindow;
+	type MaxConfirmationWindow = MaxConfirmationWindow;
+	type MaxExpiriesPerBlock = ConstU32<8>;
+	type MaxPayeeReceipts = ConstU32<8>;
+	type MaxAccountTxns = ConstU32<8>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Escrow = Escrow;
+	type Assets = MultiCurrencyAsset<Test, (), RuntimeHoldReason>;
+	type AssetRegistry = ();
+	type ResolverOrigin = EnsureRoot<AccountId>;
+	type DisputeTimeout = DisputeTimeout;
+	type VotingPeriod = VotingPeriod;
+	type GovernanceQuorum = GovernanceQuorum;
+	type MaxCouncilMembers = ConstU32<4>;
+	type RevertPenalty = RevertPenalty;
+	type MaxRevertPenalty = MaxRevertPenalty;
+	type RevertCooldown = RevertCooldown;
+	type OnEscrowOpened = ();
+	type OnConfirmed = ();
+	type OnReleased = ();
+	type OnReverted = ();
+	type WeightInfo = ();
+}
+
+impl vane_wallet_less::Config for Test {
+	type AuthorityId = crate::ocw::VaneOcw;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxPendingCalls = ConstU32<2>;
+	type MaxPasswordLen = ConstU32<8>;
+	type WeightInfo = ();
+}
+
+// Native balance held on `who` for Vane payments
+pub fn escrowed(who: &AccountId) -> Balance {
+	<Escrow as fungible::InspectHold<AccountId>>::balance_on_hold(&vane_payment::HoldReason::VaneEscrow.into(), who)
+}
+
+// Payer and payee funded in the native currency, DELEGATED places the calls
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: [PAYER, PAYEE, ISSUER].iter().map(|account| (account.clone(), INITIAL_BALANCE)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		vane_wallet_less::DelegatedSigner::<Test>::put(DELEGATED);
+	});
+	ext
+}
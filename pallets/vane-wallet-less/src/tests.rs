@@ -0,0 +1,166 @@
+// Wallet-less call tests, DELEGATED places calls on behalf of the signers
+
+use crate::{
+	migrations::v1::{self, MigrateToV1},
+	mock::*,
+	Call, Error, Pallet, PaymentCallMemPool, PaymentCalls,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResultWithPostInfo,
+	storage::unhashed,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use sp_runtime::{traits::Dispatchable, DispatchError};
+use vane_payment::Confirm;
+
+const AMOUNT: u128 = 100_000;
+const PASSWORD: &[u8] = b"secret";
+
+fn pending(password: &[u8]) -> Vec<PaymentCalls<Test>> {
+	PaymentCallMemPool::<Test>::get(BoundedVec::truncate_from(password.to_vec())).into_inner()
+}
+
+// Dispatched like an extrinsic, a failing call leaves no pending call behind
+fn place_pay(origin: AccountId, signer: AccountId, password: &[u8]) -> DispatchResultWithPostInfo {
+	RuntimeCall::VaneWalletLess(Call::place_vane_pay { signer, payee: PAYEE, amount: AMOUNT, password: password.to_vec() })
+		.dispatch(RuntimeOrigin::signed(origin))
+}
+
+// `who` is 1 for the payee and 2 for the payer
+fn place_confirm(signer: AccountId, reference: Vec<u8>, who: u8) -> DispatchResultWithPostInfo {
+	RuntimeCall::VaneWalletLess(Call::place_vane_confirm { signer, reference, who, password: PASSWORD.to_vec() })
+		.dispatch(RuntimeOrigin::signed(DELEGATED))
+}
+
+// Reference of the last payment opened
+fn last_reference() -> Vec<u8> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::VanePayment(vane_payment::Event::BalanceHeld { reference_no, .. }) => Some(reference_no),
+			_ => None,
+		})
+		.expect("no payment opened")
+}
+
+// Placing calls
+
+#[test]
+fn only_the_delegated_signer_places_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(place_pay(STRANGER, PAYER, PASSWORD), Error::<Test>::UnAuthorized);
+		assert_noop!(
+			VaneWalletLess::set_delegated_signer(RuntimeOrigin::signed(STRANGER), STRANGER),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn placed_payment_is_kept_and_escrowed_for_the_signer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(place_pay(DELEGATED, PAYER, PASSWORD));
+
+		assert!(matches!(
+			pending(PASSWORD).as_slice(),
+			[PaymentCalls::VanePay { signer, payee, amount: AMOUNT, resolver: None }] if *signer == PAYER && *payee == PAYEE
+		));
+		assert_eq!(escrowed(&PAYER), AMOUNT);
+	});
+}
+
+#[test]
+fn placed_confirmation_is_dispatched_for_the_signer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(place_pay(DELEGATED, PAYER, PASSWORD));
+		let reference = last_reference();
+
+		assert_ok!(place_confirm(PAYEE, reference.clone(), 1));
+
+		assert!(matches!(
+			pending(PASSWORD).last(),
+			Some(PaymentCalls::ConfirmPay { signer, who: Confirm::Payee, reference: placed }) if *signer == PAYEE && placed.to_vec() == reference
+		));
+
+		// The payee's confirmation was recorded, the payer can no longer confirm as the payee
+		assert_noop!(place_confirm(PAYER, reference, 1), Error::<Test>::CallDispatchFailed);
+	});
+}
+
+#[test]
+fn failed_dispatch_keeps_nothing() {
+	new_test_ext().execute_with(|| {
+		// STRANGER has nothing to pay with
+		assert_noop!(place_pay(DELEGATED, STRANGER, PASSWORD), Error::<Test>::CallDispatchFailed);
+		assert!(pending(PASSWORD).is_empty());
+	});
+}
+
+#[test]
+fn pending_calls_are_bounded_per_password() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..2 {
+			assert_ok!(place_pay(DELEGATED, PAYER, PASSWORD));
+		}
+
+		assert_noop!(place_pay(DELEGATED, PAYER, PASSWORD), Error::<Test>::TooManyCalls);
+		assert_noop!(place_pay(DELEGATED, PAYER, b"too long pass"), Error::<Test>::PasswordTooLong);
+	});
+}
+
+// Migrations
+
+fn upgrade<M: OnRuntimeUpgrade>() {
+	#[cfg(feature = "try-runtime")]
+	let state = M::pre_upgrade().unwrap();
+	M::on_runtime_upgrade();
+	#[cfg(feature = "try-runtime")]
+	M::post_upgrade(state).unwrap();
+}
+
+fn payment() -> PaymentCalls<Test> {
+	PaymentCalls::VanePay { signer: PAYER, payee: PAYEE, amount: AMOUNT, resolver: None }
+}
+
+// Unbounded v0 queue, encoded like the bounded one
+fn legacy_calls(password: &[u8], calls: Vec<PaymentCalls<Test>>) {
+	unhashed::put(&v1::PaymentCallMemPool::<Test>::hashed_key_for(password.to_vec()), &calls);
+}
+
+#[test]
+fn upgrade_keeps_the_latest_calls_of_each_password() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		let latest = PaymentCalls::ConfirmPay {
+			signer: PAYEE,
+			who: Confirm::Payee,
+			reference: BoundedVec::truncate_from(b"reference".to_vec()),
+		};
+		legacy_calls(PASSWORD, vec![payment(), payment(), latest]);
+
+		upgrade::<MigrateToV1<Test>>();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		let calls = pending(PASSWORD);
+		assert_eq!(calls.len(), 2);
+		assert!(matches!(calls[0], PaymentCalls::VanePay { .. }));
+		assert!(matches!(calls[1], PaymentCalls::ConfirmPay { .. }));
+	});
+}
+
+#[test]
+fn upgrade_drops_passwords_over_the_bound() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		legacy_calls(b"too long pass", vec![payment()]);
+		legacy_calls(PASSWORD, vec![payment()]);
+
+		upgrade::<MigrateToV1<Test>>();
+
+		assert_eq!(PaymentCallMemPool::<Test>::iter_keys().count(), 1);
+		assert_eq!(pending(PASSWORD).len(), 1);
+	});
+}
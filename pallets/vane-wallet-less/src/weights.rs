@@ -0,0 +1,80 @@
+//! Weights for `vane_wallet_less`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+//! `c` is the number of calls already pending under the password.
+//! The dispatched `vane_payment` call is charged on top with its own weight.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_wallet_less`.
+pub trait WeightInfo {
+	fn set_delegated_signer() -> Weight;
+	fn place_vane_pay(c: u32, ) -> Weight;
+	fn place_vane_confirm(c: u32, ) -> Weight;
+	fn place_vane_revert() -> Weight;
+}
+
+/// Hand-written weights for `vane_wallet_less`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VaneWalletLess DelegatedSigner (r:0 w:1)
+	fn set_delegated_signer() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneWalletLess DelegatedSigner (r:1 w:0)
+	/// Storage: VaneWalletLess PaymentCallMemPool (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn place_vane_pay(c: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3_600)
+			.saturating_add(Weight::from_parts(540_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(c.into()))
+	}
+	/// Storage: VaneWalletLess DelegatedSigner (r:1 w:0)
+	/// Storage: VaneWalletLess PaymentCallMemPool (r:1 w:1)
+	/// The range of component `c` is `[0, 64]`.
+	fn place_vane_confirm(c: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 3_700)
+			.saturating_add(Weight::from_parts(560_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 190).saturating_mul(c.into()))
+	}
+	fn place_vane_revert() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_delegated_signer() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn place_vane_pay(c: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 3_600)
+			.saturating_add(Weight::from_parts(540_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 180).saturating_mul(c.into()))
+	}
+	fn place_vane_confirm(c: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 3_700)
+			.saturating_add(Weight::from_parts(560_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 190).saturating_mul(c.into()))
+	}
+	fn place_vane_revert() -> Weight {
+		Weight::from_parts(6_000_000, 0)
+	}
+}
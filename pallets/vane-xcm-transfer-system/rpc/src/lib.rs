@@ -0,0 +1,103 @@
+//! `vane_*` JSON-RPC methods for Vane receipts and escrows.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use vane_xcm_transfer_system_runtime_api::VaneApi as VaneRuntimeApi;
+use vane_xcm_transfer_system_runtime_api::{ConfirmationState, FeeQuote, ReceiptInfo, Token};
+
+#[rpc(client, server)]
+pub trait VaneApi<BlockHash, AccountId> {
+	/// Receipts of payments made by `payer`.
+	#[method(name = "vane_payerReceipts")]
+	fn payer_receipts(&self, payer: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>>;
+
+	/// Receipts of payments made to `payee`.
+	#[method(name = "vane_payeeReceipts")]
+	fn payee_receipts(&self, payee: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>>;
+
+	/// Payments waiting for `account` to confirm.
+	#[method(name = "vane_pendingConfirmations")]
+	fn pending_confirmations(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>>;
+
+	/// Amount still held in escrow for the payment with `reference_no`.
+	#[method(name = "vane_escrowBalance")]
+	fn escrow_balance(&self, reference_no: Bytes, at: Option<BlockHash>) -> RpcResult<Option<u128>>;
+
+	/// Which side confirmed the payment with `reference_no` and its release status.
+	#[method(name = "vane_confirmationState")]
+	fn confirmation_state(&self, reference_no: Bytes, at: Option<BlockHash>) -> RpcResult<Option<ConfirmationState>>;
+
+	/// Release fee of paying `amount` of `currency` to `payee`.
+	#[method(name = "vane_quoteRelease")]
+	fn quote_release(&self, payee: AccountId, currency: Token, amount: u128, at: Option<BlockHash>) -> RpcResult<Option<FeeQuote>>;
+}
+
+/// Provides the `vane_*` RPC methods.
+pub struct Vane<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Vane<C, Block> {
+	/// Creates a new instance of the Vane RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code for runtime API failures.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))).into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId> VaneApiServer<<Block as BlockT>::Hash, AccountId> for Vane<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VaneRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Send + Sync + 'static,
+{
+	fn payer_receipts(&self, payer: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().payer_receipts(at, payer).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn payee_receipts(&self, payee: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().payee_receipts(at, payee).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn pending_confirmations(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<ReceiptInfo<AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().pending_confirmations(at, account).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn escrow_balance(&self, reference_no: Bytes, at: Option<Block::Hash>) -> RpcResult<Option<u128>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().escrow_balance(at, reference_no.to_vec()).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn confirmation_state(&self, reference_no: Bytes, at: Option<Block::Hash>) -> RpcResult<Option<ConfirmationState>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().confirmation_state(at, reference_no.to_vec()).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn quote_release(&self, payee: AccountId, currency: Token, amount: u128, at: Option<Block::Hash>) -> RpcResult<Option<FeeQuote>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().quote_release(at, payee, currency, amount).map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Runtime API for reading Vane receipts and escrows without decoding raw storage
+
+use codec::Codec;
+use sp_std::vec::Vec;
+pub use vane_xcm_transfer_system::helper::{ConfirmationState, FeeQuote, ReceiptInfo, Token};
+
+sp_api::decl_runtime_apis! {
+	pub trait VaneApi<AccountId>
+		where AccountId: Codec
+	{
+		// Receipts of payments made by `payer`
+		fn payer_receipts(payer: AccountId) -> Vec<ReceiptInfo<AccountId>>;
+
+		// Receipts of payments made to `payee`
+		fn payee_receipts(payee: AccountId) -> Vec<ReceiptInfo<AccountId>>;
+
+		// Payments waiting for `account` to confirm
+		fn pending_confirmations(account: AccountId) -> Vec<ReceiptInfo<AccountId>>;
+
+		// Amount still held in escrow for a payment
+		fn escrow_balance(reference_no: Vec<u8>) -> Option<u128>;
+
+		fn confirmation_state(reference_no: Vec<u8>) -> Option<ConfirmationState>;
+
+		// Release fee quote shown before the payer signs
+		fn quote_release(payee: AccountId, currency: Token, amount: u128) -> Option<FeeQuote>;
+	}
+}
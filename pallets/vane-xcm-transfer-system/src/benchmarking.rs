@@ -0,0 +1,220 @@
+// Benchmarks for the Vane XCM escrow
+// `r` is the number of receipts the payee already holds, every call rewrites the payee's receipts
+
+use super::*;
+use crate::helper::{Confirm, RevertReasons, Token, XcmStatus, MAX_BYTES};
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungibles::Inspect;
+use frame_system::RawOrigin;
+use staging_xcm::latest::{Error as XcmError, Junction::{AccountId32, Parachain}, Junctions::X2};
+
+// Covers the release fee quote of every destination
+const AMOUNT: u128 = 1_000_000_000_000_000;
+
+const SEED: u32 = 0;
+
+// Derived DOT issued by the para account, created when the runtime has none at genesis
+fn setup_asset<T: Config>() -> Result<T::AssetIdParameter, BenchmarkError> {
+	let issuer = match ParaAccount::<T>::get() {
+		Some(issuer) => issuer,
+		None => {
+			let issuer: T::AccountId = account("issuer", 0, SEED);
+			ParaAccount::<T>::put(&issuer);
+			issuer
+		}
+	};
+
+	let asset_id: T::AssetIdParameter = CurrencyId::DOT;
+
+	if <pallet_assets::Pallet<T>>::maybe_total_supply(asset_id.into()).is_none() {
+		let origin = <T as pallet_assets::Config>::ForceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		<pallet_assets::Pallet<T>>::force_create(
+			origin,
+			asset_id,
+			T::Lookup::unlookup(issuer),
+			true,
+			1u32.into()
+		)?;
+	}
+
+	Ok(asset_id)
+}
+
+// Payer holding reserve backed DOT worth `AMOUNT`, the min balance stays free when it is held
+fn funded_payer<T: Config>(asset_id: T::AssetIdParameter) -> Result<T::AccountId, BenchmarkError> {
+	let payer: T::AccountId = account("payer", 0, SEED);
+	frame_system::Pallet::<T>::inc_providers(&payer);
+
+	let issuer = ParaAccount::<T>::get().ok_or(BenchmarkError::Stop("para account not set"))?;
+	let min_balance: u128 = <pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(asset_id.into()).saturated_into();
+	let amount = AMOUNT.saturating_add(min_balance);
+
+	<pallet_assets::Pallet<T>>::mint(
+		RawOrigin::Signed(issuer).into(),
+		asset_id,
+		T::Lookup::unlookup(payer.clone()),
+		amount.saturated_into()
+	)?;
+
+	ReserveBalance::<T>::mutate(Token::DOT, |reserve| *reserve = reserve.saturating_add(amount));
+
+	Ok(payer)
+}
+
+// Payee with `r` receipts from other payers
+fn payee_with_receipts<T: Config>(r: u32) -> Result<T::AccountId, BenchmarkError> {
+	let payee: T::AccountId = account("payee", 0, SEED);
+	frame_system::Pallet::<T>::inc_providers(&payee);
+
+	for i in 0..r {
+		let payer: T::AccountId = account("filler", i, SEED);
+		Pallet::<T>::vane_multisig_record(payer, payee.clone(), AMOUNT, Token::DOT)
+			.map_err(|_| BenchmarkError::Stop("failed to record receipt"))?;
+	}
+
+	Ok(payee)
+}
+
+// Escrowed payment waiting for the payee to confirm
+fn open_escrow<T: Config>(r: u32) -> Result<(T::AccountId, T::AccountId, BoundedVec<u8, MAX_BYTES>, T::AssetIdParameter), BenchmarkError> {
+	let asset_id = setup_asset::<T>()?;
+	let payee = payee_with_receipts::<T>(r)?;
+	let payer = funded_payer::<T>(asset_id)?;
+
+	Pallet::<T>::vane_transfer(
+		RawOrigin::Signed(payer.clone()).into(),
+		T::Lookup::unlookup(payee.clone()),
+		AMOUNT,
+		Token::DOT,
+		None
+	)?;
+
+	let reference = PayerTxnReceipt::<T>::iter_key_prefix(&payer)
+		.next()
+		.ok_or(BenchmarkError::Stop("payment not recorded"))?;
+
+	Ok((payer, payee, reference, asset_id))
+}
+
+// Payment confirmed by both sides with the release message sent
+fn released_escrow<T: Config>(r: u32) -> Result<(T::AccountId, T::AccountId, BoundedVec<u8, MAX_BYTES>, QueryId), BenchmarkError> {
+	let (payer, payee, reference, _) = open_escrow::<T>(r)?;
+
+	Pallet::<T>::vane_confirm(RawOrigin::Signed(payee.clone()).into(), Confirm::Payee, reference.to_vec())?;
+	Pallet::<T>::vane_confirm(RawOrigin::Signed(payer.clone()).into(), Confirm::Payer, reference.to_vec())?;
+
+	let query_id = XcmQueries::<T>::iter_keys()
+		.next()
+		.ok_or(BenchmarkError::Stop("release not sent"))?;
+
+	Ok((payer, payee, reference, query_id))
+}
+
+fn failed_response() -> Response {
+	Response::ExecutionResult(Some((1, XcmError::TooExpensive)))
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn vane_transfer(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let asset_id = setup_asset::<T>()?;
+		let payee = payee_with_receipts::<T>(r)?;
+		let payer = funded_payer::<T>(asset_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer), T::Lookup::unlookup(payee.clone()), AMOUNT, Token::DOT, None);
+
+		assert_eq!(PayeeTxnReceipt::<T>::decode_len(&payee), Some(r as usize + 1));
+		Ok(())
+	}
+
+	// The payer's confirmation releases the escrow
+	#[benchmark]
+	fn vane_confirm(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, payee, reference, _) = open_escrow::<T>(r)?;
+		Pallet::<T>::vane_confirm(RawOrigin::Signed(payee).into(), Confirm::Payee, reference.to_vec())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), Confirm::Payer, reference.to_vec());
+
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Sent);
+		Ok(())
+	}
+
+	// A failed release restores the escrow
+	#[benchmark]
+	fn xcm_response(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (_, _, reference, query_id) = released_escrow::<T>(r)?;
+		let origin = T::ResponseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			Pallet::<T>::xcm_response(origin, query_id, failed_response())?;
+		}
+
+		assert!(FailedReleases::<T>::contains_key(&reference));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_failed_release(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (_, payee, reference, query_id) = released_escrow::<T>(r)?;
+		let origin = T::ResponseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Pallet::<T>::xcm_response(origin, query_id, failed_response())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payee), reference.to_vec(), None);
+
+		assert!(!FailedReleases::<T>::contains_key(&reference));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vane_revert(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, _, reference, _) = open_escrow::<T>(r)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer.clone()), reference.to_vec(), RevertReasons::ChangeOfDecision);
+
+		assert_eq!(RevertedTxnPayer::<T>::get(&payer), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_release_destination() {
+		let payee: T::AccountId = whitelisted_caller();
+		let destination = VersionedMultiLocation::V3(
+			MultiLocation::new(1, X2(Parachain(2000), AccountId32 { network: None, id: [0u8; 32] }))
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payee.clone()), Some(Box::new(destination)));
+
+		assert!(ReleaseDestination::<T>::contains_key(&payee));
+	}
+
+	// Single entry of the expiry queue processed in `on_idle`
+	#[benchmark]
+	fn expire_escrow(r: Linear<0, { T::MaxPayeeReceipts::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let (payer, _, reference, asset_id) = open_escrow::<T>(r)?;
+		let expiry = EscrowExpiry::<T>::get(&reference).ok_or(BenchmarkError::Stop("expiry not scheduled"))?;
+		frame_system::Pallet::<T>::set_block_number(expiry);
+
+		#[block]
+		{
+			Pallet::<T>::expire_escrow(reference.clone(), asset_id)?;
+		}
+
+		let receipt = PayerTxnReceipt::<T>::get(&payer, &reference).ok_or(BenchmarkError::Stop("receipt removed"))?;
+		assert_eq!(receipt.xcm_status, XcmStatus::Expired);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
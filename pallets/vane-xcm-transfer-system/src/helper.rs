@@ -8,11 +8,12 @@ pub use utils::*;
 use pallet_assets;
 
 pub mod utils {
-	use core::ops::Add;
 	use frame_support::parameter_types;
-	use sp_std::ops::{Mul, Sub};
-	use frame_system::{AccountInfo, RawOrigin};
-	use sp_runtime::traits::{TrailingZeroInput};
+	use frame_system::RawOrigin;
+	use frame_support::traits::fungibles::MutateHold;
+	use frame_support::traits::tokens::{Fortitude, Precision};
+	use sp_runtime::traits::{One, Zero};
+	use sp_runtime::SaturatedConversion;
     use staging_xcm::{
         v3::{
             Xcm, WeightLimit,
@@ -20,140 +21,81 @@ pub mod utils {
 
     };
     use sp_std::{vec::Vec,vec};
-	use staging_xcm::latest::Parent;
-	use staging_xcm::prelude::{AccountId32, All, BuyExecution, DepositAsset, Here, WithdrawAsset};
-	use sp_io::hashing::blake2_256;
+	use staging_xcm::latest::{MultiAsset, MultiLocation, Parent, QueryId, QueryResponseInfo, Response};
+	use staging_xcm::prelude::{AccountId32, AccountKey20, All, BuyExecution, ClaimAsset, ClearOrigin, DepositAsset, DepositReserveAsset, GeneralIndex, Here, Instruction, Junction, PalletInstance, Parachain, RefundSurplus, ReportError, ReserveAssetDeposited, SetAppendix, WithdrawAsset, X1, X2, X3};
+	use staging_xcm_executor::traits::WeightBounds;
+	use frame_support::weights::WeightToFee;
+	use frame_support::dispatch::GetDispatchInfo;
+	use vane_primitive::{CurrencyId, ExistentialDeposits, GetByKey};
+	use sp_runtime::{FixedPointNumber, FixedU128};
+	use crate::weights::WeightInfo;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 
 
 	use super::*;
 
 
 
-	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	#[scale_info(skip_type_params(T))]
-	pub struct AccountSigners<T: Config> {
-		payee: T::AccountId,
-		payer: T::AccountId,
-	}
-	impl<T> AccountSigners<T>
-		where
-			T: Config,
-	{
-		pub fn new(
-			payee: T::AccountId,
-			payer: T::AccountId,
-		) -> Self {
-			AccountSigners { payee, payer }
-		}
-		pub(super) fn get_accounts(&self) -> (&T::AccountId, &T::AccountId) {
-			(&self.payer,&self.payee)
-		}
+	// Types and rules shared with the other Vane payment pallets
+	pub use vane_primitive::escrow::{
+		Confirm, ConfirmationState, ReceiptInfo, ReferenceNo, RevertReasons, Token, XcmStatus,
+		MAX_BYTES, MAX_NO_TXNS, MAX_REFERENCE_ATTEMPTS,
+	};
+	use vane_primitive::escrow::{
+		self, ConfirmError, ConfirmOutcome, OnConfirmed, OnEscrowOpened, OnReleased, OnReverted,
+	};
 
-		// refer here https://doc.rust-lang.org/stable/book/ch06-01-defining-an-enum.html?highlight=enum#enum-values
+	pub type AccountSigners<T> = escrow::AccountSigners<<T as frame_system::Config>::AccountId>;
+	pub type TxnReceipt<T> = escrow::TxnReceipt<<T as frame_system::Config>::AccountId>;
+	pub type CallExecuted<T> =
+		escrow::CallExecuted<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Hash, BlockNumberFor<T>>;
 
+	// AssetHub reserve details for USDT and USDC
+	// Vane sovereign account on AssetHub holds the reserve backing the derived vUSDT and vUSDC
+	parameter_types! {
+		pub const ASSET_HUB_PARA_ID: u32 = 1000;
+		pub const ASSET_HUB_ASSETS_PALLET: u8 = 50;
+		pub const USDT_ASSET_INDEX: u128 = 1984;
+		pub const USDC_ASSET_INDEX: u128 = 1337;
 	}
 
-	#[derive(Encode, Decode, Clone, PartialEq, Eq,MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub enum Token {
-		DOT,
-		USDT
-	}
-
-	#[derive(Encode, Decode, Clone, PartialEq, Eq,MaxEncodedLen, RuntimeDebug, TypeInfo)]
-	pub enum XcmStatus {
-		Sent,
-		Completed,
-		Tbc
+	// Execution fee of a release message on the destination
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct FeeQuote {
+		pub weight: Weight,
+		pub fee: u128,
+		// Part of the fee forwarded for execution on the beneficiary's chain, zero when paid on the reserve
+		pub destination_fee: u128,
+		// Minimum received by the beneficiary, unused fee is refunded on top
+		pub receivable: u128,
 	}
 
-	// Confirmation enum which will be used to confirm the account_ids before dispatching multi-sig
-	// Call
+	// Release message waiting for its outcome, kept to restore the escrow if it fails
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	pub enum Confirm {
-		Payer,
-		Payee,
-	}
-
-	parameter_types! {
-		pub const MAX_BYTES: u8 = 50;
-		pub const MAX_NO_TXNS: u8 = 20;
-	}
-
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug,MaxEncodedLen, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
-
-	pub struct TxnReceipt<T: Config> {
-		payee: T::AccountId,
-		payer: T::AccountId,
+	pub struct XcmRelease<T: Config> {
+		pub payer: T::AccountId,
+		pub payee: T::AccountId,
+		pub beneficiary: T::AccountId,
 		pub multi_id: T::AccountId,
-		pub amount: u128,
 		pub reference_no: BoundedVec<u8,MAX_BYTES>,
-		currency: Option<Token>,
-		no_txn: BoundedVec<u128, MAX_NO_TXNS>,
-		pub xcm_status: XcmStatus
-	}
-
-	impl<T: Config> TxnReceipt<T> {
-		pub fn new(
-			payee: T::AccountId,
-			payer: T::AccountId,
-			multi_id: T::AccountId,
-			ref_no: BoundedVec<u8,MAX_BYTES>,
-			amount: u128,
-			txn: u128,
-			currency:Option<Token>
-		) -> Self {
-
-			let mut no_txn = BoundedVec::new();
-			no_txn.to_vec().push(txn);
-
-			Self {
-				payee, payer, reference_no: ref_no,
-				amount,currency, no_txn,
-				xcm_status: XcmStatus::Tbc, multi_id
-			}
-		}
-
-		pub fn update_txn(&mut self, txn: u128){
-			self.no_txn.try_push(txn).unwrap() // Put error handling
-		}
-
-		pub fn update_amount(&mut self, amount: u128){
-			self.amount += amount
-		}
-	}
-
-
-	// Call executed struct information
-	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	#[scale_info(skip_type_params(T))]
-	pub struct CallExecuted<T: Config> {
-		payer: T::AccountId,
-		payee: T::AccountId,
-		allowed_multi_id: T::AccountId,
-		confirmed_multi_id: T::AccountId,
-		proof: T::Hash,
-		time: BlockNumberFor<T>,
-	}
-
-	impl<T> CallExecuted<T>
-		where
-			T: Config,
-	{
-		pub(super) fn new(
-			payer: T::AccountId,
-			payee: T::AccountId,
-			allowed_multi_id: T::AccountId,
-			confirmed_multi_id: T::AccountId,
-			proof: T::Hash,
-			time: BlockNumberFor<T>,
-		) -> Self {
-			CallExecuted { payer, payee, allowed_multi_id, confirmed_multi_id, proof, time }
-		}
+		pub currency: Token,
+		pub asset_id: T::AssetIdParameter,
+		// Derived asset burnt from the payer's hold
+		pub amount: u128,
+		// Reserve asset carried by the message
+		pub withdrawn: u128,
+		pub destination: MultiLocation,
+		// Reserve asset as seen from the destination
+		pub asset: MultiLocation,
+		// Beneficiary location chosen by the payee as seen from Vane,
+		// `None` deposits to the beneficiary's account on the reserve chain
+		pub route: Option<MultiLocation>,
 	}
 
 
-
 	impl<T: Config> Pallet<T>{
 
 
@@ -177,109 +119,45 @@ pub mod utils {
             payee: T::AccountId,
             amount: u128,
             currency: Token
-        ) -> Result<T::AccountId,Error<T>>{
+        ) -> Result<(T::AccountId, BoundedVec<u8,MAX_BYTES>),Error<T>>{
 
-			// ****** CRUCIAL ******
-			// Check the balance receipt in Vane Soverign Account before proceeding
+			// Reserve backing is checked when the derived asset is escrowed, see `escrow_derived_asset`
 
 
-			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone());
+			let accounts = AccountSigners::<T>::new(payee.clone(), payer.clone(), None);
 			let multi_id = Self::derive_multi_id(accounts.clone());
 
 
-			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone());
-
-			AllowedSigners::<T>::insert(&payer, ref_no.to_vec(), accounts);
+			// Every payment gets its own reference and receipt, even between the same pair
+			let ref_no = Self::derive_reference_no(payer.clone(), payee.clone(), multi_id.clone())?;
 
-			//if the multi_id is the same as previous Receipt just increment the total amount and add the txn_no amount
+			AllowedSigners::<T>::insert(&payer, &ref_no, accounts);
 
             let receipt =
 				TxnReceipt::<T>::new(payee.clone(), payer.clone(),multi_id.clone(), ref_no.clone(), amount.clone(),(amount),Some(currency));
 
 			// Store to each storage item for txntickets
 			// Useful for getting reference no for TXN confirmation
-			// Start with the payee storage
-
-			PayeeTxnReceipt::<T>::mutate(&payee, |p_vec|{
-				// Check if the multi_id already exists in the receipts and get its index of the receipt
-				let index = p_vec.iter().position(|receipt| receipt.multi_id == multi_id);
-				if let Some(idx) = index {
-					// Get the receipt
-					let mut receipt = p_vec.get_mut(idx).ok_or(Error::<T>::UnexpectedError).unwrap();
-					receipt.update_txn(amount);
-					receipt.update_amount(amount)
-
-				}else{
-					p_vec.push(receipt.clone())
-				}
-			});
+			// Call weights are charged for a payee holding the maximum number of receipts
+			PayeeTxnReceipt::<T>::try_mutate(&payee, |p_vec| Self::push_payee_receipt(p_vec, receipt.clone()))?;
 
-			// Update for Payer/Sender Txn receipt
-			let existing_payer_receipt = PayerTxnReceipt::<T>::get(&payer,&payee);
+			PayerTxnReceipt::<T>::insert(&payer, &ref_no, receipt);
 
-			if let Some(mut receipt) = existing_payer_receipt {
+			ReceiptIndex::<T>::insert(&ref_no, (payer.clone(), payee.clone()));
 
-				// vane_payment::PayerTxnReceipt::<T>::mutate(&payer, &payee, |receipt_inner|{
-				// 	receipt_inner.clone().unwrap().update_txn(amount)
-				// });
-				receipt.update_txn(amount);
-				receipt.update_amount(amount);
-
-			}else{
-
-				PayerTxnReceipt::<T>::insert(&payer,&payee,receipt);
-			}
-
-
-			Self::create_multi_account(multi_id.clone()).map_err(|_| Error::<T>::UnexpectedError)?;
-
-			let time = <frame_system::Pallet<T>>::block_number();
-
-			Self::deposit_event(Event::MultisigAccountCreated {
-				id: multi_id.clone(),
-				time,
-			});
-
-            Ok(multi_id)
+            Ok((multi_id, ref_no))
         }
 
 
         pub fn vane_xcm_transfer_dot(
+			payer: T::AccountId,
             amount: u128,
             multi_id: AccountIdLookupOf<T>, // Multi Id Account
-			multi_id_acc: T::AccountId, // Just for types difference usage but this and multi_id are sme value
 			asset_id: T::AssetIdParameter
 		) -> DispatchResult {
 
-
-			// Deposit Amount to MultiSig Account
-			let issuer = ParaAccount::<T>::get().unwrap();
-
-			let balance: <T as pallet_assets::Config>::Balance = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)? ;
-
-			// Check if the multi_id account does contain the tokens
-			let to_check_balance:<T as pallet_assets::Config>::Balance = 0u128.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
-
-			if <pallet_assets::Pallet<T>>::balance(asset_id.into(),multi_id_acc.clone()) != to_check_balance {
-
-				let current_balance = <pallet_assets::Pallet<T>>::balance(asset_id.into(),multi_id_acc);
-				let to_mint = current_balance.add(balance.try_into().expect("Failed to add balances in pallet  vane_xcm"));
-
-				<pallet_assets::Pallet<T>>::mint(
-					RawOrigin::Signed(issuer).into(),
-					asset_id,
-					multi_id.clone(),
-					to_mint // handle this error
-				)?;
-
-			}else{
-				<pallet_assets::Pallet<T>>::mint(
-					RawOrigin::Signed(issuer).into(),
-					asset_id,
-					multi_id.clone(),
-					balance // handle this error
-				)?;
-			}
+			// Hold the payer's reserve backed vDOT
+			Self::escrow_derived_asset(payer, amount, asset_id)?;
 
 			let time = <frame_system::Pallet<T>>::block_number();
 			// Event
@@ -297,109 +175,982 @@ pub mod utils {
         pub fn vane_xcm_confirm_transfer_dot(
 			payer: T::AccountId,
             payee: T::AccountId,
-			multi_id: AccountIdLookupOf<T>,
+			reference_no: BoundedVec<u8,MAX_BYTES>,
             amount: u128,
 			asset_id: T::AssetIdParameter
 
         ) -> DispatchResult{
 
-			let issuer = ParaAccount::<T>::get().unwrap();
+			// Burn the asset held on the payer
+			Self::burn_escrowed(&payer, amount, asset_id)?;
 
-			// Burn the asset in the multi_id account
-			let amount_type: <T as pallet_assets::Config>::Balance = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)? ;
+			// The reserve leaves the sovereign account together with the message below
+			Self::release_reserve(Token::DOT, amount, asset_id)?;
 
-			<pallet_assets::Pallet<T>>::burn(
-				RawOrigin::Signed(issuer).into(),
+			// Send XCM instruction to send funds from Parachain sovereign account to payee acount
+			// The relay execution fee is quoted and taken from the amount when the message is built
+
+			let receipt = PayerTxnReceipt::<T>::get(&payer,&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			let (destination, asset) = Self::reserve_location(&Token::DOT);
+
+			let route = EscrowRoute::<T>::take(&reference_no);
+			let release = XcmRelease::<T> {
+				payer,
+				beneficiary: payee.clone(),
+				payee,
+				multi_id: receipt.multi_id,
+				reference_no: receipt.reference_no,
+				currency: Token::DOT,
 				asset_id,
-				multi_id.clone(),
-				amount_type
-			)?;
+				amount,
+				withdrawn: amount,
+				destination,
+				asset,
+				route,
+			};
 
-			// Change the status in the payer receipt
+			Self::send_release(release, false)?;
 
+            // Event
+			Self::deposit_event(
+				Event::MessageTransferedToPolkadot
+			);
 
-			// Send XCM instruction to send funds from Parachain sovereign account to payee acount
-			//let payee_id:[u8;32] = payee.encode().try_into().unwrap();
+            Ok(())
+        }
 
-			// Take 1 amount
 
-			let new_amount = amount.sub(10_000_000_000);
+        // USDT and USDC, both reserved on AssetHub
+        pub fn vane_xcm_transfer_assethub(
+			payer: T::AccountId,
+			currency: Token,
+            amount: u128,
+            multi_id: AccountIdLookupOf<T>, // Multi Id Account
+			asset_id: T::AssetIdParameter
+		) -> DispatchResult {
 
-			let message = Xcm::<()>(vec![
-				// Transfer equivalent funds from Sovereign Account to payee account
-				// TransferAsset {
-				// 	assets: (Here, amount).into(), // We must have a function to calculate fees
-				// 	beneficiary: (AccountId32 {network: None, id: payee.encode().try_into().unwrap()}).into(),
-				// }
-				WithdrawAsset((Here,new_amount).into()),
-				BuyExecution { fees: (Here,new_amount).into(), weight_limit: WeightLimit::Unlimited },
-				DepositAsset { assets: All.into(), beneficiary:  (AccountId32 {network: None, id: payee.encode().try_into().unwrap()}).into() }
-			]);
+			// Hold the payer's reserve backed vUSDT or vUSDC
+			Self::escrow_derived_asset(payer, amount, asset_id)?;
 
-			<pallet_xcm::Pallet<T>>::send_xcm(Here,Parent,message).map_err(|_| Error::<T>::ErrorSendingXcm)?;
+			let time = <frame_system::Pallet<T>>::block_number();
+			// Event
+			match currency {
+				Token::USDC => Self::deposit_event(Event::UsdcXcmTransferInitiated { time, amount, multi_id }),
+				_ => Self::deposit_event(Event::UsdtXcmTransferInitiated { time, amount, multi_id }),
+			}
 
-			// Change the status in the payee receipt
+            Ok(())
+        }
 
+        pub fn vane_xcm_confirm_transfer_assethub(
+			payer: T::AccountId,
+            payee: T::AccountId,
+			reference_no: BoundedVec<u8,MAX_BYTES>,
+			currency: Token,
+            amount: u128,
+			asset_id: T::AssetIdParameter
+		) -> DispatchResult{
+
+			// Burn the derived asset held on the payer
+			Self::burn_escrowed(&payer, amount, asset_id)?;
+
+			Self::release_reserve(currency.clone(), amount, asset_id)?;
+
+			let receipt = PayerTxnReceipt::<T>::get(&payer,&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			let (destination, asset) = Self::reserve_location(&currency);
+
+			// AssetHub is the reserve, the message withdraws from Vane sovereign account on AssetHub,
+			// pays the quoted fee in the released asset and deposits whatever remains to the payee
+			let route = EscrowRoute::<T>::take(&reference_no);
+			let release = XcmRelease::<T> {
+				payer,
+				beneficiary: payee.clone(),
+				payee,
+				multi_id: receipt.multi_id,
+				reference_no: receipt.reference_no,
+				currency,
+				asset_id,
+				amount,
+				withdrawn: amount,
+				destination,
+				asset,
+				route,
+			};
 
-            // Event
+			Self::send_release(release, false)?;
+
+			// Event
 			Self::deposit_event(
-				Event::MessageTransferedToPolkadot
+				Event::MessageTransferedToAssetHub
 			);
 
-            Ok(())
+			Ok(())
         }
 
+		// Refund of an unconfirmed escrow, the reserve asset is sent back to the payer's account on the reserve chain
+		pub fn vane_xcm_revert(
+			payer: T::AccountId,
+			reference_no: BoundedVec<u8,MAX_BYTES>
+		) -> Result<(QueryId, u128), DispatchError> {
 
-        pub fn vane_xcm_transfer_assethub_dot() -> DispatchResult{
-            Ok(())
-        }
+			let receipt = PayerTxnReceipt::<T>::get(&payer,&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
 
-        pub fn vane_xcm_confirm_transfer_assethub_dot() -> DispatchResult{
+			// Once the release message left the escrow can no longer be reverted
+			ensure!(receipt.xcm_status == XcmStatus::Tbc, Error::<T>::AlreadyReleased);
 
-            Ok(())
-        }
+			let currency = receipt.currency().ok_or(Error::<T>::NotSupportedYet)?;
+			let asset_id = Self::currency_asset_id(&currency);
+			let amount = receipt.amount;
 
+			Self::burn_escrowed(&payer, amount, asset_id)?;
+			Self::release_reserve(currency.clone(), amount, asset_id)?;
 
-		// Util functions
+			let (destination, asset) = Self::reserve_location(&currency);
+			EscrowRoute::<T>::remove(&reference_no);
 
-		pub fn derive_reference_no(
+			let release = XcmRelease::<T> {
+				payer: payer.clone(),
+				payee: receipt.payee,
+				beneficiary: payer,
+				multi_id: receipt.multi_id,
+				reference_no: receipt.reference_no,
+				currency,
+				asset_id,
+				amount,
+				withdrawn: amount,
+				destination,
+				asset,
+				route: None,
+			};
+
+			let query_id = Self::send_release(release, false)?;
+
+			Ok((query_id, amount))
+		}
+
+		// Escrow lifecycle, driven by the extrinsics and the `VaneEscrow` implementation
+
+		pub fn open_escrow(
 			payer: T::AccountId,
 			payee: T::AccountId,
-			multi_id: T::AccountId,
-		) -> BoundedVec<u8,MAX_BYTES> {
-			let mut buffer = Vec::new();
-			buffer.append(&mut payer.using_encoded(blake2_256).to_vec());
-			buffer.append(&mut payee.using_encoded(blake2_256).to_vec());
-			buffer.append(&mut multi_id.using_encoded(blake2_256).to_vec());
+			currency: Token,
+			amount: u128,
+			confirmation_window: Option<BlockNumberFor<T>>
+		) -> Result<ReferenceNo, DispatchError> {
+
+			// The escrowed asset always matches the currency the payment is recorded in
+			let asset_id = Self::currency_asset_id(&currency);
+
+			// Reject payments which cannot cover the release fee, or whose destination the reserve cannot reach
+			Self::quote_payment(&payee, currency.clone(), amount)?;
+
+			let (multi_id, reference_no) = Self::vane_multisig_record(payer.clone(), payee.clone(), amount, currency.clone())?;
+
+			// The release goes where it was quoted, whatever the payee sets later
+			if let Some(route) = ReleaseDestination::<T>::get(&payee) {
+				EscrowRoute::<T>::insert(&reference_no, route);
+			}
+			let multi_id_acc = T::Lookup::unlookup(multi_id);
+
+			match currency {
+				Token::DOT => Self::vane_xcm_transfer_dot(payer.clone(), amount, multi_id_acc, asset_id)?,
+				Token::USDT | Token::USDC => {
+					Self::vane_xcm_transfer_assethub(payer.clone(), currency.clone(), amount, multi_id_acc, asset_id)?
+				},
+			};
+
+			// Refund the payer if the payee does not confirm in time
+			Self::schedule_expiry(reference_no.clone(), asset_id, confirmation_window)?;
+
+			T::OnEscrowOpened::on_escrow_opened(&payer, &payee, &reference_no, amount);
 
-			let reference = blake2_256(&buffer[..]);
-			return reference[20..26].to_vec().try_into().unwrap(); // Proper error handling should be done
+			Ok(reference_no)
 		}
 
+		// The payment is released as soon as the payer confirms after the payee
+		pub fn confirm_escrow(
+			who: T::AccountId,
+			role: Confirm,
+			reference_no: ReferenceNo
+		) -> Result<ConfirmOutcome<T::AccountId>, DispatchError> {
+
+			let (payer, _) = ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			let allowed_signers = AllowedSigners::<T>::get(&payer, &reference_no)
+				.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
+
+			let outcome = ConfirmedSigners::<T>::try_mutate(&reference_no, |signers| {
+				escrow::confirm(signers, &allowed_signers, who.clone(), role.clone())
+			}).map_err(|error| match error {
+				ConfirmError::PayeeAlreadyConfirmed => Error::<T>::PayeeAlreadyConfirmed,
+				ConfirmError::WaitForPayeeToConfirm => Error::<T>::WaitForPayeeToConfirm,
+				ConfirmError::ExceededSigners => Error::<T>::ExceededSigners,
+				ConfirmError::NotThePayee => Error::<T>::NotThePayee,
+				ConfirmError::NotThePayer => Error::<T>::NotThePayer,
+			})?;
+
+			T::OnConfirmed::on_confirmed(&who, &role, &reference_no);
 
-		pub  fn derive_multi_id(account_object: AccountSigners<T>) -> T::AccountId {
+			let time = <frame_system::Pallet<T>>::block_number();
+
+			match outcome {
+				ConfirmOutcome::PayeeConfirmed => {
+					Self::deposit_event(Event::PayeeAddressConfirmedXcm {
+						account_id: who,
+						timestamp: time,
+						reference_no: reference_no.to_vec(),
+					});
+				},
+				ConfirmOutcome::Confirmed { .. } => {
+					Self::deposit_event(Event::PayerAddressConfirmedXcm {
+						account_id: who,
+						timestamp: time,
+						reference_no: reference_no.to_vec(),
+					});
+
+					Self::release_escrow(reference_no)?;
+				},
+			};
+
+			Ok(outcome)
+		}
+
+		// Pays the escrow out once the confirmed signers match the ones allowed when the payment was opened
+		pub fn release_escrow(reference_no: ReferenceNo) -> DispatchResult {
+
+			let signers = ConfirmedSigners::<T>::get(&reference_no);
+			let (Some(payee), Some(payer)) = (signers.get(0).cloned(), signers.get(1).cloned()) else {
+				return Err(Error::<T>::WaitForPayerToConfirm.into());
+			};
 
-			let (acc1, acc2) = account_object.get_accounts();
+			let allowed_signers = AllowedSigners::<T>::get(&payer, &reference_no)
+				.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
 
-			let entropy =  (b"vane/salt", acc1, acc2).using_encoded(blake2_256);
+			let confirmed_multi_id = Self::derive_multi_id(AccountSigners::<T>::new(payee.clone(), payer.clone(), None));
+			ensure!(confirmed_multi_id == Self::derive_multi_id(allowed_signers), Error::<T>::FailedToMatchAccounts);
 
-			let multi_account = Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-				.expect("infinite length input; no invalid inputs for type; qed");
+			let receipt = PayerTxnReceipt::<T>::get(&payer, &reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			let asset_id = Self::escrowed_asset_id(&reference_no)?;
 
-			multi_account
+			// Release path depends on the currency the payer escrowed
+			match receipt.currency() {
+				Some(Token::DOT) => {
+					Self::vane_xcm_confirm_transfer_dot(payer.clone(), payee.clone(), reference_no.clone(), receipt.amount, asset_id)?
+				},
+				Some(currency @ (Token::USDT | Token::USDC)) => {
+					Self::vane_xcm_confirm_transfer_assethub(payer.clone(), payee.clone(), reference_no.clone(), currency, receipt.amount, asset_id)?
+				},
+				None => Err(Error::<T>::NotSupportedYet)?
+			}
+
+			T::OnReleased::on_released(&payer, &payee, &reference_no, receipt.amount);
+
+			Ok(())
 		}
 
-		pub fn create_multi_account(multi_id: T::AccountId) -> DispatchResult {
-			let account_info = AccountInfo::<T::Nonce, T::AccountData> { ..Default::default() };
+		// Refunds the payer on behalf of `who`, the payer or the payee.
+		// Reverts caused by the payer count against the payer. Misbehaviour only counts against a payee
+		// who gives the payment back, a payer's claim of it is refunded without blaming anyone
+		pub fn revert_escrow(
+			who: T::AccountId,
+			reference_no: ReferenceNo,
+			reason: RevertReasons
+		) -> DispatchResult {
 
-			// Ensure the multi_id account is not yet registered in the storage
-			if <frame_system::Pallet<T>>::account_exists(&multi_id) {
-				return Ok(());
+			let (payer, payee) = ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			ensure!(who == payer || who == payee, Error::<T>::NotThePayerOrPayee);
+			let by_payee = who == payee;
+
+			let (query_id, amount) = Self::vane_xcm_revert(payer.clone(), reference_no.clone())?;
+
+			// The payee can no longer confirm this payment
+			AllowedSigners::<T>::remove(&payer, &reference_no);
+			ConfirmedSigners::<T>::remove(&reference_no);
+
+			match reason {
+				RevertReasons::WrongPayeeAddress | RevertReasons::ChangeOfDecision if !by_payee => {
+					RevertedTxnPayer::<T>::mutate(&payer, |count| *count = count.saturating_add(1));
+				},
+				RevertReasons::PayeeMisbehaviour if by_payee => {
+					RevertedTxnPayee::<T>::mutate(&payee, |count| *count = count.saturating_add(1));
+				},
+				_ => (),
+			}
+
+			T::OnReverted::on_reverted(&payer, &payee, &reference_no, amount, Some(reason.clone()));
+
+			Self::deposit_event(Event::FundsReverted {
+				reference_no: reference_no.to_vec(),
+				payer,
+				reason,
+				amount,
+				query_id,
+			});
+
+			Ok(())
+		}
+
+		// Reserve chain of a token and the token as seen from it
+		pub fn reserve_location(currency: &Token) -> (MultiLocation, MultiLocation) {
+			match currency {
+				Token::DOT => (Parent.into(), Here.into()),
+				Token::USDT => (
+					MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID::get()))),
+					MultiLocation::new(
+						0,
+						X2(PalletInstance(ASSET_HUB_ASSETS_PALLET::get()), GeneralIndex(USDT_ASSET_INDEX::get()))
+					),
+				),
+				Token::USDC => (
+					MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID::get()))),
+					MultiLocation::new(
+						0,
+						X2(PalletInstance(ASSET_HUB_ASSETS_PALLET::get()), GeneralIndex(USDC_ASSET_INDEX::get()))
+					),
+				),
+			}
+		}
+
+		// Builds and sends the release message to the reserve chain.
+		// `claim` picks up assets trapped by a previous failed release instead of withdrawing
+		pub fn send_release(release: XcmRelease<T>, claim: bool) -> Result<QueryId, DispatchError> {
+
+			let (chain, account) = Self::release_route(&release)?;
+			let remote = chain != MultiLocation::here();
+
+			let quote = Self::quote_release(release.currency.clone(), release.withdrawn, remote)?;
+
+			let assets: MultiAsset = (release.asset, release.withdrawn).into();
+			let fees: MultiAsset = (release.asset, quote.fee.saturating_sub(quote.destination_fee)).into();
+			let beneficiary = MultiLocation::new(0, X1(account));
+
+			let load = if claim {
+				ClaimAsset { assets: assets.into(), ticket: Here.into() }
+			} else {
+				WithdrawAsset(assets.into())
+			};
+
+			// Beneficiaries on another chain get the asset through a reserve transfer from the reserve chain,
+			// only the reserve leg reports back its outcome
+			let deposit = if remote {
+				let remote_fees: MultiAsset = (Self::parachain_asset_location(&release.currency), quote.destination_fee).into();
+				DepositReserveAsset { assets: All.into(), dest: chain, xcm: Self::remote_deposit(remote_fees, beneficiary) }
+			} else {
+				DepositAsset { assets: All.into(), beneficiary }
+			};
+
+			let mut message = Self::release_message(load, fees, quote.weight, deposit);
+
+			Self::deposit_event(Event::ReleaseFeeQuoted {
+				reference_no: release.reference_no.to_vec(),
+				weight: quote.weight,
+				fee: quote.fee,
+			});
+
+			let destination = release.destination;
+
+			// Ask the destination to report back the execution outcome
+			let query_id = Self::register_xcm_query(&mut message, release)?;
+
+			<pallet_xcm::Pallet<T>>::send_xcm(Here,destination,message).map_err(|_| Error::<T>::ErrorSendingXcm)?;
+
+			// Change the status in the payer and payee receipts
+			Self::mark_xcm_sent(query_id)?;
+
+			Ok(query_id)
+		}
+
+		// Release message without its `ReportError` appendix. Only the quoted fee is handed to the trader,
+		// the remainder and any unused weight refunded by `RefundSurplus` are deposited to the beneficiary
+		pub fn release_message(
+			load: Instruction<()>,
+			fees: MultiAsset,
+			weight: Weight,
+			deposit: Instruction<()>
+		) -> Xcm<()> {
+			Xcm(vec![
+				load,
+				BuyExecution { fees, weight_limit: WeightLimit::Limited(weight) },
+				RefundSurplus,
+				deposit
+			])
+		}
+
+		// Deposit executed on the beneficiary's chain by a release with a remote beneficiary
+		pub fn remote_deposit(fees: MultiAsset, beneficiary: MultiLocation) -> Xcm<()> {
+			Xcm(vec![
+				BuyExecution { fees, weight_limit: WeightLimit::Unlimited },
+				DepositAsset { assets: All.into(), beneficiary },
+			])
+		}
+
+		// Paid execution barriers expect the message to start by loading assets and buying
+		// execution, so the appendix goes right after `BuyExecution`
+		pub fn append_report(message: &mut Xcm<()>, response_info: QueryResponseInfo) {
+			let index = message.0.iter()
+				.position(|instruction| matches!(instruction, BuyExecution { .. }))
+				.map_or(0, |idx| idx + 1);
+			message.0.insert(index, SetAppendix(Xcm(vec![ReportError(response_info)])));
+		}
+
+		// Splits the beneficiary into its chain and account, both as seen from the reserve chain
+		pub fn release_route(release: &XcmRelease<T>) -> Result<(MultiLocation, Junction), DispatchError> {
+			let Some(route) = release.route else {
+				let id: [u8;32] = release.beneficiary.encode().try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+				return Ok((Here.into(), AccountId32 { network: None, id }));
+			};
+
+			Self::route_from(&release.destination, route)
+		}
+
+		// Chain and account of `route` as seen from the reserve chain of `currency`
+		pub fn reserve_route(currency: &Token, route: MultiLocation) -> Result<(MultiLocation, Junction), DispatchError> {
+			let (reserve, _) = Self::reserve_location(currency);
+			Self::route_from(&reserve, route)
+		}
+
+		pub fn route_from(reserve: &MultiLocation, route: MultiLocation) -> Result<(MultiLocation, Junction), DispatchError> {
+			let route = route
+				.reanchored(reserve, <T as pallet_xcm::Config>::UniversalLocation::get())
+				.map_err(|_| Error::<T>::InvalidReleaseDestination)?;
+
+			let (chain, account) = route.split_last_interior();
+			let account = account.ok_or(Error::<T>::InvalidReleaseDestination)?;
+
+			// Only parachains can receive a reserve transfer of the released asset,
+			// so a relay chain account is only reachable when the relay chain is the reserve
+			if chain != MultiLocation::here() {
+				ensure!(matches!(chain.last(), Some(Parachain(_))), Error::<T>::InvalidReleaseDestination);
+			}
+
+			Ok((chain, account))
+		}
+
+		// Reserve asset as seen from any parachain
+		pub fn parachain_asset_location(currency: &Token) -> MultiLocation {
+			match currency {
+				Token::DOT => Parent.into(),
+				Token::USDT => MultiLocation::new(
+					1,
+					X3(Parachain(ASSET_HUB_PARA_ID::get()), PalletInstance(ASSET_HUB_ASSETS_PALLET::get()), GeneralIndex(USDT_ASSET_INDEX::get()))
+				),
+				Token::USDC => MultiLocation::new(
+					1,
+					X3(Parachain(ASSET_HUB_PARA_ID::get()), PalletInstance(ASSET_HUB_ASSETS_PALLET::get()), GeneralIndex(USDC_ASSET_INDEX::get()))
+				),
+			}
+		}
+
+		// Accepts an account on the relay chain or on a parachain, either `AccountId32` or `AccountKey20`.
+		// Whether the reserve of a payment can reach it is checked when the payment is opened
+		pub fn validate_release_destination(destination: &MultiLocation) -> DispatchResult {
+			let valid = match (destination.parents, destination.interior) {
+				(1, X1(AccountId32 { .. })) => true,
+				(1, X2(Parachain(_), AccountId32 { .. })) | (1, X2(Parachain(_), AccountKey20 { .. })) => true,
+				_ => false,
+			};
+
+			ensure!(valid, Error::<T>::InvalidReleaseDestination);
+			Ok(())
+		}
+
+
+		// Fee quoting
+
+		// Quotes the execution cost of releasing `amount` of `currency`, `remote` adds the
+		// execution on the beneficiary's chain. Exposed so the fee can be shown before the payer signs
+		pub fn quote_release(currency: Token, amount: u128, remote: bool) -> Result<FeeQuote, DispatchError> {
+
+			// Weighed on the message `send_release` builds, the amounts, accounts and
+			// query it carries do not change its weight
+			let (_, asset) = Self::reserve_location(&currency);
+			let assets: MultiAsset = (asset, amount).into();
+			let beneficiary = MultiLocation::new(0, X1(AccountId32 { network: None, id: [0u8; 32] }));
+			let remote_message = Self::remote_deposit(assets.clone(), beneficiary);
+
+			let deposit = if remote {
+				DepositReserveAsset { assets: All.into(), dest: Here.into(), xcm: remote_message.clone() }
 			} else {
-				// Register to frame_system Account Storage item;
-				<frame_system::Account<T>>::set(multi_id, account_info);
+				DepositAsset { assets: All.into(), beneficiary }
+			};
+
+			let mut message = Self::release_message(WithdrawAsset(assets.clone().into()), assets.clone(), Weight::zero(), deposit);
+			Self::append_report(&mut message, QueryResponseInfo { destination: Here.into(), query_id: 0, max_weight: Weight::zero() });
+
+			let weight = Self::weigh_release(&currency, message)?;
+			let reserve_fee = Self::release_fee(&currency, &weight);
+
+			// The beneficiary's chain is priced like the reserve chain, it runs the deposit
+			// behind the instructions the reserve chain puts in front of it
+			let destination_fee = if remote {
+				let mut received = Xcm(vec![ReserveAssetDeposited(assets.into()), ClearOrigin]);
+				received.0.extend(remote_message.0);
+				Self::release_fee(&currency, &Self::weigh_release(&currency, received)?)
+			} else {
+				0
+			};
+
+			let fee = reserve_fee.saturating_add(destination_fee);
+
+			let receivable = amount.checked_sub(fee)
+				.filter(|receivable| *receivable > 0)
+				.ok_or(Error::<T>::AmountBelowReleaseFee)?;
+
+			Ok(FeeQuote { weight, fee, destination_fee, receivable })
+		}
+
+		// Weight of `message` on the reserve chain of `currency`
+		pub fn weigh_release(currency: &Token, message: Xcm<()>) -> Result<Weight, DispatchError> {
+			let mut message: Xcm<<T as pallet_xcm::Config>::RuntimeCall> = message.into();
+			let weight = match currency {
+				Token::DOT => T::RelayReleaseWeigher::weight(&mut message),
+				Token::USDT | Token::USDC => T::AssetHubReleaseWeigher::weight(&mut message),
+			};
+			weight.map_err(|_| Error::<T>::ReleaseNotWeighable.into())
+		}
+
+		// Fee charged for `weight` by the reserve chain of `currency`.
+		// AssetHub converts its DOT fee into a sufficient asset by the ratio of their min balances
+		pub fn release_fee(currency: &Token, weight: &Weight) -> u128 {
+			match currency {
+				Token::DOT => T::RelayWeightToFee::weight_to_fee(weight),
+				Token::USDT | Token::USDC => {
+					let min_balance = ExistentialDeposits::get(&CurrencyId::from(currency.clone()));
+					FixedU128::saturating_from_rational(min_balance, T::AssetHubExistentialDeposit::get())
+						.saturating_mul_int(T::AssetHubWeightToFee::weight_to_fee(weight))
+				},
+			}
+		}
+
+
+		// Xcm status tracking
+
+		// Registers a pallet_xcm notify query and appends `ReportError` so the destination
+		// answers with the execution outcome once the release message is executed
+		pub fn register_xcm_query(
+			message: &mut Xcm<()>,
+			release: XcmRelease<T>,
+		) -> Result<QueryId, DispatchError> {
+
+			let responder = release.destination;
+
+			let destination = <T as pallet_xcm::Config>::UniversalLocation::get()
+				.invert_target(&responder)
+				.map_err(|_| Error::<T>::XcmQueryRegistrationFailed)?;
+
+			let notify: <T as pallet_xcm::Config>::RuntimeCall = <T as Config>::RuntimeCall::from(
+				Call::<T>::xcm_response { query_id: 0, response: Default::default() }
+			).into();
+			let max_weight = notify.get_dispatch_info().weight;
+
+			let timeout = <frame_system::Pallet<T>>::block_number().saturating_add(T::XcmQueryTimeout::get());
+			let query_id = <pallet_xcm::Pallet<T>>::new_notify_query(responder, notify, timeout, Here);
+
+			QueryTimeouts::<T>::try_mutate(timeout, |queue| queue.try_push(query_id))
+				.map_err(|_| Error::<T>::ExpiryQueueFull)?;
+			Self::rewind_expiry_cursor(timeout);
+
+			Self::append_report(message, QueryResponseInfo { destination, query_id, max_weight });
+
+			XcmQueries::<T>::insert(query_id, release);
+
+			Ok(query_id)
+		}
+
+		pub fn mark_xcm_sent(query_id: QueryId) -> DispatchResult {
+			let release = XcmQueries::<T>::get(query_id).ok_or(Error::<T>::UnknownXcmQuery)?;
+			Self::update_xcm_status(&release, query_id, XcmStatus::Sent);
+			Ok(())
+		}
+
+		pub fn update_xcm_status(
+			release: &XcmRelease<T>,
+			query_id: QueryId,
+			status: XcmStatus
+		) {
+			// Expired receipts keep their status, the refund outcome is reported by the event
+			let expired = PayerTxnReceipt::<T>::get(&release.payer, &release.reference_no)
+				.map_or(false, |receipt| receipt.xcm_status == XcmStatus::Expired);
+
+			if !expired {
+				Self::set_receipt_status(&release.payer, &release.payee, &release.reference_no, status.clone());
+			}
+
+			Self::deposit_event(Event::XcmStatusUpdated {
+				reference_no: release.reference_no.to_vec(),
+				query_id,
+				status,
+			});
+		}
+
+		// A full payee list drops its oldest settled receipt, open payments are never dropped
+		pub fn push_payee_receipt(
+			receipts: &mut BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>,
+			receipt: TxnReceipt<T>
+		) -> Result<(), Error<T>> {
+			if receipts.len() >= T::MaxPayeeReceipts::get() as usize {
+				let settled = receipts.iter()
+					.position(|receipt| receipt.is_settled())
+					.ok_or(Error::<T>::TooManyReceipts)?;
+				receipts.remove(settled);
+			}
+
+			receipts.try_push(receipt).map_err(|_| Error::<T>::TooManyReceipts)
+		}
+
+		pub fn set_receipt_status(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			reference_no: &BoundedVec<u8, MAX_BYTES>,
+			status: XcmStatus
+		) {
+			PayerTxnReceipt::<T>::mutate(payer, reference_no, |maybe_receipt| {
+				if let Some(receipt) = maybe_receipt {
+					receipt.xcm_status = status.clone();
+				}
+			});
+
+			PayeeTxnReceipt::<T>::mutate(payee, |p_vec| {
+				p_vec.iter_mut()
+					.filter(|receipt| &receipt.reference_no == reference_no)
+					.for_each(|receipt| receipt.xcm_status = status.clone());
+			});
+		}
+
+		// Expiry
+
+		pub fn schedule_expiry(
+			reference_no: BoundedVec<u8, MAX_BYTES>,
+			asset_id: T::AssetIdParameter,
+			confirmation_window: Option<BlockNumberFor<T>>
+		) -> DispatchResult {
+
+			let window = confirmation_window.unwrap_or_else(T::ConfirmationWindow::get);
+			ensure!(
+				!window.is_zero() && window <= T::MaxConfirmationWindow::get(),
+				Error::<T>::InvalidConfirmationWindow
+			);
+
+			let expiry = <frame_system::Pallet<T>>::block_number().saturating_add(window);
+
+			ExpiryQueue::<T>::try_mutate(expiry, |queue| queue.try_push((reference_no.clone(), asset_id)))
+				.map_err(|_| Error::<T>::ExpiryQueueFull)?;
+			EscrowExpiry::<T>::insert(&reference_no, expiry);
+
+			Self::rewind_expiry_cursor(expiry);
+
+			Self::deposit_event(Event::ExpiryScheduled {
+				reference_no: reference_no.to_vec(),
+				expiry,
+			});
+
+			Ok(())
+		}
+
+		// Processing resumes from the earliest scheduled block, which can come before blocks already queued
+		// when a short window or a query timeout is scheduled after a longer one
+		pub fn rewind_expiry_cursor(at: BlockNumberFor<T>) {
+			ExpiryCursor::<T>::mutate(|cursor| *cursor = Some(cursor.map_or(at, |cursor| cursor.min(at))));
+		}
+
+		// Walks the expiry queue and the release query timeouts up to `now`, stopping once the remaining
+		// weight is used. Unprocessed entries stay queued and the cursor resumes from them next block
+		pub fn process_expiry_queue(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+
+			let Some(mut cursor) = ExpiryCursor::<T>::get() else { return Weight::zero() };
+
+			let queue_weight = T::DbWeight::get().reads_writes(4, 4);
+			let entry_weight = Self::expiry_weight();
+			let timeout_weight = Self::query_timeout_weight();
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+
+			while cursor <= now {
+				if used.saturating_add(queue_weight).any_gt(remaining_weight) {
+					break;
+				}
+				used = used.saturating_add(queue_weight);
+
+				let mut queue = ExpiryQueue::<T>::take(cursor);
+
+				while let Some((reference_no, asset_id)) = queue.last().cloned() {
+					if used.saturating_add(entry_weight).any_gt(remaining_weight) {
+						break;
+					}
+					used = used.saturating_add(entry_weight);
+					queue.pop();
+
+					// A failing refund must not leave a partially expired escrow behind
+					let result = frame_support::storage::with_storage_layer(|| {
+						Self::expire_escrow(reference_no.clone(), asset_id)
+					});
+
+					if let Err(error) = result {
+						log::warn!(
+							target: "vane-xcm",
+							"Failed to expire escrow {:?}: {:?}",
+							reference_no, error,
+						);
+
+						// The escrow stays open, its expiry is retried next block while that block has room
+						let retry = now.saturating_add(One::one());
+						let retry_at = ExpiryQueue::<T>::try_mutate(retry, |queue| queue.try_push((reference_no.clone(), asset_id)))
+							.ok()
+							.map(|_| retry);
+
+						Self::deposit_event(Event::ExpiryFailed {
+							reference_no: reference_no.to_vec(),
+							error,
+							retry_at,
+						});
+					}
+				}
+
+				let mut timeouts = QueryTimeouts::<T>::take(cursor);
+
+				while let Some(query_id) = timeouts.last().cloned() {
+					if used.saturating_add(timeout_weight).any_gt(remaining_weight) {
+						break;
+					}
+					used = used.saturating_add(timeout_weight);
+					timeouts.pop();
+
+					Self::time_out_query(query_id);
+				}
+
+				if !queue.is_empty() || !timeouts.is_empty() {
+					if !queue.is_empty() {
+						ExpiryQueue::<T>::insert(cursor, queue);
+					}
+					if !timeouts.is_empty() {
+						QueryTimeouts::<T>::insert(cursor, timeouts);
+					}
+					break;
+				}
+
+				cursor = cursor.saturating_add(One::one());
+			}
+
+			ExpiryCursor::<T>::put(cursor);
+
+			used
+		}
+
+		// A release whose destination never reported back is marked failed.
+		// The query stays registered, a late report still settles the release
+		pub fn time_out_query(query_id: QueryId) {
+			// Answered in time
+			let Some(release) = XcmQueries::<T>::get(query_id) else { return };
+
+			Self::update_xcm_status(&release, query_id, XcmStatus::Failed);
+
+			Self::deposit_event(Event::ReleaseTimedOut {
+				reference_no: release.reference_no.to_vec(),
+				query_id,
+			});
+		}
+
+		// Upper bound of a single query timeout, the query and the payer and payee receipts
+		pub fn query_timeout_weight() -> Weight {
+			T::DbWeight::get().reads_writes(3, 2)
+		}
+
+		// Refunds an escrow whose confirmation window passed
+		pub fn expire_escrow(reference_no: BoundedVec<u8, MAX_BYTES>, asset_id: T::AssetIdParameter) -> DispatchResult {
+
+			let (payer, payee) = ReceiptIndex::<T>::get(&reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+			let receipt = PayerTxnReceipt::<T>::get(&payer, &reference_no).ok_or(Error::<T>::ReceiptNotFound)?;
+
+			let expired = EscrowExpiry::<T>::get(&reference_no)
+				.map_or(false, |expiry| expiry <= <frame_system::Pallet<T>>::block_number());
+			if expired {
+				EscrowExpiry::<T>::remove(&reference_no);
+			}
+
+			// Released or reverted escrows are left alone
+			if receipt.xcm_status != XcmStatus::Tbc || !AllowedSigners::<T>::contains_key(&payer, &reference_no) {
+				return Ok(());
+			}
+
+			// Only refunded once its confirmation window passed
+			ensure!(expired, Error::<T>::EscrowNotExpired);
+
+			let (query_id, amount) = Self::vane_xcm_revert(payer.clone(), reference_no.clone())?;
+
+			AllowedSigners::<T>::remove(&payer, &reference_no);
+			ConfirmedSigners::<T>::remove(&reference_no);
+
+			Self::set_receipt_status(&payer, &payee, &reference_no, XcmStatus::Expired);
+
+			T::OnReverted::on_reverted(&payer, &payee, &reference_no, amount, None);
+
+			Self::deposit_event(Event::EscrowExpired {
+				reference_no: reference_no.to_vec(),
+				payer,
+				amount,
+				query_id,
+			});
+
+			Ok(())
+		}
+
+		// Upper bound of a single expiry, receipts, signers, burn, reserve accounting and the refund message
+		pub fn expiry_weight() -> Weight {
+			T::WeightInfo::expire_escrow(T::MaxPayeeReceipts::get())
+		}
+
+		// Failed releases
+
+		// The reserve asset of a failed release is trapped on the destination under Vane sovereign origin,
+		// so the escrow is restored against it until the payer or payee claims it back
+		pub fn restore_failed_release(query_id: QueryId, release: XcmRelease<T>) -> DispatchResult {
+
+			let issuer = ParaAccount::<T>::get().ok_or(Error::<T>::UnexpectedError)?;
+			let amount_type: <T as pallet_assets::Config>::Balance = release.amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+
+			<pallet_assets::Pallet<T>>::mint(
+				RawOrigin::Signed(issuer).into(),
+				release.asset_id,
+				T::Lookup::unlookup(release.payer.clone()),
+				amount_type
+			)?;
+			T::Escrow::hold(release.asset_id.into(), &HoldReason::VaneEscrow.into(), &release.payer, amount_type)?;
+
+			// A trapped reserve is only back once claimed, an unspent one never left Vane's account
+			if !TrappedReleases::<T>::contains_key(&release.reference_no) {
+				ReserveBalance::<T>::mutate(&release.currency, |reserve| *reserve = reserve.saturating_add(release.amount));
+			}
+			Self::reconcile_reserve(release.currency.clone(), release.asset_id);
+
+			Self::deposit_event(Event::ReleaseFailed {
+				reference_no: release.reference_no.to_vec(),
+				query_id,
+			});
+			Self::deposit_event(Event::EscrowRestored {
+				reference_no: release.reference_no.to_vec(),
+				payer: release.payer.clone(),
+				amount: release.amount,
+			});
+
+			FailedReleases::<T>::insert(release.reference_no.clone(), release);
+
+			Ok(())
+		}
+
+		// Most reserve asset left in the holding of a failed release, which the destination traps.
+		// The reported index is the failing instruction of the message built by `send_release`,
+		// `None` when the load itself failed and nothing new was trapped
+		pub fn trapped_amount(release: &XcmRelease<T>, response: &Response) -> Option<u128> {
+			match response {
+				Response::ExecutionResult(Some((0, _))) => None,
+				// At most the whole load is trapped, what the trader spent is only known to the destination
+				_ => Some(release.withdrawn),
+			}
+		}
+
+		// The held asset leaves Vane together with its reserve
+		pub fn burn_escrowed(
+			payer: &T::AccountId,
+			amount: u128,
+			asset_id: T::AssetIdParameter
+		) -> DispatchResult {
+
+			let amount_type: <T as pallet_assets::Config>::Balance = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+
+			T::Escrow::burn_held(
+				asset_id.into(),
+				&HoldReason::VaneEscrow.into(),
+				payer,
+				amount_type,
+				Precision::Exact,
+				Fortitude::Force
+			)?;
+
+			Ok(())
+		}
+
+		// Reserve accounting
+
+		// Derived assets are only minted by the asset transactor against an inbound reserve deposit,
+		// escrowing holds the payer's backed balance instead of minting new supply
+		pub fn escrow_derived_asset(
+			payer: T::AccountId,
+			amount: u128,
+			asset_id: T::AssetIdParameter
+		) -> DispatchResult {
+
+			let balance: <T as pallet_assets::Config>::Balance = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+
+			ensure!(
+				<pallet_assets::Pallet<T>>::balance(asset_id.into(), payer.clone()) >= balance,
+				Error::<T>::NotEnoughReserveDeposited
+			);
+
+			T::Escrow::hold(asset_id.into(), &HoldReason::VaneEscrow.into(), &payer, balance)?;
+
+			Self::deposit_event(Event::EscrowHeld {
+				time: <frame_system::Pallet<T>>::block_number(),
+				payer,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		pub fn release_reserve(
+			currency: Token,
+			amount: u128,
+			asset_id: T::AssetIdParameter
+		) -> DispatchResult {
+
+			ReserveBalance::<T>::try_mutate(&currency, |reserve| -> DispatchResult {
+				*reserve = reserve.checked_sub(amount).ok_or(Error::<T>::InsufficientReserve)?;
 				Ok(())
+			})?;
+
+			Self::reconcile_reserve(currency, asset_id);
+			Ok(())
+		}
+
+		// Compare the derived asset issuance against the recorded reserve
+		pub fn reconcile_reserve(currency: Token, asset_id: T::AssetIdParameter) -> bool {
+			let reserve = ReserveBalance::<T>::get(&currency);
+			let issuance: u128 = <pallet_assets::Pallet<T>>::total_supply(asset_id.into()).saturated_into();
+			let backed = issuance <= reserve;
+
+			if !backed {
+				log::warn!(
+					target: "vane-xcm",
+					"Derived {:?} issuance {:?} exceeds reserve {:?}",
+					currency, issuance, reserve,
+				);
 			}
+
+			Self::deposit_event(Event::ReserveReconciled { currency, reserve, issuance, backed });
+
+			backed
+		}
+
+		// Util functions
+
+		// The per pair nonce makes every payment between the same accounts unique
+		pub fn derive_reference_no(
+			payer: T::AccountId,
+			payee: T::AccountId,
+			multi_id: T::AccountId,
+		) -> Result<BoundedVec<u8,MAX_BYTES>, Error<T>> {
+			let nonce = PairNonce::<T>::get(&payer,&payee);
+
+			let (reference_no, nonce) = escrow::derive_reference_no(
+				&payer, &payee, &multi_id, nonce,
+				|reference_no| ReceiptIndex::<T>::contains_key(reference_no)
+			).ok_or(Error::<T>::ReferenceCollision)?;
+
+			PairNonce::<T>::insert(&payer,&payee,nonce);
+			Ok(reference_no)
+		}
+
+
+		pub  fn derive_multi_id(account_object: AccountSigners<T>) -> T::AccountId {
+			escrow::derive_multi_id(&account_object)
 		}
 
 	}
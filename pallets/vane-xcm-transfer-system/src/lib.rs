@@ -3,64 +3,159 @@
 
 pub use pallet::*;
 pub mod helper;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
-use log;
 use frame_support::Blake2_128;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use pallet_xcm;
 use sp_runtime::traits::{StaticLookup};
-//use vane_primitive::CurrencyId;
+use sp_runtime::SaturatedConversion;
+use vane_primitive::{CurrencyId, OnReserveDeposit};
 use sp_std::vec::Vec;
 use frame_support::parameter_types;
+use frame_support::weights::WeightToFee;
+use frame_support::traits::fungibles::MutateHold;
+use staging_xcm::latest::{MultiLocation, QueryId, Response};
+use staging_xcm::VersionedMultiLocation;
+use staging_xcm_executor::traits::WeightBounds;
+use sp_std::boxed::Box;
 
 
 #[frame_support::pallet]
 mod pallet{
 
-	use super::helper::{TxnReceipt,CallExecuted,AccountSigners,Token,Confirm};
+	use super::helper::{TxnReceipt,CallExecuted,AccountSigners,Token,Confirm,RevertReasons,XcmStatus,XcmRelease,ReceiptInfo,ConfirmationState,FeeQuote,ReferenceNo,MAX_BYTES};
+	use vane_primitive::escrow::{
+		ConfirmOutcome, MaxSigners, OnConfirmed, OnEscrowOpened, OnReleased, OnReverted, VaneEscrow,
+	};
 	use super::*;
 
 
+	// Escrowed assets are the derived assets of `CurrencyId`, worked out from the payment currency
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_xcm::Config + pallet_assets::Config {
+	pub trait Config: frame_system::Config + pallet_xcm::Config + pallet_assets::Config<AssetIdParameter = CurrencyId> {
 
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		// Used to build the `xcm_response` notification registered with pallet_xcm
+		type RuntimeCall: From<Call<Self>> + Into<<Self as pallet_xcm::Config>::RuntimeCall>;
+
+		// Origin of the QueryResponse reporting the outcome of a release message
+		type ResponseOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin, Success = MultiLocation>;
+
+		// Number of blocks a release waits for its outcome before it is marked failed
+		#[pallet::constant]
+		type XcmQueryTimeout: Get<BlockNumberFor<Self>>;
+
+		// Weighs release messages the way the relay chain, the reserve of DOT, does
+		type RelayReleaseWeigher: WeightBounds<<Self as pallet_xcm::Config>::RuntimeCall>;
+
+		// Weighs release messages the way AssetHub, the reserve of USDT and USDC, does
+		type AssetHubReleaseWeigher: WeightBounds<<Self as pallet_xcm::Config>::RuntimeCall>;
+
+		// Relay chain weight to fee, charged in DOT
+		type RelayWeightToFee: WeightToFee<Balance = u128>;
+
+		// AssetHub weight to fee in DOT. Sufficient assets pay it scaled by their min balance
+		// over `AssetHubExistentialDeposit`, the way AssetHub charges them
+		type AssetHubWeightToFee: WeightToFee<Balance = u128>;
+
+		#[pallet::constant]
+		type AssetHubExistentialDeposit: Get<u128>;
+
+		// Blocks the payee has to confirm a payment before it is refunded, used when the payer sets none
+		#[pallet::constant]
+		type ConfirmationWindow: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type MaxConfirmationWindow: Get<BlockNumberFor<Self>>;
+
+		// Payments which can expire in the same block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		// Receipts a payee can hold, bounds the weight of every call touching them
+		// Settled receipts are dropped first once full
+		#[pallet::constant]
+		type MaxPayeeReceipts: Get<u32>;
+
+		// Executed payments kept per account, the oldest is dropped once full
+		#[pallet::constant]
+		type MaxAccountTxns: Get<u32>;
+
+		type RuntimeHoldReason: From<HoldReason>;
+
+		// Escrowed derived assets stay on the payer's account under `HoldReason::VaneEscrow`
+		type Escrow: MutateHold<
+			Self::AccountId,
+			AssetId = <Self as pallet_assets::Config>::AssetId,
+			Balance = <Self as pallet_assets::Config>::Balance,
+			Reason = Self::RuntimeHoldReason,
+		>;
+
+		// Payment lifecycle hooks for pallets building on Vane payments
+		type OnEscrowOpened: OnEscrowOpened<Self::AccountId>;
+
+		type OnConfirmed: OnConfirmed<Self::AccountId>;
+
+		type OnReleased: OnReleased<Self::AccountId>;
+
+		type OnReverted: OnReverted<Self::AccountId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	// Reasons for holding funds in this pallet
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		// Payment waiting for its release or refund
+		#[codec(index = 0)]
+		VaneEscrow,
 	}
 
 	pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
-	// Max signers for Confirm Signers Bounded Vec
-	parameter_types! {
-		pub const MaxSigners: u16 = 2;
-	}
+	// Receipts, signers and executed payments moved to bounded storage in v1,
+	// escrows moved from the multi_id accounts to holds on the payer in v2,
+	// allowed signers took the shared escrow engine's layout in v3
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// Number of multi-sig transactions done by a specific account_id
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_account_multitxns)]
 	pub type AccountMultiTxns<T: Config> =
-	StorageMap<_, Blake2_256, T::AccountId, Vec<CallExecuted<T>>, ValueQuery>;
+	StorageMap<_, Blake2_256, T::AccountId, BoundedVec<CallExecuted<T>, T::MaxAccountTxns>, ValueQuery>;
 
 	// Signers which will be stored when payer initiates the transaction,
-	//This will be used to create a multi_id account which is shared with both payer and specified payee
+	// Their multi_id identifies the payer and payee pair when confirming
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_allowed_signers)]
 	pub type AllowedSigners<T: Config> =
-	StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, Vec<u8>, AccountSigners<T>>;
+	StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, BoundedVec<u8, MAX_BYTES>, AccountSigners<T>>;
 
 
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn get_signers)]
 
 	// Signers who have confirmed the transaction which will be compared to allowed signers as verification process
 	pub type ConfirmedSigners<T: Config> =
-	StorageMap<_, Twox64Concat, Vec<u8>, BoundedVec<T::AccountId, MaxSigners>, ValueQuery>;
+	StorageMap<_, Twox64Concat, BoundedVec<u8, MAX_BYTES>, BoundedVec<T::AccountId, MaxSigners>, ValueQuery>;
 
 	// Number of reverted or faulty transaction a payer did
 	#[pallet::storage]
@@ -73,31 +168,99 @@ mod pallet{
 	pub type RevertedTxnPayee<T: Config> = StorageMap<_, Blake2_256, T::AccountId, u32, ValueQuery>;
 
 
+	// Keys ----> (payer, reference_no), one receipt per payment
 	#[pallet::storage]
 	pub type PayerTxnReceipt<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
 		Blake2_128Concat,
-		T::AccountId,
+		BoundedVec<u8, MAX_BYTES>,
 		TxnReceipt<T>
 	>;
 
+	// Reference number lookup
+	// Key ----> reference_no
+	// Value ----> (payer, payee)
+	#[pallet::storage]
+	#[pallet::getter(fn get_receipt_index)]
+	pub type ReceiptIndex<T: Config> =
+	StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, (T::AccountId, T::AccountId)>;
+
+	// Number of payments made between a payer and a payee, used to derive unique reference numbers
+	#[pallet::storage]
+	pub type PairNonce<T: Config> =
+	StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	// TxnTicket Payee
 	// This is used to notify the payee as their is new pending transaction which needs confirmation
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type PayeeTxnReceipt<T: Config> =
-	StorageMap<_, Blake2_128Concat, T::AccountId, Vec<TxnReceipt<T>>, ValueQuery>;
+	StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>, ValueQuery>;
 
 	#[pallet::storage]
 	pub type MultiSigToPayee<T: Config> = StorageMap<_,Blake2_128,T::AccountId,T::AccountId>;
 
 	#[pallet::storage]
-	pub type TestStorage<T: Config> = StorageMap<_,Blake2_128, T::AccountId, u32,ValueQuery>;
+	pub type ParaAccount<T: Config> = StorageValue<_,T::AccountId>;
 
+	// Pending release messages waiting for the destination to report the outcome
 	#[pallet::storage]
-	pub type ParaAccount<T: Config> = StorageValue<_,T::AccountId>;
+	pub type XcmQueries<T: Config> = StorageMap<_, Blake2_128Concat, QueryId, XcmRelease<T>>;
+
+	// Releases which failed on the destination, their escrow is restored until claimed
+	// Key ----> reference_no
+	#[pallet::storage]
+	#[pallet::getter(fn get_failed_release)]
+	pub type FailedReleases<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, XcmRelease<T>>;
+
+	// Most reserve asset a failed release can have left trapped on its destination, the whole withdrawn load.
+	// Failed releases without one left nothing trapped, their claim withdraws again
+	// Key ----> reference_no
+	#[pallet::storage]
+	pub type TrappedReleases<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, u128>;
+
+	// Where a payee wants released funds deposited, as seen from Vane
+	#[pallet::storage]
+	#[pallet::getter(fn get_release_destination)]
+	pub type ReleaseDestination<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, MultiLocation>;
+
+	// Payee destination an escrow was opened and quoted against, later changes only apply to new escrows
+	// Key ----> reference_no
+	#[pallet::storage]
+	pub type EscrowRoute<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, MultiLocation>;
+
+	// Payments waiting for confirmation, keyed by the block they expire at
+	// Value ----> [(reference_no, asset_id)]
+	#[pallet::storage]
+	pub type ExpiryQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(BoundedVec<u8, MAX_BYTES>, T::AssetIdParameter), T::MaxExpiriesPerBlock>,
+		ValueQuery
+	>;
+
+	// Release queries waiting for their outcome, keyed by the block they time out at
+	#[pallet::storage]
+	pub type QueryTimeouts<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, BoundedVec<QueryId, T::MaxExpiriesPerBlock>, ValueQuery>;
+
+	// Next block of the expiry queue to be processed
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+	// Block an escrow expires at, cleared once its expiry is processed
+	// Key ----> reference_no
+	#[pallet::storage]
+	pub type EscrowExpiry<T: Config> = StorageMap<_, Blake2_128Concat, BoundedVec<u8, MAX_BYTES>, BlockNumberFor<T>>;
+
+	// Reserve held in Vane sovereign accounts per token
+	// Increased by inbound reserve deposits and decreased by outbound releases,
+	// derived asset issuance must never exceed it
+	#[pallet::storage]
+	#[pallet::getter(fn get_reserve_balance)]
+	pub type ReserveBalance<T: Config> = StorageMap<_, Blake2_128Concat, Token, u128, ValueQuery>;
 
 
 	#[pallet::genesis_config]
@@ -150,15 +313,53 @@ mod pallet{
 		MultiSigCallFailed,
 
 		TxnReceiptUnavailable,
+
+		NotEnoughReserveDeposited,
+
+		InsufficientReserve,
+
+		UnknownXcmQuery,
+
+		XcmQueryRegistrationFailed,
+
+		FailedReleaseNotFound,
+
+		AmountBelowReleaseFee,
+
+		ReferenceCollision,
+
+		AlreadyReleased,
+
+		InvalidConfirmationWindow,
+
+		ExpiryQueueFull,
+
+		InvalidReleaseDestination,
+
+		BadVersion,
+
+		TooManyReceipts,
+
+		NotThePayee,
+
+		NotThePayer,
+
+		NotThePayerOrPayee,
+
+		ClaimExceedsTrapped,
+
+		EscrowNotExpired,
+
+		ReleaseNotWeighable,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config>{
-		MultisigAccountCreated{
+		EscrowHeld {
 			time: BlockNumberFor<T>,
-			id: T::AccountId,
-			// amount
+			payer: T::AccountId,
+			amount: u128,
 		},
 		DotXcmTransferInitiated {
 			time: BlockNumberFor<T>,
@@ -166,6 +367,16 @@ mod pallet{
 			multi_id: AccountIdLookupOf<T>
 			// TXN HASH for Dot side txn_hash: T::Hash,
 		},
+		UsdtXcmTransferInitiated {
+			time: BlockNumberFor<T>,
+			amount: u128,
+			multi_id: AccountIdLookupOf<T>
+		},
+		UsdcXcmTransferInitiated {
+			time: BlockNumberFor<T>,
+			amount: u128,
+			multi_id: AccountIdLookupOf<T>
+		},
 		PayerAddressConfirmedXcm {
 			account_id: T::AccountId,
 			timestamp:BlockNumberFor<T>,
@@ -178,175 +389,403 @@ mod pallet{
 		},
 		MessageTransferedToPolkadot,
 
+		MessageTransferedToAssetHub,
+
+		XcmStatusUpdated {
+			reference_no: Vec<u8>,
+			query_id: QueryId,
+			status: XcmStatus,
+		},
+
+		ReserveDeposited {
+			who: T::AccountId,
+			currency: Token,
+			amount: u128,
+		},
+		ReserveReconciled {
+			currency: Token,
+			reserve: u128,
+			issuance: u128,
+			backed: bool,
+		},
+
+		ReleaseFailed {
+			reference_no: Vec<u8>,
+			query_id: QueryId,
+		},
+		ReleaseTimedOut {
+			reference_no: Vec<u8>,
+			query_id: QueryId,
+		},
+		EscrowRestored {
+			reference_no: Vec<u8>,
+			payer: T::AccountId,
+			amount: u128,
+		},
+		ReleaseFeeQuoted {
+			reference_no: Vec<u8>,
+			weight: Weight,
+			fee: u128,
+		},
+		FundsReverted {
+			reference_no: Vec<u8>,
+			payer: T::AccountId,
+			reason: RevertReasons,
+			amount: u128,
+			query_id: QueryId,
+		},
+		ExpiryScheduled {
+			reference_no: Vec<u8>,
+			expiry: BlockNumberFor<T>,
+		},
+		// `retry_at` is `None` when the next block's queue is full, the escrow then has to be reverted
+		ExpiryFailed {
+			reference_no: Vec<u8>,
+			error: DispatchError,
+			retry_at: Option<BlockNumberFor<T>>,
+		},
+		EscrowExpired {
+			reference_no: Vec<u8>,
+			payer: T::AccountId,
+			amount: u128,
+			query_id: QueryId,
+		},
+		ReleaseDestinationSet {
+			who: T::AccountId,
+			destination: Option<MultiLocation>,
+		},
+		FailedReleaseClaimed {
+			reference_no: Vec<u8>,
+			who: T::AccountId,
+			query_id: QueryId,
+		},
+
 	}
 
 
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// Expired escrows are refunded with the weight left in the block
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_expiry_queue(now, remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>{
 		#[pallet::call_index(0)]
-		#[pallet::weight(10)]
+		#[pallet::weight(T::WeightInfo::vane_transfer(T::MaxPayeeReceipts::get()))]
 		pub fn vane_transfer(
 			origin: OriginFor<T>,
 			payee: AccountIdLookupOf<T>,
 			amount: u128, // Fungibility
-			currency: Token,
-			asset_id: T::AssetIdParameter
+			currency: Token, // The escrowed derived asset follows the currency
+			confirmation_window: Option<BlockNumberFor<T>> // Defaults to ConfirmationWindow
 
 		) -> DispatchResult{
-			// log the origin
 
-			let caller = ensure_signed(origin.clone())?;
-			log::info!(
-				target: "",
-				" Caller {:?}",
-				caller,
-			);
+			let caller = ensure_signed(origin)?;
+			let payee_acc = T::Lookup::lookup(payee)?;
+
+			Self::open_escrow(caller, payee_acc, currency, amount, confirmation_window)?;
+
+			Ok(())
+		}
+
+
+		//Vane Transfer Confirmation
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vane_confirm(T::MaxPayeeReceipts::get()))]
+		pub fn vane_confirm(
+			origin: OriginFor<T>,
+			who: Confirm,
+			reference_no: Vec<u8>
+		) -> DispatchResult {
 
 
-			let payee_acc = T::Lookup::lookup(payee.clone())?;
+			let user_account = ensure_signed(origin)?;
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.try_into()
+				.map_err(|_| Error::<T>::ReceiptNotFound)?;
 
-			//ensure!( caller_acc == payer, Error::<T>::NotTheCaller);
-			// Construct a Multisig Account
+			Self::confirm_escrow(user_account, who, reference)?;
 
-			let multi_id = Self::vane_multisig_record(caller, payee_acc, amount, currency.clone())?;
+			Ok(())
+		}
 
-			// Check the Token type
+		// Notification dispatched by pallet_xcm once the destination reports the release outcome
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::xcm_response(T::MaxPayeeReceipts::get()))]
+		pub fn xcm_response(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response
+		) -> DispatchResult {
 
-			match currency {
-				Token::DOT => {
+			let _responder = T::ResponseOrigin::ensure_origin(origin)?;
 
-					let multi_id_acc = T::Lookup::unlookup(multi_id.clone());
-					let asset = asset_id;
+			let release = XcmQueries::<T>::take(query_id).ok_or(Error::<T>::UnknownXcmQuery)?;
 
-					Self::vane_xcm_transfer_dot(amount,multi_id_acc,multi_id,asset)?;
+			match response {
+				Response::ExecutionResult(None) => {
+					// A successful claim picked up whatever was trapped
+					TrappedReleases::<T>::remove(&release.reference_no);
+					Self::update_xcm_status(&release, query_id, XcmStatus::Completed);
 				},
-				Token::USDT => {
-					Err(Error::<T>::NotSupportedYet)?
+				_ => {
+					if let Some(trapped) = Self::trapped_amount(&release, &response) {
+						TrappedReleases::<T>::insert(&release.reference_no, trapped);
+					}
+					Self::update_xcm_status(&release, query_id, XcmStatus::Failed);
+					// Put the funds back in escrow until the payer or payee claims them
+					Self::restore_failed_release(query_id, release)?;
 				}
 			};
 
 			Ok(())
 		}
 
+		// Claim the funds of a failed release through the trapped asset on the destination.
+		// The payee retries the release to themselves while the payer takes the funds back.
+		// The claimant passes the amount the destination reported trapping, bounded by the withdrawn load
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::claim_failed_release(T::MaxPayeeReceipts::get()))]
+		pub fn claim_failed_release(
+			origin: OriginFor<T>,
+			reference_no: Vec<u8>,
+			trapped: Option<u128>
+		) -> DispatchResult {
 
-		//Vane Transfer Confirmation
+			let who = ensure_signed(origin)?;
 
-		#[pallet::call_index(1)]
-		#[pallet::weight(10)]
-		pub fn vane_confirm(
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.clone().try_into().map_err(|_| Error::<T>::FailedReleaseNotFound)?;
+			let mut release = FailedReleases::<T>::get(&reference).ok_or(Error::<T>::FailedReleaseNotFound)?;
+
+			// Only the payer or the beneficiary of the failed release can claim it
+			ensure!(who == release.payer || who == release.beneficiary, Error::<T>::NotTheCaller);
+
+			// Nothing trapped means the reserve never left Vane's account, it is withdrawn again
+			let max_trapped = TrappedReleases::<T>::get(&reference);
+			if let Some(max) = max_trapped {
+				let amount = trapped.unwrap_or(max);
+				ensure!(amount <= max, Error::<T>::ClaimExceedsTrapped);
+				release.withdrawn = amount;
+			}
+			FailedReleases::<T>::remove(&reference);
+
+			// The payer is refunded on the reserve chain, the payee keeps the chosen destination
+			if who != release.beneficiary {
+				release.route = None;
+			}
+			release.beneficiary = who.clone();
+
+			// Escrow leaves Vane again together with the claimed reserve
+			Self::burn_escrowed(&release.payer, release.amount, release.asset_id)?;
+			if max_trapped.is_none() {
+				Self::release_reserve(release.currency.clone(), release.amount, release.asset_id)?;
+			}
+
+			let query_id = Self::send_release(release, max_trapped.is_some())?;
+
+			Self::deposit_event(Event::FailedReleaseClaimed {
+				reference_no,
+				who,
+				query_id,
+			});
+
+			Ok(())
+		}
+
+		// If the payer makes a mistake the escrowed funds can be sent back before the release,
+		// the payee can also give them back. Reverts caused by the payer count against the payer,
+		// misbehaviour only counts against the payee when the payee reverts
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::vane_revert(T::MaxPayeeReceipts::get()))]
+		pub fn vane_revert(
 			origin: OriginFor<T>,
-			who: Confirm,
 			reference_no: Vec<u8>,
-			amount: u128, // From PayerTxnTicket
-			asset_id: T::AssetIdParameter
+			reason: RevertReasons
 		) -> DispatchResult {
 
+			let who = ensure_signed(origin)?;
 
-			let user_account = ensure_signed(origin)?;
-			// Check the storage
-			let b_vec = ConfirmedSigners::<T>::get(reference_no.clone());
-
-			if let Some(addr) = b_vec.get(0) {
-				if addr.eq(&user_account) {
-					return Err(Error::<T>::PayeeAlreadyConfirmed.into());
-
-				// Else for checking if payee tries to confirm twice.
-				} else {
-					ConfirmedSigners::<T>::try_mutate(reference_no.clone(), |vec| {
-						vec.try_push(user_account.clone())
-					})
-					.map_err(|_| Error::<T>::ExceededSigners)?;
-
-					let time = <frame_system::Pallet<T>>::block_number();
-
-					Self::deposit_event(Event::PayerAddressConfirmedXcm {
-						account_id: user_account,
-						timestamp: time,
-						reference_no: reference_no.clone(),
-					});
-
-					// Construct AccountSigner object from ConfirmedSigners storage
-
-					let confirmed_acc_signers = AccountSigners::<T>::new(
-						ConfirmedSigners::<T>::get(reference_no.clone())
-							.get(0)
-							.ok_or(Error::<T>::UnexpectedError)?
-							.clone(),
-						ConfirmedSigners::<T>::get(reference_no.clone())
-							.get(1)
-							.ok_or(Error::<T>::UnexpectedError)?
-							.clone(),
-					);
-
-					// Derive the multi_id of newly constructed AccountSigner and one from
-					// AllowedSigners
-					let confirmed_multi_id = Self::derive_multi_id(confirmed_acc_signers);
-
-					// Get the AllowedSigners from storage
-					let payer = ConfirmedSigners::<T>::get(reference_no.clone())
-						.get(1)
-						.ok_or(Error::<T>::UnexpectedError)?
-						.clone();
-
-					let payee = ConfirmedSigners::<T>::get(reference_no.clone())
-						.get(0)
-						.ok_or(Error::<T>::UnexpectedError)?
-						.clone();
-
-					let allowed_signers =
-						AllowedSigners::<T>::get(payer.clone(), reference_no.clone())
-							.ok_or(Error::<T>::NotAllowedPayeeOrPaymentNotInitialized)?;
-
-					let allowed_multi_id = Self::derive_multi_id(allowed_signers);
-					// Compute the hash of both multi_ids (proof)
-					if confirmed_multi_id.eq(&allowed_multi_id) {
-						// Dispatch xcm call
-
-						let multi_id_multi_acc = T::Lookup::unlookup(allowed_multi_id);
-
-						Self::vane_xcm_confirm_transfer_dot(payer,payee,multi_id_multi_acc,amount,asset_id)?
-
-					} else {
-						return Err(Error::<T>::FailedToMatchAccounts.into());
-					}
-				}
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.try_into().map_err(|_| Error::<T>::ReceiptNotFound)?;
 
-			// Else block from If let Some()
-			} else {
-				match who {
-					Confirm::Payer => return Err(Error::<T>::WaitForPayeeToConfirm.into()),
-
-					Confirm::Payee => {
-						ConfirmedSigners::<T>::try_mutate(reference_no.clone(), |vec| {
-							vec.try_push(user_account.clone())
-						})
-						.map_err(|_| Error::<T>::ExceededSigners)?;
-
-						let time = <frame_system::Pallet<T>>::block_number();
-
-						Self::deposit_event(Event::PayeeAddressConfirmedXcm {
-							account_id: user_account,
-							timestamp: time,
-							reference_no,
-						});
-					},
-				};
+			Self::revert_escrow(who, reference, reason)?;
+
+			Ok(())
+		}
+
+		// Payee chosen location for released funds, an account on the relay chain or on a parachain.
+		// `None` falls back to the payee's account on the reserve chain
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_release_destination())]
+		pub fn set_release_destination(
+			origin: OriginFor<T>,
+			destination: Option<Box<VersionedMultiLocation>>
+		) -> DispatchResult {
+
+			let who = ensure_signed(origin)?;
+
+			let destination = match destination {
+				Some(versioned) => {
+					let location = MultiLocation::try_from(*versioned).map_err(|_| Error::<T>::BadVersion)?;
+					Self::validate_release_destination(&location)?;
+					ReleaseDestination::<T>::insert(&who, location);
+					Some(location)
+				},
+				None => {
+					ReleaseDestination::<T>::remove(&who);
+					None
+				}
 			};
 
+			Self::deposit_event(Event::ReleaseDestinationSet { who, destination });
+
 			Ok(())
 		}
 
+	}
+
+	// Inbound reserve transfers arriving through VaneMultiCurrencyAdapter
+	impl<T: Config> OnReserveDeposit<T::AccountId, CurrencyId, <T as pallet_assets::Config>::Balance> for Pallet<T> {
+		fn on_reserve_deposit(currency_id: CurrencyId, who: &T::AccountId, amount: <T as pallet_assets::Config>::Balance) -> DispatchResult {
+			// Only tokens the escrow supports are accounted
+			let Ok(currency) = Token::try_from(currency_id) else { return Ok(()) };
+			let amount: u128 = amount.saturated_into();
+
+			ReserveBalance::<T>::mutate(&currency, |reserve| *reserve = reserve.saturating_add(amount));
+
+			Self::deposit_event(Event::ReserveDeposited {
+				who: who.clone(),
+				currency: currency.clone(),
+				amount,
+			});
+
+			Self::reconcile_reserve(currency, currency_id);
+			Ok(())
+		}
+	}
+
+	// Escrow lifecycle shared with the other Vane payment pallets,
+	// the escrowed asset is the derived asset backing the token
+	impl<T: Config> VaneEscrow<T::AccountId> for Pallet<T> {
+		type Asset = Token;
+		type BlockNumber = BlockNumberFor<T>;
+
+		fn open(
+			payer: &T::AccountId,
+			payee: &T::AccountId,
+			asset: Token,
+			amount: u128,
+			confirmation_window: Option<BlockNumberFor<T>>
+		) -> Result<ReferenceNo, DispatchError> {
+			Self::open_escrow(payer.clone(), payee.clone(), asset, amount, confirmation_window)
+		}
+
+		fn confirm(who: &T::AccountId, role: Confirm, reference_no: &ReferenceNo) -> Result<ConfirmOutcome<T::AccountId>, DispatchError> {
+			Self::confirm_escrow(who.clone(), role, reference_no.clone())
+		}
+
+		fn release(reference_no: &ReferenceNo) -> DispatchResult {
+			Self::release_escrow(reference_no.clone())
+		}
 
+		fn revert(payer: &T::AccountId, reference_no: &ReferenceNo, reason: RevertReasons) -> DispatchResult {
+			Self::revert_escrow(payer.clone(), reference_no.clone(), reason)
+		}
 
+		fn expire(reference_no: &ReferenceNo) -> DispatchResult {
+			let asset_id = Self::escrowed_asset_id(reference_no)?;
+			Self::expire_escrow(reference_no.clone(), asset_id)
+		}
 	}
 
 	impl<T: Config> Pallet<T>{
 
-		pub fn read_payer_receipt(origin: OriginFor<T>,payee: T:: AccountId) -> Result<TxnReceipt<T>, DispatchError>{
-			let payer = ensure_signed(origin)?;
-			let receipt = PayerTxnReceipt::<T>::get(payer,payee).ok_or(Error::<T>::ReceiptNotFound)?;
-			Ok(receipt)
+		// Derived asset escrowed for a payment, never taken from the caller
+		pub fn currency_asset_id(currency: &Token) -> T::AssetIdParameter {
+			CurrencyId::from(currency.clone())
+		}
+
+		pub fn escrowed_asset_id(reference_no: &ReferenceNo) -> Result<T::AssetIdParameter, DispatchError> {
+			let currency = Self::get_receipt(reference_no)
+				.ok_or(Error::<T>::ReceiptNotFound)?
+				.currency()
+				.ok_or(Error::<T>::NotSupportedYet)?;
+
+			Ok(Self::currency_asset_id(&currency))
+		}
+
+		// Receipt of a single payment looked up by its reference number
+		pub fn get_receipt(reference_no: &BoundedVec<u8, MAX_BYTES>) -> Option<TxnReceipt<T>> {
+			let (payer, _payee) = ReceiptIndex::<T>::get(reference_no)?;
+			PayerTxnReceipt::<T>::get(payer, reference_no)
+		}
+
+		// Runtime API queries
+
+		pub fn payer_receipts(payer: T::AccountId) -> Vec<ReceiptInfo<T::AccountId>> {
+			PayerTxnReceipt::<T>::iter_prefix_values(&payer).map(|receipt| receipt.info()).collect()
+		}
+
+		pub fn payee_receipts(payee: T::AccountId) -> Vec<ReceiptInfo<T::AccountId>> {
+			PayeeTxnReceipt::<T>::get(&payee).iter().map(|receipt| receipt.info()).collect()
+		}
+
+		// Payments waiting for `account` to confirm, the payee confirms first and the payer after
+		pub fn pending_confirmations(account: T::AccountId) -> Vec<ReceiptInfo<T::AccountId>> {
+			let as_payee = PayeeTxnReceipt::<T>::get(&account)
+				.into_iter()
+				.filter(|receipt| {
+					Self::confirmation_state(receipt.reference_no.to_vec())
+						.map_or(false, |state| state.is_open() && !state.payee_confirmed)
+				});
+
+			let as_payer = PayerTxnReceipt::<T>::iter_prefix_values(&account)
+				.filter(|receipt| {
+					Self::confirmation_state(receipt.reference_no.to_vec())
+						.map_or(false, |state| state.is_open() && state.payee_confirmed && !state.payer_confirmed)
+				});
+
+			as_payee.chain(as_payer).map(|receipt| receipt.info()).collect()
+		}
+
+		// Amount still held on the payer for a payment
+		pub fn escrow_balance(reference_no: Vec<u8>) -> Option<u128> {
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.try_into().ok()?;
+			let receipt = Self::get_receipt(&reference)?;
+
+			if receipt.xcm_status == XcmStatus::Tbc && Self::confirmation_state(reference.to_vec())?.is_open() {
+				Some(receipt.amount)
+			} else {
+				Some(0)
+			}
+		}
+
+		pub fn confirmation_state(reference_no: Vec<u8>) -> Option<ConfirmationState> {
+			let reference: BoundedVec<u8, MAX_BYTES> = reference_no.clone().try_into().ok()?;
+			let (payer, payee) = ReceiptIndex::<T>::get(&reference)?;
+			let receipt = PayerTxnReceipt::<T>::get(&payer, &reference)?;
+			let signers = ConfirmedSigners::<T>::get(&reference);
+
+			Some(ConfirmationState {
+				payee_confirmed: signers.contains(&payee),
+				payer_confirmed: signers.contains(&payer),
+				// Reverted and expired payments drop their allowed signers
+				closed: !AllowedSigners::<T>::contains_key(&payer, &reference),
+				status: receipt.xcm_status,
+			})
+		}
+
+		// Release fee of a payment to `payee`, including the hop to the payee's chosen destination
+		pub fn quote_payment(payee: &T::AccountId, currency: Token, amount: u128) -> Result<FeeQuote, DispatchError> {
+			let remote = match ReleaseDestination::<T>::get(payee) {
+				Some(route) => Self::reserve_route(&currency, route)?.0 != MultiLocation::here(),
+				None => false,
+			};
+			Self::quote_release(currency, amount, remote)
 		}
 
 	}
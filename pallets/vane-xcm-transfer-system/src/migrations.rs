@@ -0,0 +1,340 @@
+// Storage migrations for the Vane XCM escrow
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use helper::{CallExecuted, TxnReceipt};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod v1 {
+	use super::*;
+
+	// Receipts before references were unique to a payment, one per payer and payee
+	#[frame_support::storage_alias]
+	pub(crate) type PayerTxnReceipt<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		TxnReceipt<T>,
+	>;
+
+	// Re-keys the payer receipts by their reference number and indexes the reference.
+	// Runs before `MigrateToV1`, which bumps the storage version past the old keys
+	pub struct RekeyPayerReceipts<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for RekeyPayerReceipts<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 0 {
+				log::info!(target: "vane-xcm", "RekeyPayerReceipts skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			// Receipts already keyed by their reference fail to decode and are left alone
+			let receipts = PayerTxnReceipt::<T>::iter().collect::<Vec<_>>();
+			let rekeyed = receipts.len() as u64;
+
+			for (payer, payee, receipt) in receipts {
+				PayerTxnReceipt::<T>::remove(&payer, &payee);
+				ReceiptIndex::<T>::insert(&receipt.reference_no, (payer.clone(), payee));
+				crate::PayerTxnReceipt::<T>::insert(&payer, receipt.reference_no.clone(), receipt);
+			}
+
+			log::info!(target: "vane-xcm", "RekeyPayerReceipts re-keyed {} receipts", rekeyed);
+
+			T::DbWeight::get().reads_writes(rekeyed.saturating_add(1), rekeyed.saturating_mul(3))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((PayerTxnReceipt::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let receipts = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+			ensure!(PayerTxnReceipt::<T>::iter_keys().next().is_none(), "receipts left under their payee");
+
+			// Receipts keyed by their reference before the upgrade are counted too
+			let mut rekeyed = 0u64;
+			for (payer, reference_no, receipt) in crate::PayerTxnReceipt::<T>::iter() {
+				ensure!(receipt.reference_no == reference_no, "receipt keyed by another reference");
+				ensure!(
+					ReceiptIndex::<T>::get(&reference_no) == Some((payer, receipt.payee().clone())),
+					"receipt reference not indexed"
+				);
+				rekeyed += 1;
+			}
+			ensure!(rekeyed >= receipts, "payer receipts lost");
+
+			Ok(())
+		}
+	}
+
+	// Moves the payee receipts and executed payments into their bounded vectors.
+	// Signers keep their keys, a reference number encodes the same as a `Vec<u8>`
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 0 {
+				log::info!(target: "vane-xcm", "MigrateToV1 skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+			let mut dropped = 0u64;
+
+			PayeeTxnReceipt::<T>::translate::<Vec<TxnReceipt<T>>, _>(|_, receipts| {
+				translated += 1;
+				let (kept, lost) = bound_receipts::<T>(receipts);
+				dropped += lost;
+				Some(kept)
+			});
+
+			AccountMultiTxns::<T>::translate::<Vec<CallExecuted<T>>, _>(|_, mut executed| {
+				translated += 1;
+				// Keep the most recent payments
+				let max = T::MaxAccountTxns::get() as usize;
+				let excess = executed.len().saturating_sub(max);
+				dropped += excess as u64;
+				executed.drain(..excess);
+				Some(BoundedVec::truncate_from(executed))
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "vane-xcm", "MigrateToV1 bounded {} entries, dropped {} records", translated, dropped);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let payees = PayeeTxnReceipt::<T>::iter_keys().count() as u64;
+			Ok(payees.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+
+			let payees = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+
+			// Values left over their bound fail to decode and are skipped
+			ensure!(PayeeTxnReceipt::<T>::iter_values().count() as u64 == payees, "payee receipts lost or over bound");
+			ensure!(
+				AccountMultiTxns::<T>::iter_values().count() == AccountMultiTxns::<T>::iter_keys().count(),
+				"executed payments over bound"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Settled receipts go first, then the oldest ones. The payer's receipt of a dropped entry stays
+	fn bound_receipts<T: Config>(mut receipts: Vec<TxnReceipt<T>>) -> (BoundedVec<TxnReceipt<T>, T::MaxPayeeReceipts>, u64) {
+		let max = T::MaxPayeeReceipts::get() as usize;
+		let mut excess = receipts.len().saturating_sub(max);
+		let dropped = excess as u64;
+
+		receipts.retain(|receipt| {
+			if excess > 0 && receipt.is_settled() {
+				excess -= 1;
+				false
+			} else {
+				true
+			}
+		});
+		receipts.drain(..excess);
+
+		(BoundedVec::truncate_from(receipts), dropped)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::fungibles::{Mutate, MutateHold};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::traits::fungibles::InspectHold;
+	use frame_support::traits::tokens::Preservation;
+	use helper::XcmStatus;
+	use sp_runtime::traits::Zero;
+	use sp_std::collections::btree_set::BTreeSet;
+
+	// Escrows opened before v2 sit in the multi_id accounts, they are moved back to their payer and held there.
+	// A multi_id left without balance or references is removed from frame_system
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 1 {
+				log::info!(target: "vane-xcm", "MigrateToV2 skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let escrows = open_escrows::<T>();
+			let mut multi_ids = BTreeSet::new();
+			let mut moved = 0u64;
+			let mut removed = 0u64;
+
+			for (payer, multi_id, asset_id, amount) in escrows.iter() {
+				// Nothing moves when the payer cannot hold the amount, the escrow stays in the multi_id
+				let result = frame_support::storage::with_storage_layer(|| {
+					escrow_on_payer::<T>(payer, multi_id, *asset_id, *amount)
+				});
+
+				match result {
+					Ok(()) => moved += 1,
+					Err(error) => log::warn!(
+						target: "vane-xcm",
+						"MigrateToV2 failed to hold {:?} for {:?}: {:?}",
+						amount, payer, error,
+					),
+				}
+
+				multi_ids.insert(multi_id.clone());
+			}
+
+			for multi_id in multi_ids.iter() {
+				let Ok(account) = frame_system::Account::<T>::try_get(multi_id) else { continue };
+
+				if account.nonce.is_zero() &&
+					account.providers == 0 &&
+					account.consumers == 0 &&
+					account.sufficients == 0 &&
+					account.data == Default::default()
+				{
+					frame_system::Account::<T>::remove(multi_id);
+					removed += 1;
+				}
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: "vane-xcm",
+				"MigrateToV2 held {} of {} escrows, removed {} multi_id accounts",
+				moved, escrows.len(), removed,
+			);
+
+			let reads = PayerTxnReceipt::<T>::iter_keys().count() as u64;
+			let writes = moved.saturating_mul(4).saturating_add(removed);
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(escrows.len() as u64 * 4).saturating_add(1), writes.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((open_escrows::<T>().len() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+
+			let escrows = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+			let open = open_escrows::<T>();
+			ensure!(open.len() as u64 == escrows, "escrows lost");
+
+			for (payer, _, asset_id, amount) in open {
+				let held: u128 = T::Escrow::balance_on_hold(asset_id.into(), &HoldReason::VaneEscrow.into(), &payer)
+					.saturated_into();
+				ensure!(held >= amount, "escrow not held on the payer");
+			}
+
+			Ok(())
+		}
+	}
+
+	// Payments waiting for confirmation and failed releases waiting for a claim
+	// Value ----> [(payer, multi_id, asset_id, amount)]
+	fn open_escrows<T: Config>() -> Vec<(T::AccountId, T::AccountId, T::AssetIdParameter, u128)> {
+		let pending = PayerTxnReceipt::<T>::iter()
+			.filter(|(payer, reference, receipt)| {
+				receipt.xcm_status == XcmStatus::Tbc && AllowedSigners::<T>::contains_key(payer, reference)
+			})
+			.filter_map(|(payer, _, receipt)| {
+				let asset_id: T::AssetIdParameter = CurrencyId::from(receipt.currency()?);
+				Some((payer, receipt.multi_id, asset_id, receipt.amount))
+			});
+
+		let failed = FailedReleases::<T>::iter_values()
+			.map(|release| (release.payer, release.multi_id, release.asset_id, release.amount));
+
+		pending.chain(failed).collect()
+	}
+
+	fn escrow_on_payer<T: Config>(
+		payer: &T::AccountId,
+		multi_id: &T::AccountId,
+		asset_id: T::AssetIdParameter,
+		amount: u128,
+	) -> DispatchResult {
+		let balance: <T as pallet_assets::Config>::Balance = amount.try_into().map_err(|_| Error::<T>::UnexpectedError)?;
+
+		<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::transfer(asset_id.into(), multi_id, payer, balance, Preservation::Expendable)?;
+		T::Escrow::hold(asset_id.into(), &HoldReason::VaneEscrow.into(), payer, balance)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use helper::AccountSigners;
+
+	// Allowed signers before the escrow engine was shared, without a resolver
+	#[derive(Encode, Decode)]
+	struct OldAccountSigners<AccountId> {
+		payee: AccountId,
+		payer: AccountId,
+	}
+
+	// Allowed signers take the engine's layout, xcm payments are opened without a resolver
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 2 {
+				log::info!(target: "vane-xcm", "MigrateToV3 skipped, storage version {:?}", onchain);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut translated = 0u64;
+
+			AllowedSigners::<T>::translate::<OldAccountSigners<T::AccountId>, _>(|_, _, old| {
+				translated += 1;
+				Some(AccountSigners::<T>::new(old.payee, old.payer, None))
+			});
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: "vane-xcm", "MigrateToV3 translated {} allowed signers", translated);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((AllowedSigners::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 3, "storage version not updated");
+
+			let signers = u64::decode(&mut &state[..]).map_err(|_| "invalid pre upgrade state")?;
+			ensure!(AllowedSigners::<T>::iter_values().count() as u64 == signers, "allowed signers lost");
+
+			Ok(())
+		}
+	}
+}
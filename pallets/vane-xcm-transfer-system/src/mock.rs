@@ -0,0 +1,336 @@
+// Test runtime for the Vane XCM escrow.
+// Release messages are captured by `TestXcmRouter` instead of being sent, tests answer them through `xcm_response`
+
+use crate as vane_xcm;
+use crate::helper::{ReferenceNo, Token};
+use codec::Encode;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{fungibles::{InspectHold, Mutate}, AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, Hooks, Nothing},
+	weights::{IdentityFee, Weight},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use staging_xcm::latest::prelude::*;
+use staging_xcm_builder::{
+	AccountId32Aliases, AllowUnpaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds, ParentIsPreset,
+	SignedToAccountId32,
+};
+use staging_xcm_executor::XcmExecutor;
+use std::cell::RefCell;
+use vane_primitive::{CurrencyId, MultiCurrencyAsset, OnReserveDeposit};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const PAYER: AccountId = AccountId32::new([1u8; 32]);
+pub const PAYEE: AccountId = AccountId32::new([2u8; 32]);
+pub const STRANGER: AccountId = AccountId32::new([3u8; 32]);
+// Vane sovereign account, issuer of the derived assets
+pub const ISSUER: AccountId = AccountId32::new([9u8; 32]);
+
+pub const PARA_ID: u32 = 2000;
+pub const INITIAL_DEPOSIT: Balance = 1_000_000;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		XcmPallet: pallet_xcm,
+		VaneXcmTransfer: vane_xcm,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<CurrencyId> for AssetsBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> CurrencyId {
+		CurrencyId::Foreign(id)
+	}
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = CurrencyId;
+	type AssetIdParameter = CurrencyId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<32>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetsBenchmarkHelper;
+}
+
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+// Messages sent by the pallet, oldest first
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+pub struct TestXcmRouter;
+impl SendXcm for TestXcmRouter {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(dest: &mut Option<MultiLocation>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		Ok(((dest, message), MultiAssets::new()))
+	}
+
+	fn deliver((dest, message): Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = message.using_encoded(sp_io::hashing::blake2_256);
+		SENT_XCM.with(|sent| sent.borrow_mut().push((dest, message)));
+		Ok(hash)
+	}
+}
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
+	pub UniversalLocation: InteriorMultiLocation = Parachain(PARA_ID).into();
+	pub const UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+}
+
+pub type LocationToAccountId = (
+	ParentIsPreset<AccountId>,
+	AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+pub type XcmWeigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+
+pub struct XcmConfig;
+impl staging_xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = TestXcmRouter;
+	type AssetTransactor = ();
+	type OriginConverter = ();
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type Aliasers = Nothing;
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type Weigher = XcmWeigher;
+	type Trader = ();
+	type ResponseHandler = XcmPallet;
+	type AssetTrap = XcmPallet;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = XcmPallet;
+	type SubscriptionService = XcmPallet;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+}
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = TestXcmRouter;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Nothing;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Nothing;
+	type Weigher = XcmWeigher;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type MaxLockers = ConstU32<8>;
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ReachableDest = ReachableDest;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+}
+
+parameter_types! {
+	pub const XcmQueryTimeout: u64 = 20;
+	pub const ReleaseUnitWeight: Weight = Weight::from_parts(1_000, 0);
+	pub const AssetHubExistentialDeposit: u128 = 70_000;
+	pub const ConfirmationWindow: u64 = 10;
+	pub const MaxConfirmationWindow: u64 = 100;
+	pub const MaxExpiriesPerBlock: u32 = 8;
+	pub const MaxPayeeReceipts: u32 = 8;
+	pub const MaxAccountTxns: u32 = 8;
+}
+
+pub type Escrow = MultiCurrencyAsset<Test, (), RuntimeHoldReason>;
+
+impl vane_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type XcmQueryTimeout = XcmQueryTimeout;
+	type RelayReleaseWeigher = FixedWeightBounds<ReleaseUnitWeight, RuntimeCall, MaxInstructions>;
+	type AssetHubReleaseWeigher = FixedWeightBounds<ReleaseUnitWeight, RuntimeCall, MaxInstructions>;
+	type RelayWeightToFee = IdentityFee<Balance>;
+	type AssetHubWeightToFee = IdentityFee<Balance>;
+	type AssetHubExistentialDeposit = AssetHubExistentialDeposit;
+	type ConfirmationWindow = ConfirmationWindow;
+	type MaxConfirmationWindow = MaxConfirmationWindow;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxPayeeReceipts = MaxPayeeReceipts;
+	type MaxAccountTxns = MaxAccountTxns;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Escrow = Escrow;
+	type OnEscrowOpened = ();
+	type OnConfirmed = ();
+	type OnReleased = ();
+	type OnReverted = ();
+	type WeightInfo = ();
+}
+
+// Inbound reserve transfer credited to `who`, as done by the asset transactor
+pub fn deposit_reserve(who: &AccountId, currency: Token, amount: Balance) {
+	let currency_id = CurrencyId::from(currency);
+	assert_ok!(<Assets as Mutate<AccountId>>::mint_into(currency_id, who, amount));
+	assert_ok!(<VaneXcmTransfer as OnReserveDeposit<AccountId, CurrencyId, Balance>>::on_reserve_deposit(currency_id, who, amount));
+}
+
+// Derived asset held on `who` for Vane payments
+pub fn escrowed(who: &AccountId, currency_id: CurrencyId) -> Balance {
+	Escrow::balance_on_hold(currency_id, &vane_xcm::HoldReason::VaneEscrow.into(), who)
+}
+
+// Origin of a `QueryResponse` reported by the reserve chain
+pub fn response_origin() -> RuntimeOrigin {
+	pallet_xcm::Origin::Response(Parent.into()).into()
+}
+
+// Reference of the last payment opened
+pub fn last_reference() -> ReferenceNo {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::VaneXcmTransfer(vane_xcm::Event::ExpiryScheduled { reference_no, .. }) => {
+				Some(ReferenceNo::truncate_from(reference_no))
+			},
+			_ => None,
+		})
+		.expect("no payment opened")
+}
+
+// Moves to block `n`, every block gives its idle weight to the expiry queue
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		VaneXcmTransfer::on_idle(next, Weight::MAX);
+	}
+}
+
+// Payer holding reserve backed DOT, USDT and USDC, the para account issues the derived assets
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(CurrencyId::DOT, ISSUER, true, 1), (CurrencyId::USDT, ISSUER, true, 1), (CurrencyId::USDC, ISSUER, true, 1)],
+		metadata: vec![],
+		accounts: vec![],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	vane_xcm::GenesisConfig::<Test> { para_account: Some(ISSUER) }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		deposit_reserve(&PAYER, Token::DOT, INITIAL_DEPOSIT);
+		deposit_reserve(&PAYER, Token::USDT, INITIAL_DEPOSIT);
+		deposit_reserve(&PAYER, Token::USDC, INITIAL_DEPOSIT);
+		SENT_XCM.with(|sent| sent.borrow_mut().clear());
+	});
+	ext
+}
@@ -0,0 +1,463 @@
+// Escrow lifecycle tests, release messages are answered by hand through `xcm_response`
+
+use crate::{
+	helper::{Confirm, ReferenceNo, RevertReasons, Token, TxnReceipt, XcmStatus},
+	migrations::v1::{self, RekeyPayerReceipts},
+	mock::*,
+	Error, Pallet, PayerTxnReceipt, ReceiptIndex, ReserveBalance, RevertedTxnPayee, RevertedTxnPayer, TrappedReleases,
+	XcmQueries,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+use staging_xcm::{
+	latest::{Error as XcmError, Instruction, Junction, Junctions, MultiAsset, MultiAssets, MultiLocation, Parent, Response},
+	VersionedMultiLocation,
+};
+use vane_primitive::{escrow::VaneEscrow, CurrencyId};
+
+const AMOUNT: u128 = 100_000;
+
+// Escrowed asset
+
+#[test]
+fn vane_transfer_escrows_the_asset_of_the_currency() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::USDT, None));
+
+		let reference = last_reference();
+		let receipt = PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap();
+		assert_eq!(receipt.currency(), Some(Token::USDT));
+
+		assert_eq!(escrowed(&PAYER, CurrencyId::USDT), AMOUNT);
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), 0);
+	});
+}
+
+#[test]
+fn vane_transfer_rejects_a_payer_holding_another_asset() {
+	new_test_ext().execute_with(|| {
+		// Only holds the USDT derived asset while recording a DOT payment
+		deposit_reserve(&STRANGER, Token::USDT, INITIAL_DEPOSIT);
+
+		assert_noop!(
+			VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(STRANGER), PAYEE, AMOUNT, Token::DOT, None),
+			Error::<Test>::NotEnoughReserveDeposited
+		);
+		assert_eq!(escrowed(&STRANGER, CurrencyId::USDT), 0);
+	});
+}
+
+#[test]
+fn release_burns_the_asset_of_the_recorded_currency() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), 0);
+		assert_eq!(Assets::balance(CurrencyId::DOT, PAYER), INITIAL_DEPOSIT - AMOUNT);
+		assert_eq!(Assets::balance(CurrencyId::USDT, PAYER), INITIAL_DEPOSIT);
+		assert_eq!(ReserveBalance::<Test>::get(Token::DOT), INITIAL_DEPOSIT - AMOUNT);
+
+		// The relay chain is the reserve of DOT
+		let (destination, _) = sent_xcm().pop().unwrap();
+		assert_eq!(destination, MultiLocation::from(Parent));
+	});
+}
+
+#[test]
+fn usdc_is_released_from_asset_hub() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::USDC, None));
+		let reference = last_reference();
+		assert_eq!(escrowed(&PAYER, CurrencyId::USDC), AMOUNT);
+
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+		assert_eq!(ReserveBalance::<Test>::get(Token::USDC), INITIAL_DEPOSIT - AMOUNT);
+		let (destination, _) = sent_xcm().pop().unwrap();
+		assert_eq!(destination, Pallet::<Test>::reserve_location(&Token::USDC).0);
+	});
+}
+
+// Confirmation
+
+#[test]
+fn only_the_payee_confirms_first() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_noop!(
+			VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(STRANGER), Confirm::Payee, reference.to_vec()),
+			Error::<Test>::NotThePayee
+		);
+		assert_noop!(
+			VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payee, reference.to_vec()),
+			Error::<Test>::NotThePayee
+		);
+	});
+}
+
+#[test]
+fn only_the_payer_confirms_last() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+
+		assert_noop!(
+			VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(STRANGER), Confirm::Payer, reference.to_vec()),
+			Error::<Test>::NotThePayer
+		);
+
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), 0);
+	});
+}
+
+// Reverts
+
+#[test]
+fn payer_claiming_misbehaviour_blames_nobody() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_ok!(VaneXcmTransfer::vane_revert(
+			RuntimeOrigin::signed(PAYER),
+			reference.to_vec(),
+			RevertReasons::PayeeMisbehaviour
+		));
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), 0);
+		assert_eq!(RevertedTxnPayee::<Test>::get(&PAYEE), 0);
+		assert_eq!(RevertedTxnPayer::<Test>::get(&PAYER), 0);
+	});
+}
+
+#[test]
+fn payee_giving_the_payment_back_admits_misbehaviour() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_ok!(VaneXcmTransfer::vane_revert(
+			RuntimeOrigin::signed(PAYEE),
+			reference.to_vec(),
+			RevertReasons::PayeeMisbehaviour
+		));
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), 0);
+		assert_eq!(RevertedTxnPayee::<Test>::get(&PAYEE), 1);
+	});
+}
+
+#[test]
+fn payer_changing_their_mind_counts_against_the_payer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_ok!(VaneXcmTransfer::vane_revert(
+			RuntimeOrigin::signed(PAYER),
+			reference.to_vec(),
+			RevertReasons::ChangeOfDecision
+		));
+		assert_eq!(RevertedTxnPayer::<Test>::get(&PAYER), 1);
+		assert_eq!(RevertedTxnPayee::<Test>::get(&PAYEE), 0);
+	});
+}
+
+#[test]
+fn strangers_cannot_revert() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_noop!(
+			VaneXcmTransfer::vane_revert(RuntimeOrigin::signed(STRANGER), reference.to_vec(), RevertReasons::ChangeOfDecision),
+			Error::<Test>::NotThePayerOrPayee
+		);
+	});
+}
+
+// Release destination
+
+fn set_destination(destination: MultiLocation) {
+	let destination = Some(Box::new(VersionedMultiLocation::V3(destination)));
+	assert_ok!(VaneXcmTransfer::set_release_destination(RuntimeOrigin::signed(PAYEE), destination));
+}
+
+fn account_on(chain: Junctions) -> MultiLocation {
+	let account = Junction::AccountId32 { network: None, id: [2u8; 32] };
+	MultiLocation::new(1, chain.pushed_with(account).unwrap())
+}
+
+#[test]
+fn relay_chain_account_only_receives_dot() {
+	new_test_ext().execute_with(|| {
+		set_destination(account_on(Junctions::Here));
+
+		assert_noop!(
+			VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::USDT, None),
+			Error::<Test>::InvalidReleaseDestination
+		);
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+	});
+}
+
+#[test]
+fn destination_set_after_opening_does_not_move_the_release() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		set_destination(account_on(Junctions::X1(Junction::Parachain(2000))));
+
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+		// Released as quoted, to the payee's account on the relay chain
+		let (_, message) = sent_xcm().pop().unwrap();
+		assert!(message.0.iter().any(|instruction| matches!(instruction, Instruction::DepositAsset { .. })));
+		assert!(!message.0.iter().any(|instruction| matches!(instruction, Instruction::DepositReserveAsset { .. })));
+	});
+}
+
+// Failed releases
+
+// Released DOT payment whose release message is answered with `response`
+fn failed_release(response: Response) -> ReferenceNo {
+	assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+	let reference = last_reference();
+	assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+	assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+	let query_id = XcmQueries::<Test>::iter_keys().next().unwrap();
+	assert_ok!(VaneXcmTransfer::xcm_response(response_origin(), query_id, response));
+	reference
+}
+
+// First instruction of the last message sent, which loads the released asset
+fn last_load() -> Instruction<()> {
+	let (_, message) = sent_xcm().pop().unwrap();
+	message.0[0].clone()
+}
+
+fn dot(amount: u128) -> MultiAssets {
+	MultiAsset::from((MultiLocation::here(), amount)).into()
+}
+
+#[test]
+fn claim_picks_up_everything_trapped_before_execution_was_bought() {
+	new_test_ext().execute_with(|| {
+		let reference = failed_release(Response::ExecutionResult(Some((1, XcmError::TooExpensive))));
+		assert_eq!(TrappedReleases::<Test>::get(&reference), Some(AMOUNT));
+
+		assert_ok!(VaneXcmTransfer::claim_failed_release(RuntimeOrigin::signed(PAYEE), reference.to_vec(), None));
+		assert_eq!(last_load(), Instruction::ClaimAsset { assets: dot(AMOUNT), ticket: MultiLocation::here() });
+	});
+}
+
+#[test]
+fn claim_leaves_out_the_fees_spent_before_the_deposit_failed() {
+	new_test_ext().execute_with(|| {
+		let reference = failed_release(Response::ExecutionResult(Some((4, XcmError::FailedToTransactAsset("")))));
+		assert_eq!(TrappedReleases::<Test>::get(&reference), Some(AMOUNT));
+
+		// The destination reports what it trapped once the fees were spent
+		let fee = Pallet::<Test>::quote_release(Token::DOT, AMOUNT, false).unwrap().fee;
+		assert_ok!(VaneXcmTransfer::claim_failed_release(RuntimeOrigin::signed(PAYEE), reference.to_vec(), Some(AMOUNT - fee)));
+		assert_eq!(last_load(), Instruction::ClaimAsset { assets: dot(AMOUNT - fee), ticket: MultiLocation::here() });
+	});
+}
+
+#[test]
+fn claim_cannot_exceed_the_withdrawn_load() {
+	new_test_ext().execute_with(|| {
+		let reference = failed_release(Response::ExecutionResult(Some((4, XcmError::FailedToTransactAsset("")))));
+
+		assert_noop!(
+			VaneXcmTransfer::claim_failed_release(RuntimeOrigin::signed(PAYEE), reference.to_vec(), Some(AMOUNT + 1)),
+			Error::<Test>::ClaimExceedsTrapped
+		);
+	});
+}
+
+#[test]
+fn trapped_reserve_is_not_credited_back() {
+	new_test_ext().execute_with(|| {
+		let reference = failed_release(Response::ExecutionResult(Some((1, XcmError::TooExpensive))));
+		assert_eq!(ReserveBalance::<Test>::get(Token::DOT), INITIAL_DEPOSIT - AMOUNT);
+
+		assert_ok!(VaneXcmTransfer::claim_failed_release(RuntimeOrigin::signed(PAYER), reference.to_vec(), None));
+		assert_eq!(ReserveBalance::<Test>::get(Token::DOT), INITIAL_DEPOSIT - AMOUNT);
+	});
+}
+
+#[test]
+fn claim_withdraws_again_when_nothing_was_loaded() {
+	new_test_ext().execute_with(|| {
+		let reference = failed_release(Response::ExecutionResult(Some((0, XcmError::NotWithdrawable))));
+		assert_eq!(TrappedReleases::<Test>::get(&reference), None);
+		assert_eq!(ReserveBalance::<Test>::get(Token::DOT), INITIAL_DEPOSIT);
+
+		assert_ok!(VaneXcmTransfer::claim_failed_release(RuntimeOrigin::signed(PAYER), reference.to_vec(), None));
+		assert_eq!(last_load(), Instruction::WithdrawAsset(dot(AMOUNT)));
+		assert_eq!(ReserveBalance::<Test>::get(Token::DOT), INITIAL_DEPOSIT - AMOUNT);
+	});
+}
+
+// Expiry
+
+#[test]
+fn short_window_expires_before_an_earlier_longer_one() {
+	new_test_ext().execute_with(|| {
+		let long = Some(MaxConfirmationWindow::get());
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, long));
+		run_to_block(2);
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, Some(5)));
+		let short = last_reference();
+
+		run_to_block(7);
+
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &short).unwrap().xcm_status, XcmStatus::Expired);
+		assert_eq!(escrowed(&PAYER, CurrencyId::DOT), AMOUNT);
+	});
+}
+
+#[test]
+fn open_escrow_cannot_be_expired_early() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+
+		assert_noop!(
+			<VaneXcmTransfer as VaneEscrow<AccountId>>::expire(&reference),
+			Error::<Test>::EscrowNotExpired
+		);
+
+		run_to_block(1 + ConfirmationWindow::get());
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Expired);
+	});
+}
+
+#[test]
+fn failed_expiry_is_retried_next_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		let expiry = 1 + ConfirmationWindow::get();
+
+		// The recorded reserve cannot cover the refund
+		ReserveBalance::<Test>::insert(Token::DOT, 0);
+		run_to_block(expiry);
+
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Tbc);
+		System::assert_has_event(
+			crate::Event::<Test>::ExpiryFailed {
+				reference_no: reference.to_vec(),
+				error: Error::<Test>::InsufficientReserve.into(),
+				retry_at: Some(expiry + 1),
+			}
+			.into(),
+		);
+
+		ReserveBalance::<Test>::insert(Token::DOT, INITIAL_DEPOSIT);
+		run_to_block(expiry + 1);
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Expired);
+	});
+}
+
+// Release outcome
+
+#[test]
+fn unanswered_release_fails_once_its_query_times_out() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+		let query_id = XcmQueries::<Test>::iter_keys().next().unwrap();
+
+		run_to_block(XcmQueryTimeout::get());
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Sent);
+
+		run_to_block(1 + XcmQueryTimeout::get());
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Failed);
+
+		// A late report still settles the release
+		assert_ok!(VaneXcmTransfer::xcm_response(response_origin(), query_id, Response::ExecutionResult(None)));
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Completed);
+	});
+}
+
+#[test]
+fn release_times_out_before_a_longer_pending_expiry() {
+	new_test_ext().execute_with(|| {
+		let long = Some(MaxConfirmationWindow::get());
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, long));
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYEE), Confirm::Payee, reference.to_vec()));
+		assert_ok!(VaneXcmTransfer::vane_confirm(RuntimeOrigin::signed(PAYER), Confirm::Payer, reference.to_vec()));
+
+		run_to_block(1 + XcmQueryTimeout::get());
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference).unwrap().xcm_status, XcmStatus::Failed);
+	});
+}
+
+// Migrations
+
+// Runs a migration with its try-runtime checks when they are built
+fn upgrade<M: OnRuntimeUpgrade>() {
+	#[cfg(feature = "try-runtime")]
+	let state = M::pre_upgrade().unwrap();
+	M::on_runtime_upgrade();
+	#[cfg(feature = "try-runtime")]
+	M::post_upgrade(state).unwrap();
+}
+
+#[test]
+fn rekey_moves_legacy_receipts_under_their_reference() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		let reference = ReferenceNo::truncate_from(vec![7u8; 6]);
+		let receipt = TxnReceipt::<Test>::new(
+			PAYEE,
+			PAYER,
+			ISSUER,
+			reference.clone(),
+			AMOUNT,
+			AMOUNT,
+			Some(Token::DOT),
+		);
+		v1::PayerTxnReceipt::<Test>::insert(&PAYER, &PAYEE, receipt.clone());
+
+		upgrade::<RekeyPayerReceipts<Test>>();
+
+		assert!(!v1::PayerTxnReceipt::<Test>::contains_key(&PAYER, &PAYEE));
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference), Some(receipt.clone()));
+		assert_eq!(ReceiptIndex::<Test>::get(&reference), Some((PAYER, PAYEE)));
+		assert_eq!(VaneXcmTransfer::get_receipt(&reference), Some(receipt));
+	});
+}
+
+#[test]
+fn rekey_leaves_receipts_keyed_by_their_reference() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(VaneXcmTransfer::vane_transfer(RuntimeOrigin::signed(PAYER), PAYEE, AMOUNT, Token::DOT, None));
+		let reference = last_reference();
+		let receipt = PayerTxnReceipt::<Test>::get(&PAYER, &reference);
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		upgrade::<RekeyPayerReceipts<Test>>();
+
+		assert_eq!(PayerTxnReceipt::<Test>::get(&PAYER, &reference), receipt);
+		assert_eq!(ReceiptIndex::<Test>::get(&reference), Some((PAYER, PAYEE)));
+	});
+}
@@ -0,0 +1,208 @@
+//! Weights for `vane_xcm_transfer_system`
+//!
+//! Hand-written upper bounds, not measured by the benchmarks of this pallet.
+//! Runtimes should replace them with weights generated by `scripts/benchmark.sh` on reference hardware.
+//! `r` is the number of receipts already stored for the payee.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `vane_xcm_transfer_system`.
+pub trait WeightInfo {
+	fn vane_transfer(r: u32, ) -> Weight;
+	fn vane_confirm(r: u32, ) -> Weight;
+	fn xcm_response(r: u32, ) -> Weight;
+	fn claim_failed_release(r: u32, ) -> Weight;
+	fn vane_revert(r: u32, ) -> Weight;
+	fn set_release_destination() -> Weight;
+	fn expire_escrow(r: u32, ) -> Weight;
+}
+
+/// Hand-written weights for `vane_xcm_transfer_system`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: VaneXcmTransfer ReleaseDestination (r:1 w:0)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:1 w:1)
+	/// Storage: VaneXcmTransfer PairNonce (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReceiptIndex (r:1 w:1)
+	/// Storage: VaneXcmTransfer AllowedSigners (r:0 w:1)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Storage: VaneXcmTransfer ExpiryQueue (r:1 w:1)
+	/// Storage: VaneXcmTransfer ExpiryCursor (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn vane_transfer(r: u32, ) -> Weight {
+		Weight::from_parts(92_000_000, 9_800)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneXcmTransfer ConfirmedSigners (r:1 w:1)
+	/// Storage: VaneXcmTransfer AllowedSigners (r:1 w:0)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:2 w:1)
+	/// Storage: VaneXcmTransfer ParaAccount (r:1 w:0)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReleaseDestination (r:1 w:0)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Storage: VaneXcmTransfer XcmQueries (r:1 w:1)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:1 w:1)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn vane_confirm(r: u32, ) -> Weight {
+		Weight::from_parts(128_000_000, 12_400)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneXcmTransfer XcmQueries (r:1 w:1)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:2 w:1)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:1 w:1)
+	/// Storage: VaneXcmTransfer ParaAccount (r:1 w:0)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	/// Storage: VaneXcmTransfer FailedReleases (r:0 w:1)
+	/// Storage: VaneXcmTransfer TrappedReleases (r:0 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn xcm_response(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 8_600)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneXcmTransfer FailedReleases (r:1 w:1)
+	/// Storage: VaneXcmTransfer TrappedReleases (r:1 w:0)
+	/// Storage: VaneXcmTransfer ParaAccount (r:1 w:0)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Storage: VaneXcmTransfer XcmQueries (r:1 w:1)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:2 w:1)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:1 w:1)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn claim_failed_release(r: u32, ) -> Weight {
+		Weight::from_parts(118_000_000, 11_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneXcmTransfer ReceiptIndex (r:1 w:0)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:3 w:1)
+	/// Storage: VaneXcmTransfer ParaAccount (r:1 w:0)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Storage: VaneXcmTransfer XcmQueries (r:1 w:1)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:1 w:1)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Storage: VaneXcmTransfer AllowedSigners (r:0 w:1)
+	/// Storage: VaneXcmTransfer ConfirmedSigners (r:0 w:1)
+	/// Storage: VaneXcmTransfer RevertedTxnPayer (r:1 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn vane_revert(r: u32, ) -> Weight {
+		Weight::from_parts(124_000_000, 12_000)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	/// Storage: VaneXcmTransfer ReleaseDestination (r:0 w:1)
+	fn set_release_destination() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneXcmTransfer ReceiptIndex (r:1 w:0)
+	/// Storage: VaneXcmTransfer PayerTxnReceipt (r:4 w:2)
+	/// Storage: VaneXcmTransfer AllowedSigners (r:1 w:1)
+	/// Storage: VaneXcmTransfer ParaAccount (r:1 w:0)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	/// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	/// Storage: PolkadotXcm Queries (r:0 w:1)
+	/// Storage: VaneXcmTransfer XcmQueries (r:1 w:1)
+	/// Storage: VaneXcmTransfer PayeeTxnReceipt (r:2 w:2)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// Storage: VaneXcmTransfer ConfirmedSigners (r:0 w:1)
+	/// The range of component `r` is `[0, 512]`.
+	fn expire_escrow(r: u32, ) -> Weight {
+		Weight::from_parts(126_000_000, 12_600)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+			.saturating_add(Weight::from_parts(0, 980).saturating_mul(r.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn vane_transfer(r: u32, ) -> Weight {
+		Weight::from_parts(92_000_000, 9_800)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn vane_confirm(r: u32, ) -> Weight {
+		Weight::from_parts(128_000_000, 12_400)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn xcm_response(r: u32, ) -> Weight {
+		Weight::from_parts(74_000_000, 8_600)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn claim_failed_release(r: u32, ) -> Weight {
+		Weight::from_parts(118_000_000, 11_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn vane_revert(r: u32, ) -> Weight {
+		Weight::from_parts(124_000_000, 12_000)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(Weight::from_parts(0, 490).saturating_mul(r.into()))
+	}
+	fn set_release_destination() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn expire_escrow(r: u32, ) -> Weight {
+		Weight::from_parts(126_000_000, 12_600)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+			.saturating_add(Weight::from_parts(0, 980).saturating_mul(r.into()))
+	}
+}
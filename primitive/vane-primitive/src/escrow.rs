@@ -0,0 +1,420 @@
+// Escrow engine shared by the Vane payment pallets.
+// A payment is opened by the payer, confirmed by the payee and then the payer, and settled by its release,
+// a revert or its expiry. The pallets keep the storage and move the funds, the engine keeps the rules
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, parameter_types};
+use scale_info::TypeInfo;
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::vec::Vec;
+use crate::CurrencyId;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+parameter_types! {
+	pub const MAX_BYTES: u8 = 50;
+	pub const MAX_NO_TXNS: u8 = 20;
+	pub const MAX_REFERENCE_ATTEMPTS: u8 = 10;
+	// Payee and payer
+	pub const MaxSigners: u16 = 2;
+}
+
+pub type ReferenceNo = BoundedVec<u8, MAX_BYTES>;
+
+// Confirmation enum which will be used to confirm the account_ids before releasing the payment
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Confirm {
+	Payer,
+	Payee,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Token {
+	DOT,
+	USDT,
+	USDC
+}
+
+impl TryFrom<CurrencyId> for Token {
+	type Error = ();
+
+	fn try_from(currency_id: CurrencyId) -> Result<Self, Self::Error> {
+		match currency_id {
+			CurrencyId::DOT => Ok(Token::DOT),
+			CurrencyId::USDT => Ok(Token::USDT),
+			CurrencyId::USDC => Ok(Token::USDC),
+			_ => Err(())
+		}
+	}
+}
+
+impl From<Token> for CurrencyId {
+	fn from(token: Token) -> Self {
+		match token {
+			Token::DOT => CurrencyId::DOT,
+			Token::USDT => CurrencyId::USDT,
+			Token::USDC => CurrencyId::USDC,
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum XcmStatus {
+	Sent,
+	Completed,
+	Tbc,
+	Failed,
+	Expired
+}
+
+// Revert Fund reasons enum
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RevertReasons {
+	// The fee will be refunded, the payer must show a proof of wrong address.
+	WrongPayeeAddress,
+	// We should introduce sort of punishment, This reason should be taken seriously and
+	// at the moment it should be only used in non trade operation.
+	ChangeOfDecision,
+	// Seller's fault, this is when a resolver intervene
+	PayeeMisbehaviour,
+}
+
+// This will act as a dispute resolution methods. A user will have to choose which method
+// is the best for a given dispute which may arise.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Resolver<AccountId> {
+	// A legal team if chosen will be authorized to sign the transaction
+	LegalTeam(AccountId),
+	// A governance vote ( A Dao ) wil have to vote to favor which way the transaction
+	// should be signed
+	Governance,
+	//some future time feature
+	Both(AccountId),
+}
+
+// This should be used as a parameter for choosing which Resolving method should take place
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ResolverChoice {
+	LegalTeam,
+	Governance,
+}
+
+// Accounts allowed to confirm a payment
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AccountSigners<AccountId> {
+	payee: AccountId,
+	payer: AccountId,
+	resolver: Option<Resolver<AccountId>>,
+}
+
+impl<AccountId> AccountSigners<AccountId> {
+	pub fn new(payee: AccountId, payer: AccountId, resolver: Option<Resolver<AccountId>>) -> Self {
+		AccountSigners { payee, payer, resolver }
+	}
+
+	pub fn get_payer(&self) -> &AccountId {
+		&self.payer
+	}
+
+	pub fn get_payee(&self) -> &AccountId {
+		&self.payee
+	}
+
+	pub fn get_resolver(&self) -> &Option<Resolver<AccountId>> {
+		&self.resolver
+	}
+
+	pub fn get_legal_account(&self) -> Option<&AccountId> {
+		if let Some(Resolver::LegalTeam(account)) = &self.resolver {
+			Some(account)
+		} else {
+			None
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TxnReceipt<AccountId> {
+	payee: AccountId,
+	payer: AccountId,
+	pub multi_id: AccountId,
+	pub amount: u128,
+	pub reference_no: ReferenceNo,
+	currency: Option<Token>,
+	no_txn: BoundedVec<u128, MAX_NO_TXNS>,
+	pub xcm_status: XcmStatus
+}
+
+impl<AccountId: Clone> TxnReceipt<AccountId> {
+	pub fn new(
+		payee: AccountId,
+		payer: AccountId,
+		multi_id: AccountId,
+		ref_no: ReferenceNo,
+		amount: u128,
+		txn: u128,
+		currency: Option<Token>
+	) -> Self {
+		let mut no_txn = BoundedVec::new();
+		let _ = no_txn.try_push(txn);
+
+		Self {
+			payee, payer, reference_no: ref_no,
+			amount, currency, no_txn,
+			xcm_status: XcmStatus::Tbc, multi_id
+		}
+	}
+
+	pub fn update_txn(&mut self, txn: u128) -> Result<(), u128> {
+		self.no_txn.try_push(txn)
+	}
+
+	pub fn update_amount(&mut self, amount: u128) {
+		self.amount = self.amount.saturating_add(amount)
+	}
+
+	pub fn payer(&self) -> &AccountId {
+		&self.payer
+	}
+
+	pub fn payee(&self) -> &AccountId {
+		&self.payee
+	}
+
+	pub fn currency(&self) -> Option<Token> {
+		self.currency.clone()
+	}
+
+	// Paid out or refunded, the payer's receipt keeps the record
+	pub fn is_settled(&self) -> bool {
+		matches!(self.xcm_status, XcmStatus::Completed | XcmStatus::Expired)
+	}
+
+	pub fn info(&self) -> ReceiptInfo<AccountId> {
+		ReceiptInfo {
+			payer: self.payer.clone(),
+			payee: self.payee.clone(),
+			multi_id: self.multi_id.clone(),
+			amount: self.amount,
+			reference_no: self.reference_no.to_vec(),
+			currency: self.currency.clone(),
+			status: self.xcm_status.clone(),
+		}
+	}
+}
+
+// Client facing view of a receipt, returned by the runtime API
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReceiptInfo<AccountId> {
+	pub payer: AccountId,
+	pub payee: AccountId,
+	pub multi_id: AccountId,
+	pub amount: u128,
+	pub reference_no: Vec<u8>,
+	pub currency: Option<Token>,
+	pub status: XcmStatus,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ConfirmationState {
+	pub payee_confirmed: bool,
+	pub payer_confirmed: bool,
+	// Reverted or expired, the payment can no longer be confirmed
+	pub closed: bool,
+	pub status: XcmStatus,
+}
+
+impl ConfirmationState {
+	pub fn is_open(&self) -> bool {
+		!self.closed && self.status == XcmStatus::Tbc
+	}
+}
+
+// Call executed struct information
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CallExecuted<AccountId, Hash, BlockNumber> {
+	payer: AccountId,
+	payee: AccountId,
+	allowed_multi_id: AccountId,
+	confirmed_multi_id: AccountId,
+	proof: Hash,
+	time: BlockNumber,
+}
+
+impl<AccountId, Hash, BlockNumber> CallExecuted<AccountId, Hash, BlockNumber> {
+	pub fn new(
+		payer: AccountId,
+		payee: AccountId,
+		allowed_multi_id: AccountId,
+		confirmed_multi_id: AccountId,
+		proof: Hash,
+		time: BlockNumber,
+	) -> Self {
+		CallExecuted { payer, payee, allowed_multi_id, confirmed_multi_id, proof, time }
+	}
+}
+
+// Step of the confirmation state machine
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ConfirmOutcome<AccountId> {
+	// Waiting for the payer
+	PayeeConfirmed,
+	// Both sides confirmed, the payment can be released once the signers match the allowed ones
+	Confirmed { payee: AccountId, payer: AccountId },
+}
+
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum ConfirmError {
+	PayeeAlreadyConfirmed,
+	WaitForPayeeToConfirm,
+	ExceededSigners,
+	// Only the payee allowed when the payment was opened confirms first
+	NotThePayee,
+	// Only the payer allowed when the payment was opened confirms last
+	NotThePayer,
+}
+
+// The payee always confirms first and sits at index 0, the payer confirms last.
+// Both have to be the signers `allowed` when the payment was opened
+pub fn confirm<AccountId: PartialEq + Clone>(
+	signers: &mut BoundedVec<AccountId, MaxSigners>,
+	allowed: &AccountSigners<AccountId>,
+	who: AccountId,
+	role: Confirm,
+) -> Result<ConfirmOutcome<AccountId>, ConfirmError> {
+	match signers.get(0).cloned() {
+		Some(payee) => {
+			// The payee cannot confirm twice
+			if payee == who {
+				return Err(ConfirmError::PayeeAlreadyConfirmed);
+			}
+			if &who != allowed.get_payer() {
+				return Err(ConfirmError::NotThePayer);
+			}
+			signers.try_push(who.clone()).map_err(|_| ConfirmError::ExceededSigners)?;
+			Ok(ConfirmOutcome::Confirmed { payee, payer: who })
+		},
+		None => match role {
+			Confirm::Payer => Err(ConfirmError::WaitForPayeeToConfirm),
+			Confirm::Payee => {
+				if &who != allowed.get_payee() {
+					return Err(ConfirmError::NotThePayee);
+				}
+				signers.try_push(who).map_err(|_| ConfirmError::ExceededSigners)?;
+				Ok(ConfirmOutcome::PayeeConfirmed)
+			},
+		},
+	}
+}
+
+// Identifies the payee, payer and legal team of a payment, no account is created for it
+pub fn derive_multi_id<AccountId: Encode + Decode>(signers: &AccountSigners<AccountId>) -> AccountId {
+	let entropy = match signers.get_legal_account() {
+		Some(legal) => (b"vane/salt", signers.get_payee(), signers.get_payer(), legal).using_encoded(blake2_256),
+		None => (b"vane/salt", signers.get_payee(), signers.get_payer()).using_encoded(blake2_256),
+	};
+
+	Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
+// The per pair nonce makes every payment between the same accounts unique,
+// colliding references are skipped by bumping the nonce. Returns the reference and the next nonce
+pub fn derive_reference_no<AccountId: Encode>(
+	payer: &AccountId,
+	payee: &AccountId,
+	multi_id: &AccountId,
+	mut nonce: u32,
+	is_taken: impl Fn(&ReferenceNo) -> bool,
+) -> Option<(ReferenceNo, u32)> {
+	for _ in 0..MAX_REFERENCE_ATTEMPTS::get() {
+		let mut buffer = Vec::new();
+		buffer.append(&mut payer.using_encoded(blake2_256).to_vec());
+		buffer.append(&mut payee.using_encoded(blake2_256).to_vec());
+		buffer.append(&mut multi_id.using_encoded(blake2_256).to_vec());
+		buffer.append(&mut nonce.encode());
+
+		let reference = blake2_256(&buffer[..]);
+		let reference_no = ReferenceNo::truncate_from(reference[20..26].to_vec());
+
+		nonce = nonce.wrapping_add(1);
+
+		if !is_taken(&reference_no) {
+			return Some((reference_no, nonce));
+		}
+	}
+
+	None
+}
+
+// Proof that the confirmed signers match the allowed ones
+pub fn confirmation_proof<AccountId: Encode, Hash: Decode>(allowed_multi_id: &AccountId, confirmed_multi_id: &AccountId) -> Option<Hash> {
+	let encoded_proof = (allowed_multi_id, confirmed_multi_id).using_encoded(blake2_256);
+	Decode::decode(&mut TrailingZeroInput::new(encoded_proof.as_ref())).ok()
+}
+
+// Lifecycle of a Vane protected payment, implemented by the payment pallets
+pub trait VaneEscrow<AccountId> {
+	// What is escrowed besides the amount, e.g. the currency
+	type Asset;
+	type BlockNumber;
+
+	// Escrows `amount` from the payer and returns the reference of the new payment.
+	// `confirmation_window` defaults to the pallet's window
+	fn open(
+		payer: &AccountId,
+		payee: &AccountId,
+		asset: Self::Asset,
+		amount: u128,
+		confirmation_window: Option<Self::BlockNumber>,
+	) -> Result<ReferenceNo, DispatchError>;
+
+	// Records the confirmation of `who`, the payment is released once both sides confirmed
+	fn confirm(who: &AccountId, role: Confirm, reference_no: &ReferenceNo) -> Result<ConfirmOutcome<AccountId>, DispatchError>;
+
+	// Pays the escrowed amount out to the payee
+	fn release(reference_no: &ReferenceNo) -> DispatchResult;
+
+	// Refunds the payer before the payment is released
+	fn revert(payer: &AccountId, reference_no: &ReferenceNo, reason: RevertReasons) -> DispatchResult;
+
+	// Refunds a payment whose confirmation window passed, settled payments are left alone.
+	// Fails while the window is still open
+	fn expire(reference_no: &ReferenceNo) -> DispatchResult;
+}
+
+// Lifecycle hooks for pallets building on Vane payments, e.g. marketplaces or subscriptions.
+// They are called once the step succeeded and cannot fail it. Tuples of hooks are called in order
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnEscrowOpened<AccountId> {
+	fn on_escrow_opened(payer: &AccountId, payee: &AccountId, reference_no: &ReferenceNo, amount: u128);
+}
+
+// Called for every confirmation, the payee's and then the payer's
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnConfirmed<AccountId> {
+	fn on_confirmed(who: &AccountId, role: &Confirm, reference_no: &ReferenceNo);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnReleased<AccountId> {
+	fn on_released(payer: &AccountId, payee: &AccountId, reference_no: &ReferenceNo, amount: u128);
+}
+
+// `reason` is `None` when the payment expired or its dispute ended without a verdict
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnReverted<AccountId> {
+	fn on_reverted(
+		payer: &AccountId,
+		payee: &AccountId,
+		reference_no: &ReferenceNo,
+		amount: u128,
+		reason: Option<RevertReasons>,
+	);
+}
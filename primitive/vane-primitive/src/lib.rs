@@ -7,12 +7,15 @@ use codec::{Decode, Encode};
 use frame_support::dispatch::{RawOrigin};
 use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::StaticLookup;
-use frame_support::traits::{ContainsPair, EnsureOriginWithArg, Everything, OriginTrait, UnfilteredDispatchable};
-use frame_support::traits::fungibles::{Balanced, Inspect};
-use frame_support::traits::tokens::{Fortitude, Precision, Preservation, WithdrawConsequence};
+use frame_support::traits::{Contains, ContainsPair, EnsureOriginWithArg, Everything, OriginTrait, UnfilteredDispatchable};
+use frame_support::traits::fungibles::{Balanced, Dust, Inspect, InspectHold, Mutate, MutateHold, Unbalanced, UnbalancedHold};
+use frame_support::traits::tokens::{BalanceStatus, DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence};
+use frame_support::PalletId;
 use scale_info::TypeInfo;
 use staging_xcm::latest::prelude::*;
 
+pub mod escrow;
+
 //
 // pub use primitive_mod::*;
 // #[frame_support::pallet]
@@ -48,7 +51,7 @@ pub trait OrderTrait {
 //use orml_traits::{GetByKey, parameter_type_with_key};
 use pallet_assets::{AssetDetails, Config};
 use sp_core::serde::{Deserialize, Serialize};
-use sp_runtime::traits::{AtLeast32BitUnsigned, Convert, Zero};
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, Convert, Saturating, Zero};
 use sp_runtime::SaturatedConversion;
 use staging_xcm_executor::Assets;
 use staging_xcm_executor::traits::{ConvertLocation, Error, MatchesFungible, TransactAsset};
@@ -100,6 +103,80 @@ pub trait UnknownAssetTrait {
 	fn withdraw(asset: &MultiAsset, from: &MultiLocation) -> DispatchResult;
 }
 
+// Hook for recording reserve backed deposits
+// Called by the asset transactor once a derived asset has been minted against an inbound reserve transfer
+pub trait OnReserveDeposit<AccountId, CurrencyId, Balance> {
+	/// Record `amount` of `currency_id` reserve deposited for `who`.
+	fn on_reserve_deposit(currency_id: CurrencyId, who: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, CurrencyId, Balance> OnReserveDeposit<AccountId, CurrencyId, Balance> for () {
+	fn on_reserve_deposit(_currency_id: CurrencyId, _who: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
+// Handler for deposits the asset transactor could not make
+// Returning an error lets the executor trap the assets instead of aborting the whole message
+pub trait OnDepositFail<CurrencyId, AccountId, Balance> {
+	/// Deposit of a known `currency_id` to `who` failed with `err`.
+	fn on_deposit_currency_fail(err: DispatchError, currency_id: CurrencyId, who: &AccountId, amount: Balance) -> XcmResult;
+
+	/// Deposit of an unknown `asset` to `location` failed with `err`.
+	fn on_deposit_unknown_asset_fail(err: DispatchError, asset: &MultiAsset, location: &MultiLocation) -> XcmResult;
+}
+
+// Logs the failure, the assets are trapped
+impl<CurrencyId: Debug, AccountId: Debug, Balance: Debug> OnDepositFail<CurrencyId, AccountId, Balance> for () {
+	fn on_deposit_currency_fail(err: DispatchError, currency_id: CurrencyId, who: &AccountId, amount: Balance) -> XcmResult {
+		log::warn!(target: "vane-xcm", "Deposit of {:?} {:?} to {:?} failed: {:?}", amount, currency_id, who, err);
+		Err(XcmError::FailedToTransactAsset(err.into()))
+	}
+
+	fn on_deposit_unknown_asset_fail(err: DispatchError, asset: &MultiAsset, location: &MultiLocation) -> XcmResult {
+		log::warn!(target: "vane-xcm", "Deposit of unknown asset {:?} to {:?} failed: {:?}", asset, location, err);
+		Err(XcmError::FailedToTransactAsset(err.into()))
+	}
+}
+
+// Deposits a failed known currency to the `Alternative` account, unknown assets are trapped
+// The reserve backing the deposit is recorded against the alternative account
+pub struct DepositToAlternative<Alternative, MultiCurrency, ReserveDeposit, AccountId>(
+	PhantomData<(Alternative, MultiCurrency, ReserveDeposit, AccountId)>,
+);
+
+impl<
+	Alternative: Get<AccountId>,
+	MultiCurrency: VaneMultiCurrency<AccountId, CurrencyId = CurrencyId>,
+	ReserveDeposit: OnReserveDeposit<AccountId, CurrencyId, MultiCurrency::Balance>,
+	AccountId: Debug + Clone + Eq,
+	CurrencyId: FullCodec + Eq + PartialEq + Copy + Debug,
+> OnDepositFail<CurrencyId, AccountId, MultiCurrency::Balance>
+for DepositToAlternative<Alternative, MultiCurrency, ReserveDeposit, AccountId>
+{
+	fn on_deposit_currency_fail(err: DispatchError, currency_id: CurrencyId, who: &AccountId, amount: MultiCurrency::Balance) -> XcmResult {
+		let alternative = Alternative::get();
+		log::warn!(
+			target: "vane-xcm",
+			"Deposit of {:?} {:?} to {:?} failed: {:?}, depositing to {:?}",
+			amount, currency_id, who, err, alternative
+		);
+
+		// Depositing to the same account would fail the same way
+		if *who == alternative {
+			return Err(XcmError::FailedToTransactAsset(err.into()))
+		}
+
+		MultiCurrency::deposit(currency_id, &alternative, amount)
+			.and_then(|_| ReserveDeposit::on_reserve_deposit(currency_id, &alternative, amount))
+			.map_err(|e| XcmError::FailedToTransactAsset(e.into()))
+	}
+
+	fn on_deposit_unknown_asset_fail(err: DispatchError, asset: &MultiAsset, location: &MultiLocation) -> XcmResult {
+		<() as OnDepositFail<CurrencyId, AccountId, MultiCurrency::Balance>>::on_deposit_unknown_asset_fail(err, asset, location)
+	}
+}
+
 const NO_UNKNOWN_ASSET_IMPL: &str = "NoUnknownAssetImpl";
 
 impl UnknownAssetTrait for () {
@@ -115,28 +192,37 @@ impl UnknownAssetTrait for () {
 
 
 // Struct for matching Vane custom derived assets
-pub struct VaneDerivedAssets;
+// An asset is accepted only when it comes from the reserve registered for it
+pub struct VaneDerivedAssets<Registry = ()>(PhantomData<Registry>);
 
-impl ContainsPair<CurrencyId, MultiLocation> for VaneDerivedAssets {
+impl<Registry: AssetRegistry> ContainsPair<CurrencyId, MultiLocation> for VaneDerivedAssets<Registry> {
 	fn contains(a: &CurrencyId, b: &MultiLocation) -> bool {
-		// match the MultiAsset && MultiLocation to point to only Polkadot Dot && AssetHub USDT (1987)
-		match b {
-			MultiLocation{parents:1, interior: Here} => {
-				true
-			},
-			// For assetHub
+		Registry::reserve(a).map_or(false, |reserve| reserve == *b)
+	}
+}
+
+impl<Registry: AssetRegistry> ContainsPair<MultiAsset, MultiLocation> for VaneDerivedAssets<Registry> {
+	fn contains(a: &MultiAsset, b: &MultiLocation) -> bool {
+		match a.id {
+			Concrete(ref location) => Registry::currency_id(location)
+				.map_or(false, |currency_id| Self::contains(&currency_id, b)),
 			_ => false
 		}
 	}
 }
 
-impl ContainsPair<MultiAsset, MultiLocation> for VaneDerivedAssets {
+/// Unregistered assets sent from one of `TrustedReserves` and living on that chain. The transactor
+/// holds them in the registry's custody until the asset is registered and its owner claims it.
+pub struct VaneTrustedUnknownAssets<Registry, TrustedReserves>(PhantomData<(Registry, TrustedReserves)>);
+
+impl<Registry: AssetRegistry, TrustedReserves: Contains<MultiLocation>> ContainsPair<MultiAsset, MultiLocation>
+	for VaneTrustedUnknownAssets<Registry, TrustedReserves>
+{
 	fn contains(a: &MultiAsset, b: &MultiLocation) -> bool {
-		match b {
-			MultiLocation{parents:1, interior: Here} => {
-				true
-			},
-			// For assetHub
+		match a.id {
+			Concrete(ref location) => Registry::currency_id(location).is_none()
+				&& TrustedReserves::contains(b)
+				&& location.starts_with(b),
 			_ => false
 		}
 	}
@@ -186,7 +272,98 @@ serde::Deserialize,)]
 pub enum CurrencyId {
 	DOT,
 	USDT,
-	USDC
+	USDC,
+	// Assets added by governance through the asset registry
+	Foreign(u32)
+}
+
+// AssetHub pallet_assets locations of the supported stables
+pub const ASSET_HUB_PARA_ID: u32 = 1000;
+pub const ASSET_HUB_ASSETS_PALLET: u8 = 50;
+pub const USDT_ASSET_ID: u128 = 1984;
+pub const USDC_ASSET_ID: u128 = 1337;
+
+// Built in mapping of the currencies Vane launched with, foreign assets only exist in the registry
+impl CurrencyId {
+	/// Location of the asset as seen from a sibling parachain.
+	pub fn location(&self) -> Option<MultiLocation> {
+		match self {
+			CurrencyId::DOT => Some(MultiLocation::parent()),
+			CurrencyId::USDT => Some(Self::asset_hub_asset(USDT_ASSET_ID)),
+			CurrencyId::USDC => Some(Self::asset_hub_asset(USDC_ASSET_ID)),
+			CurrencyId::Foreign(_) => None,
+		}
+	}
+
+	/// Chain holding the reserve of the asset.
+	pub fn reserve(&self) -> Option<MultiLocation> {
+		match self {
+			CurrencyId::DOT => Some(MultiLocation::parent()),
+			CurrencyId::USDT | CurrencyId::USDC => Some(MultiLocation::new(1, X1(Parachain(ASSET_HUB_PARA_ID)))),
+			CurrencyId::Foreign(_) => None,
+		}
+	}
+
+	pub fn from_location(location: &MultiLocation) -> Option<Self> {
+		match location {
+			MultiLocation { parents: 1, interior: Here } => Some(CurrencyId::DOT),
+			MultiLocation {
+				parents: 1,
+				interior: X3(Parachain(ASSET_HUB_PARA_ID), PalletInstance(ASSET_HUB_ASSETS_PALLET), GeneralIndex(index)),
+			} => match *index {
+				USDT_ASSET_ID => Some(CurrencyId::USDT),
+				USDC_ASSET_ID => Some(CurrencyId::USDC),
+				_ => None
+			},
+			_ => None
+		}
+	}
+
+	fn asset_hub_asset(index: u128) -> MultiLocation {
+		MultiLocation::new(
+			1,
+			X3(Parachain(ASSET_HUB_PARA_ID), PalletInstance(ASSET_HUB_ASSETS_PALLET), GeneralIndex(index))
+		)
+	}
+}
+
+
+// Registered currencies and their XCM locations
+// Disabled assets are reported as unknown
+pub trait AssetRegistry {
+	/// Location of an enabled `currency_id`.
+	fn location(currency_id: &CurrencyId) -> Option<MultiLocation>;
+
+	/// Enabled currency registered at `location`.
+	fn currency_id(location: &MultiLocation) -> Option<CurrencyId>;
+
+	/// Chain holding the reserve of an enabled `currency_id`.
+	fn reserve(currency_id: &CurrencyId) -> Option<MultiLocation>;
+
+	/// Existential deposit of a registered `currency_id`, disabled or not.
+	fn existential_deposit(currency_id: &CurrencyId) -> Option<u128>;
+}
+
+// No registry, only the built in currencies are known
+impl AssetRegistry for () {
+	fn location(currency_id: &CurrencyId) -> Option<MultiLocation> {
+		currency_id.location()
+	}
+
+	fn currency_id(location: &MultiLocation) -> Option<CurrencyId> {
+		CurrencyId::from_location(location)
+	}
+
+	fn reserve(currency_id: &CurrencyId) -> Option<MultiLocation> {
+		currency_id.reserve()
+	}
+
+	fn existential_deposit(currency_id: &CurrencyId) -> Option<u128> {
+		match currency_id {
+			CurrencyId::Foreign(_) => None,
+			_ => Some(ExistentialDeposits::get(currency_id))
+		}
+	}
 }
 
 
@@ -198,7 +375,8 @@ pub struct VaneMultiCurrencyAdapter<
 	AccountIdConvert,
 	CurrencyId,
 	CurrencyIdConvert,
-	//DepositFailureHandler,
+	ReserveDeposit,
+	DepositFailureHandler,
 >(
 	PhantomData<(
 		MultiCurrency,
@@ -208,7 +386,8 @@ pub struct VaneMultiCurrencyAdapter<
 		AccountIdConvert,
 		CurrencyId,
 		CurrencyIdConvert,
-		//DepositFailureHandler,
+		ReserveDeposit,
+		DepositFailureHandler,
 	)>,
 );
 
@@ -220,7 +399,8 @@ impl<
 	AccountIdConvert: ConvertLocation<AccountId>,
 	CurrencyId: FullCodec + Eq + PartialEq + Copy + Debug,
 	CurrencyIdConvert: Convert<MultiAsset, Option<CurrencyId>>,
-	//DepositFailureHandler: ,
+	ReserveDeposit: OnReserveDeposit<AccountId, CurrencyId, MultiCurrency::Balance>,
+	DepositFailureHandler: OnDepositFail<CurrencyId, AccountId, MultiCurrency::Balance>,
 > TransactAsset
 for VaneMultiCurrencyAdapter<
 	MultiCurrency,
@@ -230,7 +410,8 @@ for VaneMultiCurrencyAdapter<
 	AccountIdConvert,
 	CurrencyId,
 	CurrencyIdConvert,
-	//DepositFailureHandler,
+	ReserveDeposit,
+	DepositFailureHandler,
 >
 {
 	fn deposit_asset(asset: &MultiAsset, location: &MultiLocation, _context: &XcmContext) -> staging_xcm::v3::Result {
@@ -240,9 +421,17 @@ for VaneMultiCurrencyAdapter<
 			Match::matches_fungible(asset),
 		) {
 			// known asset
-			(Some(who), Some(currency_id), Some(amount)) => Ok(MultiCurrency::deposit(currency_id, &who, amount).unwrap()),// DepositFailAsset handler
+			(Some(who), Some(currency_id), Some(amount)) => {
+				match MultiCurrency::deposit(currency_id, &who, amount) {
+					// Derived asset is now backed by the reserve, let the escrow pallet account for it
+					Ok(()) => ReserveDeposit::on_reserve_deposit(currency_id, &who, amount)
+						.map_err(|e| XcmError::FailedToTransactAsset(e.into())),
+					Err(e) => DepositFailureHandler::on_deposit_currency_fail(e, currency_id, &who, amount),
+				}
+			},
 			// unknown asset
-			_ => Ok(UnknownAsset::deposit(asset, location).unwrap()), // DepositFailAsset handler
+			_ => UnknownAsset::deposit(asset, location)
+				.or_else(|e| DepositFailureHandler::on_deposit_unknown_asset_fail(e, asset, location)),
 		}
 	}
 
@@ -356,27 +545,109 @@ pub trait VaneMultiCurrency<AccountId> {
 	fn slash(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> Self::Balance;
 }
 
+// Reservable balances, used by escrow and punishment logic
+pub trait VaneMultiReservableCurrency<AccountId>: VaneMultiCurrency<AccountId> {
+	/// Same result as `reserve(currency_id, who, value)` (but without the
+	/// side-effects) assuming there are no balance changes in the meantime.
+	fn can_reserve(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> bool;
+
+	/// Deduct the reserved balance of `who` by up to `value`, returning the
+	/// part which could not be slashed.
+	fn slash_reserved(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> Self::Balance;
+
+	/// The amount of `currency_id` reserved for `who`.
+	fn reserved_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance;
+
+	/// Move `value` from the free balance of `who` to its reserved balance.
+	fn reserve(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> DispatchResult;
+
+	/// Move up to `value` back to the free balance of `who`, returning the
+	/// part which could not be unreserved.
+	fn unreserve(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> Self::Balance;
+
+	/// Move up to `value` reserved by `slashed` to `beneficiary`, into its free
+	/// or reserved balance depending on `status`. Returns the part which could
+	/// not be moved.
+	fn repatriate_reserved(
+		currency_id: Self::CurrencyId,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError>;
+}
+
 
 
 parameter_type_with_key! {
 		pub ExistentialDeposits: |currency_id: CurrencyId| -> u128 {
 			match currency_id {
 				CurrencyId::DOT => 1_000_000_000, // DOT
-
-				_ => { unimplemented!()}
+				// AssetHub min balances, both with 6 decimals
+				CurrencyId::USDT => 700, // 0.0007 USDT
+				CurrencyId::USDC => 70_000, // 0.07 USDC
+				// Unregistered foreign assets cannot be held
+				CurrencyId::Foreign(_) => u128::MAX,
 			}
 		};
 }
 
 
-pub struct MultiCurrencyAsset<T: frame_system::Config>(PhantomData<T>);
+// `Reason` is the hold reason of the escrow holds, see `VaneHolds`
+pub struct MultiCurrencyAsset<T: frame_system::Config, Registry = (), Reason = ()>(PhantomData<(T, Registry, Reason)>);
+
+// pallet_assets has no holds, reserved funds are moved to this account and tracked in `VaneReserves`
+pub const RESERVE_POT_ID: PalletId = PalletId(*b"vane/rsv");
+
+// Reserved balances of `MultiCurrencyAsset`
+// Keys ----> (asset_id, account)
+#[frame_support::storage_alias]
+pub type VaneReserves<T: pallet_assets::Config> = StorageDoubleMap<
+	VaneMultiCurrencyAsset,
+	Blake2_128Concat,
+	<T as pallet_assets::Config>::AssetId,
+	Blake2_128Concat,
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_assets::Config>::Balance,
+	ValueQuery,
+>;
+
+// Balances held by `MultiCurrencyAsset`, pallet_assets has no holds either
+// Keys ----> (asset_id, account), encoded hold reason
+#[frame_support::storage_alias]
+pub type VaneHolds<T: pallet_assets::Config> = StorageDoubleMap<
+	VaneMultiCurrencyAsset,
+	Blake2_128Concat,
+	(<T as pallet_assets::Config>::AssetId, <T as frame_system::Config>::AccountId),
+	Blake2_128Concat,
+	sp_std::vec::Vec<u8>,
+	<T as pallet_assets::Config>::Balance,
+	ValueQuery,
+>;
+
+impl<T: pallet_assets::Config, Registry, Reason> MultiCurrencyAsset<T, Registry, Reason> {
+	pub fn reserve_pot() -> T::AccountId {
+		RESERVE_POT_ID.into_account_truncating()
+	}
 
-impl<T: pallet_assets::Config > VaneMultiCurrency<T::AccountId> for MultiCurrencyAsset<T>{
+	// Sum of the holds of every reason
+	pub fn held_balance(asset_id: T::AssetId, who: &T::AccountId) -> T::Balance {
+		VaneHolds::<T>::iter_prefix_values((asset_id, who.clone()))
+			.fold(Zero::zero(), |total: T::Balance, held| total.saturating_add(held))
+	}
+
+	// Moves assets out of or into the reserve pot
+	fn pot_transfer(asset_id: T::AssetId, from: &T::AccountId, to: &T::AccountId, amount: T::Balance) -> Result<T::Balance, DispatchError> {
+		<pallet_assets::Pallet<T> as Mutate<T::AccountId>>::transfer(asset_id, from, to, amount, Preservation::Expendable)
+	}
+}
+
+impl<T: pallet_assets::Config, Registry: AssetRegistry, Reason> VaneMultiCurrency<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason>{
 	type CurrencyId = T::AssetIdParameter;
 	type Balance = T::Balance;
 
 	fn minimum_balance(currency_id: CurrencyId) -> u128 {
-		ExistentialDeposits::get(&currency_id)
+		Registry::existential_deposit(&currency_id).unwrap_or_else(|| ExistentialDeposits::get(&currency_id))
 	}
 
 
@@ -385,7 +656,10 @@ impl<T: pallet_assets::Config > VaneMultiCurrency<T::AccountId> for MultiCurrenc
 	}
 
 	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> T::Balance {
-		<pallet_assets::Pallet<T>>::total_balance(currency_id.into(),who)
+		let asset_id: T::AssetId = currency_id.into();
+		<pallet_assets::Pallet<T>>::total_balance(asset_id.clone(),who)
+			.saturating_add(VaneReserves::<T>::get(asset_id.clone(), who))
+			.saturating_add(Self::held_balance(asset_id, who))
 	}
 
 	fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> T::Balance {
@@ -440,40 +714,251 @@ impl<T: pallet_assets::Config > VaneMultiCurrency<T::AccountId> for MultiCurrenc
 		<pallet_assets::Pallet<T>>::balance(currency_id.into(), who) >= value
 	}
 
+	// Free balance is slashed first, then the reserved balance
 	fn slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> T::Balance {
-		todo!() // later on
+		if amount.is_zero() {
+			return amount;
+		}
+
+		let slashed = <pallet_assets::Pallet<T>>::withdraw(
+			currency_id.clone().into(),
+			who,
+			amount,
+			Precision::BestEffort,
+			Preservation::Expendable,
+			Fortitude::Force
+		).map(|credit| credit.peek()).unwrap_or_default();
+
+		let remaining = amount.saturating_sub(slashed);
+		if remaining.is_zero() {
+			return remaining;
+		}
+
+		Self::slash_reserved(currency_id, who, remaining)
+	}
+}
+
+impl<T: pallet_assets::Config, Registry: AssetRegistry, Reason> VaneMultiReservableCurrency<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {
+	fn can_reserve(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> bool {
+		if value.is_zero() {
+			return true;
+		}
+		<pallet_assets::Pallet<T>>::reducible_balance(currency_id.into(), who, Preservation::Expendable, Fortitude::Polite) >= value
+	}
+
+	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		let asset_id: T::AssetId = currency_id.into();
+		let actual = VaneReserves::<T>::get(asset_id.clone(), who).min(value);
+		if actual.is_zero() {
+			return value;
+		}
+
+		let slashed = <pallet_assets::Pallet<T>>::withdraw(
+			asset_id.clone(),
+			&Self::reserve_pot(),
+			actual,
+			Precision::BestEffort,
+			Preservation::Expendable,
+			Fortitude::Force
+		).map(|credit| credit.peek()).unwrap_or_default();
+
+		VaneReserves::<T>::mutate(asset_id, who, |reserved| *reserved = reserved.saturating_sub(slashed));
+
+		value.saturating_sub(slashed)
+	}
+
+	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
+		VaneReserves::<T>::get(currency_id.into(), who)
+	}
+
+	fn reserve(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		if value.is_zero() {
+			return Ok(());
+		}
+
+		let asset_id: T::AssetId = currency_id.into();
+		Self::pot_transfer(asset_id.clone(), who, &Self::reserve_pot(), value)?;
+		VaneReserves::<T>::mutate(asset_id, who, |reserved| *reserved = reserved.saturating_add(value));
+
+		Ok(())
+	}
+
+	fn unreserve(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		let asset_id: T::AssetId = currency_id.into();
+		let actual = VaneReserves::<T>::get(asset_id.clone(), who).min(value);
+		if actual.is_zero() {
+			return value;
+		}
+
+		// Nothing is unreserved when the funds cannot be moved back, e.g. below the min balance
+		if Self::pot_transfer(asset_id.clone(), &Self::reserve_pot(), who, actual).is_err() {
+			return value;
+		}
+		VaneReserves::<T>::mutate(asset_id, who, |reserved| *reserved = reserved.saturating_sub(actual));
+
+		value.saturating_sub(actual)
+	}
+
+	fn repatriate_reserved(
+		currency_id: Self::CurrencyId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		if slashed == beneficiary {
+			return match status {
+				BalanceStatus::Free => Ok(Self::unreserve(currency_id, slashed, value)),
+				BalanceStatus::Reserved => Ok(value.saturating_sub(Self::reserved_balance(currency_id, slashed))),
+			};
+		}
+
+		let asset_id: T::AssetId = currency_id.into();
+		let actual = VaneReserves::<T>::get(asset_id.clone(), slashed).min(value);
+		if actual.is_zero() {
+			return Ok(value);
+		}
+
+		match status {
+			BalanceStatus::Free => {
+				Self::pot_transfer(asset_id.clone(), &Self::reserve_pot(), beneficiary, actual)?;
+			},
+			// Funds stay in the pot, only the ledger changes hands
+			BalanceStatus::Reserved => {
+				VaneReserves::<T>::mutate(asset_id.clone(), beneficiary, |reserved| *reserved = reserved.saturating_add(actual));
+			},
+		}
+		VaneReserves::<T>::mutate(asset_id, slashed, |reserved| *reserved = reserved.saturating_sub(actual));
+
+		Ok(value.saturating_sub(actual))
 	}
 }
 
+// Escrow holds through the fungibles traits.
+// Held funds leave the pallet_assets balance but stay in the issuance, `VaneHolds` keeps them on the holder
+impl<T: pallet_assets::Config, Registry, Reason> Inspect<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {
+	type AssetId = T::AssetId;
+	type Balance = T::Balance;
+
+	fn total_issuance(asset: T::AssetId) -> T::Balance {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::total_issuance(asset)
+	}
+
+	fn minimum_balance(asset: T::AssetId) -> T::Balance {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::minimum_balance(asset)
+	}
+
+	fn total_balance(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::total_balance(asset.clone(), who)
+			.saturating_add(Self::held_balance(asset, who))
+	}
+
+	fn balance(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::balance(asset, who)
+	}
+
+	fn reducible_balance(asset: T::AssetId, who: &T::AccountId, preservation: Preservation, force: Fortitude) -> T::Balance {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::reducible_balance(asset, who, preservation, force)
+	}
+
+	fn can_deposit(asset: T::AssetId, who: &T::AccountId, amount: T::Balance, provenance: Provenance) -> DepositConsequence {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::can_deposit(asset, who, amount, provenance)
+	}
+
+	fn can_withdraw(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> WithdrawConsequence<T::Balance> {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::can_withdraw(asset, who, amount)
+	}
+
+	fn asset_exists(asset: T::AssetId) -> bool {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::asset_exists(asset)
+	}
+}
+
+// pallet_assets only implements the balance changes, the issuance is left untouched by them
+impl<T: pallet_assets::Config, Registry, Reason> Unbalanced<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {
+	fn handle_dust(dust: Dust<T::AccountId, Self>) {
+		<pallet_assets::Pallet<T> as Unbalanced<T::AccountId>>::handle_dust(Dust(dust.0, dust.1))
+	}
+
+	fn write_balance(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> Result<Option<T::Balance>, DispatchError> {
+		<pallet_assets::Pallet<T> as Unbalanced<T::AccountId>>::write_balance(asset, who, amount)
+	}
+
+	fn set_total_issuance(asset: T::AssetId, amount: T::Balance) {
+		<pallet_assets::Pallet<T> as Unbalanced<T::AccountId>>::set_total_issuance(asset, amount)
+	}
+
+	fn decrease_balance(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		precision: Precision,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Result<T::Balance, DispatchError> {
+		<pallet_assets::Pallet<T> as Unbalanced<T::AccountId>>::decrease_balance(asset, who, amount, precision, preservation, force)
+	}
+
+	fn increase_balance(asset: T::AssetId, who: &T::AccountId, amount: T::Balance, precision: Precision) -> Result<T::Balance, DispatchError> {
+		<pallet_assets::Pallet<T> as Unbalanced<T::AccountId>>::increase_balance(asset, who, amount, precision)
+	}
+}
+
+impl<T: pallet_assets::Config, Registry, Reason: Encode + TypeInfo + 'static> InspectHold<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {
+	type Reason = Reason;
+
+	fn total_balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Self::held_balance(asset, who)
+	}
+
+	fn reducible_total_balance_on_hold(asset: T::AssetId, who: &T::AccountId, _force: Fortitude) -> T::Balance {
+		Self::held_balance(asset, who)
+	}
+
+	fn balance_on_hold(asset: T::AssetId, reason: &Reason, who: &T::AccountId) -> T::Balance {
+		VaneHolds::<T>::get((asset, who.clone()), reason.encode())
+	}
+
+	// The ledger has no per account limit
+	fn hold_available(asset: T::AssetId, _reason: &Reason, _who: &T::AccountId) -> bool {
+		<pallet_assets::Pallet<T> as Inspect<T::AccountId>>::asset_exists(asset)
+	}
+}
+
+impl<T: pallet_assets::Config, Registry, Reason: Encode + TypeInfo + 'static> UnbalancedHold<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {
+	fn set_balance_on_hold(asset: T::AssetId, reason: &Reason, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if amount.is_zero() {
+			VaneHolds::<T>::remove((asset, who.clone()), reason.encode());
+		} else {
+			VaneHolds::<T>::insert((asset, who.clone()), reason.encode(), amount);
+		}
+		Ok(())
+	}
+}
+
+impl<T: pallet_assets::Config, Registry, Reason: Encode + TypeInfo + 'static> MutateHold<T::AccountId> for MultiCurrencyAsset<T, Registry, Reason> {}
+
 
 // MultiCurrency Converter
-pub struct MultiCurrencyConverter<T: Config>(PhantomData<T>);
+pub struct MultiCurrencyConverter<T: Config, Registry = ()>(PhantomData<(T, Registry)>);
 
-impl<T: Config> Convert<MultiAsset, Option<CurrencyId>> for MultiCurrencyConverter<T>{
+impl<T: Config, Registry: AssetRegistry> Convert<MultiAsset, Option<CurrencyId>> for MultiCurrencyConverter<T, Registry>{
 	fn convert(asset: MultiAsset) -> Option<CurrencyId> {
 		match asset.id {
-			Concrete(MultiLocation{parents:1,interior:Here}) => Some(CurrencyId::DOT),
+			Concrete(location) => Registry::currency_id(&location),
 			_ => {None}
 		}
 	}
 }
 
-impl<T: Config> Convert<MultiLocation, Option<CurrencyId>> for MultiCurrencyConverter<T>{
+impl<T: Config, Registry: AssetRegistry> Convert<MultiLocation, Option<CurrencyId>> for MultiCurrencyConverter<T, Registry>{
 	fn convert(location: MultiLocation) -> Option<CurrencyId> {
-		if location == MultiLocation::parent() {
-			return Some(CurrencyId::DOT)
-		}else{
-			None
-		}
+		Registry::currency_id(&location)
 	}
 }
 
-impl<T: Config> Convert<CurrencyId, Option<MultiLocation>> for MultiCurrencyConverter<T> {
+impl<T: Config, Registry: AssetRegistry> Convert<CurrencyId, Option<MultiLocation>> for MultiCurrencyConverter<T, Registry> {
 	fn convert(asset: CurrencyId) -> Option<MultiLocation> {
-		if asset == CurrencyId::DOT{
-			Some(MultiLocation::parent())
-		} else {
-			None
-		}
+		Registry::location(&asset)
 	}
 }
@@ -137,13 +137,23 @@ generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	vane_xcm_transfer_system::migrations::v1::RekeyPayerReceipts<Runtime>,
+	vane_xcm_transfer_system::migrations::v1::MigrateToV1<Runtime>,
+	vane_xcm_transfer_system::migrations::v2::MigrateToV2<Runtime>,
+	vane_xcm_transfer_system::migrations::v3::MigrateToV3<Runtime>,
+	vane_asset_registry::migrations::v1::RegisterBuiltInAssets<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
 	Block,
 	frame_system::ChainContext<Runtime>,
 	Runtime,
-	AllPalletsWithSystem
+	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -173,6 +183,14 @@ impl WeightToFeePolynomial for WeightToFee {
 	}
 }
 
+/// Relay chain fee for executing Vane release messages, charged in DOT.
+/// The relay's own `WeightToFee`, from `polkadot-runtime-constants`.
+pub type RelayDotWeightToFee = polkadot_runtime_constants::fee::WeightToFee;
+
+/// AssetHub fee for executing Vane release messages, in DOT. The one AssetHub Polkadot
+/// charges, from `parachains-common`. USDT and USDC pay it scaled by their min balance.
+pub type AssetHubDotWeightToFee = parachains_common::polkadot::fee::WeightToFee;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -201,7 +219,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("vane-network"),
 	impl_name: create_runtime_str!("vane-network"),
 	authoring_version: 1,
-	spec_version: 400,
+	spec_version: 404,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -459,7 +477,7 @@ impl pallet_assets::Config for Runtime {
 	type AssetIdParameter = CurrencyId;
 	type Currency = Balances;
 	type CreateOrigin = VaneForeignCreators<
-		VaneDerivedAssets,
+		VaneDerivedAssets<VaneAssetRegistry>,
 		ForeignCreatorsSovereignAccountOf,
 		AccountId,
 	>;
@@ -528,8 +546,57 @@ impl pallet_author_inherent::Config for Runtime {
 }
 
 // VANE PALLETS
+parameter_types! {
+	pub const VaneXcmQueryTimeout: BlockNumber = 10 * MINUTES;
+	// Per instruction bounds of a release message on its reserve chain. The relay and AssetHub weigh
+	// XCM with their `pallet_xcm_benchmarks` weights, every instruction of a release message is below
+	// these, the over-quoted fee is given back to the beneficiary by `RefundSurplus`
+	pub VaneRelayReleaseUnitWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	pub VaneAssetHubReleaseUnitWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	// AssetHub Polkadot existential deposit, the base its sufficient assets are charged against
+	pub const VaneAssetHubExistentialDeposit: Balance = parachains_common::polkadot::currency::EXISTENTIAL_DEPOSIT;
+	pub const VaneConfirmationWindow: BlockNumber = 7 * DAYS;
+	pub const VaneMaxConfirmationWindow: BlockNumber = 30 * DAYS;
+	pub const VaneMaxExpiriesPerBlock: u32 = 50;
+	pub const VaneMaxPayeeReceipts: u32 = 512;
+	pub const VaneMaxAccountTxns: u32 = 100;
+}
+
 impl vane_xcm_transfer_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type XcmQueryTimeout = VaneXcmQueryTimeout;
+	type RelayReleaseWeigher = FixedWeightBounds<VaneRelayReleaseUnitWeight, RuntimeCall, xcm_config::MaxInstructions>;
+	type AssetHubReleaseWeigher = FixedWeightBounds<VaneAssetHubReleaseUnitWeight, RuntimeCall, xcm_config::MaxInstructions>;
+	type RelayWeightToFee = RelayDotWeightToFee;
+	type AssetHubWeightToFee = AssetHubDotWeightToFee;
+	type AssetHubExistentialDeposit = VaneAssetHubExistentialDeposit;
+	type ConfirmationWindow = VaneConfirmationWindow;
+	type MaxConfirmationWindow = VaneMaxConfirmationWindow;
+	type MaxExpiriesPerBlock = VaneMaxExpiriesPerBlock;
+	type MaxPayeeReceipts = VaneMaxPayeeReceipts;
+	type MaxAccountTxns = VaneMaxAccountTxns;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Escrow = vane_primitive::MultiCurrencyAsset<Runtime, VaneAssetRegistry, RuntimeHoldReason>;
+	type OnEscrowOpened = ();
+	type OnConfirmed = ();
+	type OnReleased = ();
+	type OnReverted = ();
+	type WeightInfo = weights::vane_xcm_transfer_system_weights::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const VaneAssetStringLimit: u32 = 32;
+}
+
+impl vane_asset_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type StringLimit = VaneAssetStringLimit;
+	type LocationToAccountId = xcm_config::LocationToAccountId;
+	type AssetTransactor = xcm_config::LocalAssetTransactor;
+	type WeightInfo = weights::vane_asset_registry_weights::WeightInfo<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -561,7 +628,8 @@ construct_runtime!(
         PolkadotXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>} = 73,
 
 		// VANE
-		VaneXcmTransfer: vane_xcm_transfer_system = 100
+		VaneXcmTransfer: vane_xcm_transfer_system = 100,
+		VaneAssetRegistry: vane_asset_registry = 101
 
     }
 );
@@ -650,6 +718,31 @@ impl_runtime_apis! {
         }
     }
 
+    impl vane_xcm_transfer_system_runtime_api::VaneApi<Block, AccountId> for Runtime {
+        fn payer_receipts(payer: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+            VaneXcmTransfer::payer_receipts(payer)
+        }
+        fn payee_receipts(payee: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+            VaneXcmTransfer::payee_receipts(payee)
+        }
+        fn pending_confirmations(account: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+            VaneXcmTransfer::pending_confirmations(account)
+        }
+        fn escrow_balance(reference_no: Vec<u8>) -> Option<u128> {
+            VaneXcmTransfer::escrow_balance(reference_no)
+        }
+        fn confirmation_state(reference_no: Vec<u8>) -> Option<vane_xcm_transfer_system_runtime_api::ConfirmationState> {
+            VaneXcmTransfer::confirmation_state(reference_no)
+        }
+        fn quote_release(
+            payee: AccountId,
+            currency: vane_xcm_transfer_system_runtime_api::Token,
+            amount: u128,
+        ) -> Option<vane_xcm_transfer_system_runtime_api::FeeQuote> {
+            VaneXcmTransfer::quote_payment(&payee, currency, amount).ok()
+        }
+    }
+
     impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
         fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
             ParachainSystem::collect_collation_info(header)
@@ -678,6 +771,8 @@ impl_runtime_apis! {
                 pallet_cc_authorities_noting,
                 PalletAuthoritiesNotingBench::<Runtime>
             );
+            list_benchmark!(list, extra, vane_xcm_transfer_system, VaneXcmTransfer);
+            list_benchmark!(list, extra, vane_asset_registry, VaneAssetRegistry);
 
             let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -731,6 +826,8 @@ impl_runtime_apis! {
                 pallet_cc_authorities_noting,
                 PalletAuthoritiesNotingBench::<Runtime>
             );
+            add_benchmark!(params, batches, vane_xcm_transfer_system, VaneXcmTransfer);
+            add_benchmark!(params, batches, vane_asset_registry, VaneAssetRegistry);
             if batches.is_empty() {
                 return Err("Benchmark not found for this pallet.".into());
             }
@@ -21,7 +21,9 @@ pub mod block_weights;
 pub mod extrinsic_weights;
 pub mod paritydb_weights;
 pub mod rocksdb_weights;
+pub mod vane_asset_registry_weights;
 pub mod vane_asset_weights;
+pub mod vane_xcm_transfer_system_weights;
 pub use block_weights::constants::BlockExecutionWeight;
 pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
 pub use paritydb_weights::constants::ParityDbWeight;
@@ -0,0 +1,59 @@
+//! Weights for `vane_asset_registry`
+//!
+//! Hand-written estimates, not yet generated by `scripts/benchmark.sh`.
+//! Run it on reference hardware to replace this file.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `vane_asset_registry`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> vane_asset_registry::WeightInfo for WeightInfo<T> {
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:1)
+	fn register_asset() -> Weight {
+		Weight::from_parts(24_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:2)
+	fn update_asset() -> Weight {
+		Weight::from_parts(27_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: VaneAssetRegistry Assets (r:1 w:1)
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(16_000_000, 3_700)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:0)
+	/// Storage: VaneAssetRegistry Assets (r:1 w:0)
+	/// Storage: VaneAssetRegistry UnknownAssets (r:1 w:1)
+	/// Storage: VaneAssets Asset (r:1 w:1)
+	/// Storage: VaneAssets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	fn claim_unknown_asset() -> Weight {
+		Weight::from_parts(58_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: VaneAssetRegistry LocationToCurrency (r:1 w:0)
+	/// Storage: VaneAssetRegistry Assets (r:1 w:0)
+	/// Storage: VaneAssetRegistry UnknownAssets (r:1 w:1)
+	/// Storage: VaneAssets Asset (r:1 w:1)
+	/// Storage: VaneAssets Account (r:1 w:1)
+	/// Storage: VaneXcmTransfer ReserveBalance (r:1 w:1)
+	fn release_unknown_asset() -> Weight {
+		Weight::from_parts(56_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
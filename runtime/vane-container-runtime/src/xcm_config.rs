@@ -1,11 +1,13 @@
 use sp_std::marker::PhantomData;
 use super::{
 	AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, VaneAssetRegistry, VaneXcmTransfer, WeightToFee,
+	XcmpQueue,
 };
 use frame_support::{
 	match_types, parameter_types,
 	traits::{ConstU32, Everything, Nothing},
+	PalletId,
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -15,9 +17,9 @@ use polkadot_runtime_common::impls::ToAuthor;
 use staging_xcm::latest::prelude::*;
 use staging_xcm_builder::{AccountId32Aliases, AliasForeignAccountId32, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, CurrencyAdapter, DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds, IsConcrete, NativeAsset, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic};
 use staging_xcm_executor::XcmExecutor;
-use vane_primitive::{CurrencyId, MultiCurrencyAsset, MultiCurrencyConverter};
+use vane_primitive::{CurrencyId, DepositToAlternative, MultiCurrencyAsset, MultiCurrencyConverter};
 //use orml_xcm_support::IsNativeConcrete;
-use sp_runtime::traits::{CheckedConversion, Convert};
+use sp_runtime::traits::{AccountIdConversion, CheckedConversion, Convert};
 use staging_xcm_executor::traits::MatchesFungible;
 
 
@@ -26,6 +28,8 @@ parameter_types! {
 	pub const RelayNetwork: Option<NetworkId> = None;
 	pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
 	pub UniversalLocation: InteriorMultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+	// Receives inbound deposits which could not be credited to their beneficiary, recoverable by root
+	pub VaneDepositFallback: AccountId = PalletId(*b"vane/dfl").into_account_truncating();
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -58,14 +62,20 @@ impl<CurrencyId, CurrencyIdConvert, Amount> MatchesFungible<Amount> for IsNative
 
 /// Means for transacting assets on this chain.
 pub type LocalAssetTransactor =  vane_primitive::VaneMultiCurrencyAdapter<
-	MultiCurrencyAsset<Runtime>,
-	(), // handler for unknown assets
-	IsNativeConcrete<CurrencyId, MultiCurrencyConverter<Runtime>>,
+	MultiCurrencyAsset<Runtime, VaneAssetRegistry>,
+	VaneAssetRegistry, // holds unknown assets until they are registered
+	IsNativeConcrete<CurrencyId, MultiCurrencyConverter<Runtime, VaneAssetRegistry>>,
 	AccountId,
 	LocationToAccountId,
 	CurrencyId,
-	MultiCurrencyConverter<Runtime>,
-	// HandlingFailedDeposits
+	MultiCurrencyConverter<Runtime, VaneAssetRegistry>,
+	VaneXcmTransfer, // records reserve backed deposits
+	DepositToAlternative<
+		VaneDepositFallback,
+		MultiCurrencyAsset<Runtime, VaneAssetRegistry>,
+		VaneXcmTransfer,
+		AccountId,
+	>, // handler for failed deposits
 >;
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
@@ -103,6 +113,14 @@ match_types! {
 	};
 }
 
+match_types! {
+	// Reserves whose unregistered assets are held in the asset registry's custody
+	pub type TrustedReserves: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: Here } |
+		MultiLocation { parents: 1, interior: X1(Parachain(vane_primitive::ASSET_HUB_PARA_ID)) }
+	};
+}
+
 // pub type Barrier = TrailingSetTopicAsId<
 // 	DenyThenTry<
 // 		DenyReserveTransferToRelayChain,
@@ -150,7 +168,11 @@ impl staging_xcm_executor::Config for XcmConfig {
 	// How to withdraw and deposit an asset.
 	type AssetTransactor = LocalAssetTransactor;
 	type OriginConverter = XcmOriginToCallOrigin;
-	type IsReserve = vane_primitive::VaneDerivedAssets; // Custom Asset matcher
+	type IsReserve = (
+		// Reserves registered in the asset registry
+		vane_primitive::VaneDerivedAssets<VaneAssetRegistry>,
+		vane_primitive::VaneTrustedUnknownAssets<VaneAssetRegistry, TrustedReserves>,
+	);
 	type IsTeleporter = ();
 	type Aliasers = AliasForeignAccountId32<ParentPrefix>;
 	// Teleporting is disabled.
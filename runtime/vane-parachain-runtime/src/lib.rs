@@ -129,6 +129,15 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	vane_xcm_transfer_system::migrations::v1::RekeyPayerReceipts<Runtime>,
+	vane_xcm_transfer_system::migrations::v1::MigrateToV1<Runtime>,
+	vane_xcm_transfer_system::migrations::v2::MigrateToV2<Runtime>,
+	vane_xcm_transfer_system::migrations::v3::MigrateToV3<Runtime>,
+	vane_asset_registry::migrations::v1::RegisterBuiltInAssets<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -136,6 +145,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 pub mod fee {
@@ -211,6 +221,14 @@ pub mod fee {
 			}]
 		}
 	}
+
+	/// Relay chain fee for executing Vane release messages, charged in DOT.
+	/// The relay's own `WeightToFee`, from `polkadot-runtime-constants`.
+	pub type RelayDotWeightToFee = polkadot_runtime_constants::fee::WeightToFee;
+
+	/// AssetHub fee for executing Vane release messages, in DOT. The one AssetHub Polkadot
+	/// charges, from `parachains-common`. USDT and USDC pay it scaled by their min balance.
+	pub type AssetHubDotWeightToFee = parachains_common::polkadot::fee::WeightToFee;
 }
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
@@ -240,7 +258,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("vane-parachain"),
 	impl_name: create_runtime_str!("vane-parachain"),
 	authoring_version: 1,
-	spec_version: 1000,
+	spec_version: 1004,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -561,8 +579,57 @@ impl pallet_collator_selection::Config for Runtime {
 // Vane pallets Implementation
 
 
+parameter_types! {
+	pub const VaneXcmQueryTimeout: BlockNumber = 10 * MINUTES;
+	// Per instruction bounds of a release message on its reserve chain. The relay and AssetHub weigh
+	// XCM with their `pallet_xcm_benchmarks` weights, every instruction of a release message is below
+	// these, the over-quoted fee is given back to the beneficiary by `RefundSurplus`
+	pub VaneRelayReleaseUnitWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	pub VaneAssetHubReleaseUnitWeight: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	// AssetHub Polkadot existential deposit, the base its sufficient assets are charged against
+	pub const VaneAssetHubExistentialDeposit: Balance = parachains_common::polkadot::currency::EXISTENTIAL_DEPOSIT;
+	pub const VaneConfirmationWindow: BlockNumber = 7 * DAYS;
+	pub const VaneMaxConfirmationWindow: BlockNumber = 30 * DAYS;
+	pub const VaneMaxExpiriesPerBlock: u32 = 50;
+	pub const VaneMaxPayeeReceipts: u32 = 512;
+	pub const VaneMaxAccountTxns: u32 = 100;
+}
+
 impl vane_xcm_transfer_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type XcmQueryTimeout = VaneXcmQueryTimeout;
+	type RelayReleaseWeigher = FixedWeightBounds<VaneRelayReleaseUnitWeight, RuntimeCall, MaxInstructions>;
+	type AssetHubReleaseWeigher = FixedWeightBounds<VaneAssetHubReleaseUnitWeight, RuntimeCall, MaxInstructions>;
+	type RelayWeightToFee = fee::RelayDotWeightToFee;
+	type AssetHubWeightToFee = fee::AssetHubDotWeightToFee;
+	type AssetHubExistentialDeposit = VaneAssetHubExistentialDeposit;
+	type ConfirmationWindow = VaneConfirmationWindow;
+	type MaxConfirmationWindow = VaneMaxConfirmationWindow;
+	type MaxExpiriesPerBlock = VaneMaxExpiriesPerBlock;
+	type MaxPayeeReceipts = VaneMaxPayeeReceipts;
+	type MaxAccountTxns = VaneMaxAccountTxns;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Escrow = vane_primitive::MultiCurrencyAsset<Runtime, VaneAssetRegistry, RuntimeHoldReason>;
+	type OnEscrowOpened = ();
+	type OnConfirmed = ();
+	type OnReleased = ();
+	type OnReverted = ();
+	type WeightInfo = weights::vane_xcm_transfer_system_weights::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const VaneAssetStringLimit: u32 = 32;
+}
+
+impl vane_asset_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type StringLimit = VaneAssetStringLimit;
+	type LocationToAccountId = xcm_config::LocationToAccountId;
+	type AssetTransactor = xcm_config::LocalAssetTransactor;
+	type WeightInfo = weights::vane_asset_registry_weights::WeightInfo<Runtime>;
 }
 
 
@@ -591,7 +658,7 @@ impl pallet_assets::Config for Runtime {
 	type AssetIdParameter = CurrencyId;
 	type Currency = Balances;
 	type CreateOrigin = VaneForeignCreators<
-		VaneDerivedAssets,
+		VaneDerivedAssets<VaneAssetRegistry>,
 		ForeignCreatorsSovereignAccountOf,
 		AccountId,
 	>;
@@ -766,7 +833,8 @@ construct_runtime!(
 
 		// Vane Pallets
 
-		VaneXcmTransfer: vane_xcm_transfer_system = 100
+		VaneXcmTransfer: vane_xcm_transfer_system = 100,
+		VaneAssetRegistry: vane_asset_registry = 101
 
 	}
 );
@@ -780,6 +848,8 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[vane_xcm_transfer_system, VaneXcmTransfer]
+		[vane_asset_registry, VaneAssetRegistry]
 	);
 }
 
@@ -920,6 +990,31 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl vane_xcm_transfer_system_runtime_api::VaneApi<Block, AccountId> for Runtime {
+		fn payer_receipts(payer: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+			VaneXcmTransfer::payer_receipts(payer)
+		}
+		fn payee_receipts(payee: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+			VaneXcmTransfer::payee_receipts(payee)
+		}
+		fn pending_confirmations(account: AccountId) -> Vec<vane_xcm_transfer_system_runtime_api::ReceiptInfo<AccountId>> {
+			VaneXcmTransfer::pending_confirmations(account)
+		}
+		fn escrow_balance(reference_no: Vec<u8>) -> Option<u128> {
+			VaneXcmTransfer::escrow_balance(reference_no)
+		}
+		fn confirmation_state(reference_no: Vec<u8>) -> Option<vane_xcm_transfer_system_runtime_api::ConfirmationState> {
+			VaneXcmTransfer::confirmation_state(reference_no)
+		}
+		fn quote_release(
+			payee: AccountId,
+			currency: vane_xcm_transfer_system_runtime_api::Token,
+			amount: u128,
+		) -> Option<vane_xcm_transfer_system_runtime_api::FeeQuote> {
+			VaneXcmTransfer::quote_payment(&payee, currency, amount).ok()
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
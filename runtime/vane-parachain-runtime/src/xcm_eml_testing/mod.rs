@@ -112,7 +112,7 @@ pub mod vane_parachain {
 	use super::*;
 	use crate::xcm_eml_testing::Storage;	use sp_core::crypto::Ss58Codec;
 	use vane_primitive::CurrencyId;
-	use vane_primitive::CurrencyId::DOT;
+	use vane_primitive::CurrencyId::{DOT, USDT};
 	use crate::{EXISTENTIAL_DEPOSIT,Balance};
 	use crate::xcm_eml_testing::accounts::{ALICE, invulnerables, sudo_key};
 
@@ -122,7 +122,7 @@ pub mod vane_parachain {
 	pub fn genesis() -> Storage {
 
 		let v_dot = "vDOT".as_bytes().to_vec();
-		let _v_usdt = "vUSDT".as_bytes().to_vec();
+		let v_usdt = "vUSDT".as_bytes().to_vec();
 		let _v_usdc = "vUSDC".as_bytes().to_vec();
 
 		// Calculate parachain Soverign account id
@@ -151,14 +151,19 @@ pub mod vane_parachain {
 
 			vane_assets: crate::VaneAssetsConfig {
 
-				metadata: vec![(DOT,v_dot.clone(), v_dot,10)],
+				metadata: vec![(DOT,v_dot.clone(), v_dot.clone(),10),(USDT,v_usdt.clone(), v_usdt.clone(),6)],
 
-				assets: vec![(DOT,para_account.clone(),true,1)],
+				assets: vec![(DOT,para_account.clone(),true,1),(USDT,para_account.clone(),true,1)],
 
-				accounts: vec![(DOT,para_account.clone(),0)]
+				accounts: vec![(DOT,para_account.clone(),0),(USDT,para_account.clone(),0)]
 
 			},
 
+			vane_asset_registry: crate::VaneAssetRegistryConfig {
+				assets: vec![(DOT,v_dot.clone(), v_dot,10),(USDT,v_usdt.clone(), v_usdt,6)],
+				..Default::default()
+			},
+
 			vane_xcm_transfer: crate::VaneXcmTransferConfig {
 				para_account: Some(para_account)
 			},
@@ -122,7 +122,7 @@ pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
 
 
 	let asset1_name = "vDOT".as_bytes().to_vec();
-	let _asset2_name = "vUSDT".as_bytes().to_vec();
+	let asset2_name = "vUSDT".as_bytes().to_vec();
 
 	// pallet_assets::GenesisConfig::<Runtime> {
 	//
@@ -134,13 +134,13 @@ pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
 
 	pallet_assets::GenesisConfig::<Runtime> {
 
-		metadata: vec![(CurrencyId::DOT,asset1_name.clone(),asset1_name,10)],
-		assets: vec![(CurrencyId::DOT,child_account_id(1),true,1)],
-		accounts: vec![(CurrencyId::DOT,child_account_id(1),0)]
+		metadata: vec![(CurrencyId::DOT,asset1_name.clone(),asset1_name,10),(CurrencyId::USDT,asset2_name.clone(),asset2_name,6)],
+		assets: vec![(CurrencyId::DOT,child_account_id(1),true,1),(CurrencyId::USDT,child_account_id(1),true,1)],
+		accounts: vec![(CurrencyId::DOT,child_account_id(1),0),(CurrencyId::USDT,child_account_id(1),0)]
 
 	}.assimilate_storage(&mut t).unwrap();
 
-	vane_xcm::GenesisConfig::<Runtime> {
+	vane_xcm_transfer_system::GenesisConfig::<Runtime> {
 		para_account: Some(child_account_id(1)),
 	}.assimilate_storage(&mut t).unwrap();
 
@@ -165,6 +165,8 @@ pub fn relay_ext() -> sp_io::TestExternalities {
 		balances: vec![
 			(ALICE, 100_000),
 			(child_account_id(1), 1000),
+			// Reserve backing the DOT derived on Vane
+			(child_account_id(2000), INITIAL_BALANCE),
 
 		],
 	}
@@ -191,7 +193,9 @@ mod tests {
 
 
 	use frame_support::{assert_ok};
-	use frame_support::traits::fungibles::Inspect;
+	use frame_support::traits::fungibles::{Inspect, Mutate};
+	use vane_primitive::OnReserveDeposit;
+	use vane_xcm_transfer_system::helper::{Confirm, ReferenceNo, Token, XcmStatus};
 	use sp_runtime::traits::Dispatchable;
 	use staging_xcm::v3::OriginKind::{Native, SovereignAccount};
 	use xcm_emulator::bx;
@@ -206,4 +210,49 @@ mod tests {
 	fn vane_remote_works(){
 
 	}
+
+	// Reference of the last payment opened on Vane
+	fn last_reference() -> ReferenceNo {
+		parachain::System::events()
+			.into_iter()
+			.rev()
+			.find_map(|record| match record.event {
+				parachain::RuntimeEvent::VaneXcmTransfer(vane_xcm_transfer_system::Event::ExpiryScheduled { reference_no, .. }) => {
+					Some(ReferenceNo::truncate_from(reference_no))
+				},
+				_ => None,
+			})
+			.expect("no payment opened")
+	}
+
+	#[test]
+	fn release_pays_the_quoted_fee_on_the_relay() {
+		MockNet::reset();
+		const AMOUNT: u128 = 100_000;
+
+		let (reference, quote) = Vane::execute_with(|| {
+			// Reserve backed DOT credited to BOB, as done by the asset transactor
+			assert_ok!(<VanePalletAsset as Mutate<_>>::mint_into(CurrencyId::DOT, &BOB, AMOUNT));
+			assert_ok!(<VanePalletVaneXcmTransferSystem as OnReserveDeposit<_, _, _>>::on_reserve_deposit(CurrencyId::DOT, &BOB, AMOUNT));
+
+			assert_ok!(VanePalletVaneXcmTransferSystem::vane_transfer(parachain::RuntimeOrigin::signed(BOB), MRISHO, AMOUNT, Token::DOT, None));
+			let reference = last_reference();
+
+			assert_ok!(VanePalletVaneXcmTransferSystem::vane_confirm(parachain::RuntimeOrigin::signed(MRISHO), Confirm::Payee, reference.to_vec()));
+			assert_ok!(VanePalletVaneXcmTransferSystem::vane_confirm(parachain::RuntimeOrigin::signed(BOB), Confirm::Payer, reference.to_vec()));
+
+			(reference, VanePalletVaneXcmTransferSystem::quote_release(Token::DOT, AMOUNT, false).unwrap())
+		});
+
+		// The relay only runs the release if the quoted weight covers the whole message, and what
+		// is left of the quoted fee after its trader is paid would reach the payee on top
+		Relay::execute_with(|| {
+			assert_eq!(RelayChainPalletBalances::free_balance(&MRISHO), AMOUNT - quote.fee);
+		});
+
+		Vane::execute_with(|| {
+			let receipt = vane_xcm_transfer_system::PayerTxnReceipt::<parachain::Runtime>::get(&BOB, &reference).unwrap();
+			assert_eq!(receipt.xcm_status, XcmStatus::Completed);
+		});
+	}
 }
@@ -1,6 +1,6 @@
 use assets_common::matching::FromSiblingParachain;
 use codec::{Decode, Encode};
-use frame_support::{construct_runtime, match_types, parameter_types, traits::{EnsureOrigin, EnsureOriginWithArg, Everything, EverythingBut, Nothing, ContainsPair}, weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight}};
+use frame_support::{construct_runtime, match_types, parameter_types, traits::{EnsureOrigin, EnsureOriginWithArg, Everything, EverythingBut, Nothing, ContainsPair}, weights::{constants::{WEIGHT_PROOF_SIZE_PER_MB, WEIGHT_REF_TIME_PER_SECOND}, Weight, WeightToFee}};
 
 use frame_system::EnsureRoot;
 use sp_core::{ConstU32, H256, Get};
@@ -116,7 +116,8 @@ pub type LocalAssetTransactor =  vane_primitive::VaneMultiCurrencyAdapter<
 	LocationToAccountId,
 	CurrencyId,
 	MultiCurrencyConverter<Runtime>,
-	// HandlingFailedDeposits
+	VaneXcmTransfer, // records reserve backed deposits
+	(), // failed deposits are trapped
 >;
 
 pub type SovereignAccountOf = (
@@ -212,7 +213,7 @@ impl Config for XcmConfig {
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
 	type Trader = FixedRateOfFungible<KsmPerSecondPerByte, ()>;
-	type ResponseHandler = ();
+	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = ();
 	type AssetLocker = PolkadotXcm;
 	type AssetExchanger = ();
@@ -425,8 +426,54 @@ impl pallet_xcm::Config for Runtime {
 
 
 
+parameter_types! {
+	pub const VaneXcmQueryTimeout: u64 = 100;
+	pub const VaneConfirmationWindow: u64 = 50;
+	pub const VaneMaxConfirmationWindow: u64 = 500;
+	pub const VaneMaxExpiriesPerBlock: u32 = 20;
+	pub const VaneMaxPayeeReceipts: u32 = 64;
+	pub const VaneMaxAccountTxns: u32 = 100;
+	// Same as the USDT min balance, so USDT releases pay one unit per weight unit
+	pub const VaneAssetHubExistentialDeposit: u128 = 700;
+}
+
+// Prices a release like the sim relay's trader does
+pub struct VaneRelayWeightToFee;
+impl WeightToFee for VaneRelayWeightToFee {
+	type Balance = Balance;
+
+	fn weight_to_fee(weight: &Weight) -> Balance {
+		let (_, units_per_second, units_per_mb) = super::relay_chain::TokensPerSecondPerByte::get();
+		units_per_second * (weight.ref_time() as u128) / (WEIGHT_REF_TIME_PER_SECOND as u128) +
+			units_per_mb * (weight.proof_size() as u128) / (WEIGHT_PROOF_SIZE_PER_MB as u128)
+	}
+}
+
+// There is no sim AssetHub, it is taken to charge one unit per weight unit
+pub type VaneAssetHubWeightToFee = frame_support::weights::IdentityFee<Balance>;
+
 impl vane_xcm_transfer_system::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type ResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+    type XcmQueryTimeout = VaneXcmQueryTimeout;
+    type RelayReleaseWeigher = FixedWeightBounds<super::relay_chain::BaseXcmWeight, RuntimeCall, super::relay_chain::MaxInstructions>;
+    type AssetHubReleaseWeigher = FixedWeightBounds<super::relay_chain::BaseXcmWeight, RuntimeCall, super::relay_chain::MaxInstructions>;
+    type RelayWeightToFee = VaneRelayWeightToFee;
+    type AssetHubWeightToFee = VaneAssetHubWeightToFee;
+    type AssetHubExistentialDeposit = VaneAssetHubExistentialDeposit;
+    type ConfirmationWindow = VaneConfirmationWindow;
+    type MaxConfirmationWindow = VaneMaxConfirmationWindow;
+    type MaxExpiriesPerBlock = VaneMaxExpiriesPerBlock;
+    type MaxPayeeReceipts = VaneMaxPayeeReceipts;
+    type MaxAccountTxns = VaneMaxAccountTxns;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Escrow = MultiCurrencyAsset<Runtime, (), RuntimeHoldReason>;
+    type OnEscrowOpened = ();
+    type OnConfirmed = ();
+    type OnReleased = ();
+    type OnReverted = ();
+    type WeightInfo = ();
 }
 impl parachain_info::Config for Runtime {}
 
@@ -468,7 +515,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		MsgQueue: mock_msg_queue::{Pallet, Storage, Event<T>},
-		PolkadotXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin},
+		PolkadotXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin},
 		VaneXcmTransfer: vane_xcm_transfer_system = 9,
 		VaneAssets: pallet_assets = 10
 	}
@@ -34,7 +34,7 @@ use polkadot_runtime_parachains::{
 };
 use staging_xcm::latest::prelude::*;
 use staging_xcm_builder::{
-	Account32Hash, AccountId32Aliases, AllowUnpaidExecutionFrom, AsPrefixedGeneralIndex,
+	Account32Hash, AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AsPrefixedGeneralIndex,
 	ChildParachainAsNative, ChildParachainConvertsVia, ChildSystemParachainAsSuperuser,
 	ConvertedConcreteId, CurrencyAdapter as XcmCurrencyAdapter, FixedRateOfFungible,
 	FixedWeightBounds, IsConcrete, NoChecking, NonFungiblesAdapter, SignedAccountId32AsNative,
@@ -138,7 +138,8 @@ parameter_types! {
 }
 
 pub type XcmRouter = super::RelayChainXcmRouter;
-pub type Barrier = AllowUnpaidExecutionFrom<Everything>;
+// Like on Polkadot, messages have to buy the execution they use
+pub type Barrier = AllowTopLevelPaidExecutionFrom<Everything>;
 
 pub struct XcmConfig;
 impl Config for XcmConfig {
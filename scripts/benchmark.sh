@@ -0,0 +1,37 @@
+#!/bin/bash
+
+# Benchmark the vane pallets wired into the runtimes and write the runtime weight files
+# Pallets not yet in a runtime keep the upper bound weights in their own weights.rs
+
+STEPS=${STEPS:-50}
+REPEAT=${REPEAT:-20}
+
+# Run cargo build with the benchmarking feature
+cargo build --release --features runtime-benchmarks
+
+# Check if cargo build was successful
+if [ $? -ne 0 ]; then
+    echo "Cargo build failed. Please check the build errors."
+    exit 1
+fi
+
+benchmark() {
+    ./target/release/vane-node benchmark pallet \
+        --chain="$1" \
+        --pallet="$2" \
+        --extrinsic='*' \
+        --steps="$STEPS" \
+        --repeat="$REPEAT" \
+        --wasm-execution=compiled \
+        --output="$3"
+}
+
+# Container chain runtime
+benchmark dev vane_xcm_transfer_system runtime/vane-container-runtime/src/weights/vane_xcm_transfer_system_weights.rs || exit 1
+benchmark dev vane_asset_registry runtime/vane-container-runtime/src/weights/vane_asset_registry_weights.rs || exit 1
+
+# Parachain runtime
+benchmark parachain vane_xcm_transfer_system runtime/vane-parachain-runtime/src/weights/vane_xcm_transfer_system_weights.rs || exit 1
+benchmark parachain vane_asset_registry runtime/vane-parachain-runtime/src/weights/vane_asset_registry_weights.rs || exit 1
+
+echo "All benchmarks executed successfully."